@@ -18,7 +18,7 @@ pub fn train_async(lang: Lang) -> JoinHandle<()> {
     thread::spawn(move || {
         let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
         let mut file = fs::File::create(out_dir.join(format!("{}{}", lang.to_string().to_lowercase(), ".rmp"))).unwrap(); 
-        let rules = grammar::rules(lang).unwrap();
+        let rules = grammar::rules_with_options(lang, &grammar::separators(lang), true).unwrap();
         let examples =  grammar::examples(lang);
         let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor()).unwrap();
         ::rmp_serde::encode::write(&mut file, &model).unwrap();
@@ -29,7 +29,7 @@ pub fn train_sync(lang: Lang) {
     println!("cargo:rerun-if-changed=grammar/{}/src/rules.rs", lang.to_string().to_lowercase());
     let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
     let mut file = fs::File::create(out_dir.join(format!("{}{}", lang.to_string().to_lowercase(), ".rmp"))).unwrap(); 
-    let rules = grammar::rules(lang).unwrap();
+    let rules = grammar::rules_with_options(lang, &grammar::separators(lang), true).unwrap();
     let examples =  grammar::examples(lang);
     let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor()).unwrap();
     ::rmp_serde::encode::write(&mut file, &model).unwrap();
@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate clap;
 extern crate rustling;
+extern crate rustling_ontology;
 extern crate rustling_ontology_grammar as grammar;
 extern crate rustling_ontology_values as values;
 extern crate rustling_ontology_moment;
@@ -9,6 +10,7 @@ extern crate prettytable;
 
 use rustling_ontology_moment::*;
 use prettytable::Table;
+use rustling_ontology::NormalizedText;
 use values::{ParsingContext, ResolverContext};
 
 fn main() {
@@ -22,7 +24,7 @@ fn main() {
     let lang = value_t!(matches.value_of("lang"), grammar::Lang).unwrap_or_else(|e| e.exit());
     match matches.subcommand() {
         ("parse", Some(matches)) => {
-            let sentence = matches.value_of("sentence").unwrap().to_lowercase();
+            let sentence = NormalizedText::new(lang, matches.value_of("sentence").unwrap()).text;
             let decoder = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
             let rules = grammar::rules(lang).unwrap();
             let matches = rules.apply_all(&*sentence).unwrap();
@@ -44,14 +44,14 @@ fn main() {
                                .map(|s| OutputKind::from_str(s).unwrap())
                                .collect::<Vec<_>>()
                   });
-            let sentence = matches.value_of("sentence").unwrap().to_lowercase();
+            let sentence = matches.value_of("sentence").unwrap();
             let parser = build_parser(lang).unwrap();
             
             let context = ResolverContext::default();
             let entities = if let Some(kinds) = kinds {
-                parser.parse_with_kind_order(&*sentence, &context, &kinds).unwrap()
+                parser.parse_with_kind_order(sentence, &context, &kinds).unwrap()
             } else {
-                parser.parse(&*sentence, &context).unwrap()
+                parser.parse(sentence, &context).unwrap()
             };
             let mut table = Table::new();
             table.set_titles(row!["ix", "log(p)", "p", "text", "value"]);
@@ -81,7 +81,7 @@ fn main() {
                              .collect()
                      })
                 .unwrap_or(OutputKind::all());
-            let sentence = matches.value_of("sentence").unwrap().to_lowercase();
+            let sentence = NormalizedText::new(lang, matches.value_of("sentence").unwrap()).text;
             let parser = build_raw_parser(lang).unwrap();
 
             let context = ResolverContext::default();
@@ -140,7 +140,7 @@ fn main() {
                 .map(|it| {
                   if it.keep() && (it.value.is_none() || force_resolution) {
                       let context = ResolverContext::new(Interval::starting_at(default_context, Grain::Second));
-                      let entities = parser.parse(it.phrase.to_lowercase().as_str(), &context).unwrap();
+                      let entities = parser.parse(it.phrase.as_str(), &context).unwrap();
                       let full_match = entities
                         .into_iter()
                         .filter(|entity| entity.byte_range.len() == it.phrase.len())
@@ -182,7 +182,7 @@ fn main() {
                 .map(|utterance| {
                   if utterance.keep() {
                       let context = ResolverContext::new(Interval::starting_at(default_context, Grain::Second));
-                      let entities = parser.parse(utterance.phrase.to_lowercase().as_str(), &context).unwrap();
+                      let entities = parser.parse(utterance.phrase.as_str(), &context).unwrap();
                       let assertion = if entities.len() == 1 {
                          let entity = entities.first();
                          match (entity, utterance.value) {
@@ -2,7 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Grain, PeriodComp, Weekday, Period};
+use rustling_ontology_moment::{Grain, PeriodComp, Weekday, Period, Season};
 
 fn german_article_regex() -> &'static str {
     r#"(?:i[nm]s?|zu[rm]?|beim?|um|w[äa]h?rend|f[uü]r) ?(?:de(?:r|m|s|n)|die|das)?"#
@@ -12,6 +12,26 @@ fn german_article_before_cycle() -> &'static str {
     r#"(?:i[nm]s?|a[nm]) (?:de(?:r|m|s|n)|die|das)|(?:i[nm]s?|a[nm])|(?:de(?:r|m|s|n)|die|das)"#
 }
 
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    let denominators = [
+        ("halb", 2),
+        ("dritt", 3),
+        ("viert", 4),
+        ("fünft", 5),
+        ("funft", 5),
+        ("sechst", 6),
+        ("siebt", 7),
+        ("acht", 8),
+        ("neunt", 9),
+        ("zehnt", 10),
+        ("hundertst", 100),
+    ];
+    denominators.iter()
+        .find(|&&(prefix, _)| word.starts_with(prefix))
+        .map(|&(_, denominator)| denominator)
+        .ok_or_else(|| RuleError::Invalid.into())
+}
+
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
         number_check!(),
@@ -21,95 +41,117 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: Some((r#"zwischen"#, r#"und"#)),
+        from_to: Some((r#"von"#, r#"bis"#)),
+        range: r#"-|~"#,
+        open: vec![
+            (OpenInterval::Under, helpers::OpenIntervalMarker::Before(r#"unter|weniger als"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::Before(r#"[üu]ber|mehr als"#)),
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::Before(r#"mindestens|wenigstens"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::Before(r#"h[öo]chstens|maximal|bis zu"#)),
+        ],
+    })
+}
+
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value())
     );
     b.rule_1_terminal("$",
                       b.reg(r#"\$|dollar[sn]?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("$") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
     );
     b.rule_1_terminal("USD",
                       b.reg(r#"us[d\$]|us[ -]dollar[sn]?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("USD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("AUD",
                       b.reg(r#"au[d\$]|australische[rnm]? dollar[sn]?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
     );
     b.rule_1_terminal("CAD",
                       b.reg(r#"cad|can\$|kanadische[rnm]? dollar[sn]?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
     );
     b.rule_1_terminal("HKD",
                       b.reg(r#"hk[d\$]|hong ?kong dollar[sn]?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("HKD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
     );
     b.rule_1_terminal("EUR",
                       b.reg(r#"euros?|eur|€"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
                       b.reg(r#"£|pfund(?:e?s)?|pfd\."#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("£") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("GBP",
                       b.reg(r#"gbp|britische[rnms]? pfund(?:e?s)?|pfund(?:e?s)? sterling"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("CHF",
                       b.reg(r#"chf|(?:schweizer )?frankens?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
     );
     b.rule_1_terminal("KR",
                       b.reg(r#"kronen?|kr"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Krone)) })
     );
     b.rule_1_terminal("DKK",
                       b.reg(r#"dkk|d[äa]nische[nr]? kronen?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("DKK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::DKK)) })
     );
     b.rule_1_terminal("NOK",
                       b.reg(r#"nok|norwegische[nr]? kronen?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("NOK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::NOK)) })
     );
     b.rule_1_terminal("SEK",
                       b.reg(r#"sek|schwedische[nr]? kronen?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("SEK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SEK)) })
     );
     b.rule_1_terminal("RUB",
                       b.reg(r#"₽|(?:russische[rnm]? )?rubel[sn]?|rub"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("RUB") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::RUB)) })
     );
     b.rule_1_terminal("INR",
                       b.reg(r#"inr|₹|(?:indische[rn]? )?rupien?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
     );
     b.rule_1_terminal("JPY",
                       b.reg(r#"jpy|yens?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
     );
     b.rule_1_terminal("CNY",
                       b.reg(r#"cny|(?:chinesische[rnm]? )?yuans?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CNY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
     );
     b.rule_1_terminal("¥",
                       b.reg(r#"¥"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("¥") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
     );
      b.rule_1_terminal("KRW",
                       b.reg(r#"krw|₩|(?:s[üu]dkoreanische[rnm]? )?wons?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
+    );
+    b.rule_1_terminal("฿",
+                      b.reg(r#"฿"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Baht)) })
+    );
+    b.rule_1_terminal("THB",
+                      b.reg(r#"baht|thb"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::THB)) })
     );
     b.rule_1_terminal("BTC",
-                      b.reg(r#"btc|฿|bitcoins?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("฿") })
+                      b.reg(r#"btc|bitcoins?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"cents?|penn(?:y|ies)|pence|cts?|c|¢"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
     );
     b.rule_2("<unit> <amount>",
              money_unit!(),
@@ -117,6 +159,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     precision: b.value().precision(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -126,6 +169,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              money_unit!(),
              |a, b| Ok(AmountOfMoneyValue {
                  value: a.value().value(),
+                 precision: a.value().precision(),
                  unit: b.value().unit,
                  ..AmountOfMoneyValue::default()
              })
@@ -203,7 +247,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, _, uod| {
                 let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).unwrap_or_else(|| Period::default());
-                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
             }
     );
     b.rule_2("while <duration>",
@@ -219,7 +263,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_2("number.number hours",
              b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -264,7 +308,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(0),
              b.reg(r#"ein ?halb"#)?,
              unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Hour),
-             |integer, _, _| Ok(DurationValue::new(PeriodComp::minutes(integer.value().value * 60 + 30).into()))
+             |integer, _, _| Ok(DurationValue::new(PeriodComp::minutes(integer.value().value * 60 + 30).into()).precision(integer.value().precision))
     );
     b.rule_2("in <duration>",
              b.reg(r#"in"#)?,
@@ -368,6 +412,12 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              duration_check!(|duration: &DurationValue| !duration.prefixed),
              |a, b| Ok(a.value() + b.value())
     );
+    b.rule_3("<fraction> einer <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"eine[rs]|der|des"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| helpers::fraction_of_grain(fraction.value().fraction().ok_or(RuleError::Invalid)?, uod.value().grain)
+    );
     Ok(())
 }
 
@@ -1697,8 +1747,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"sommer(?:zeit|s)?"#)?,
-                      |_| Ok(helpers::month_day(6, 21)?
-                          .span_to(&helpers::month_day(9, 23)?, false)?
+                      |_| Ok(helpers::season(Season::Summer)?
                           .form(Form::PartOfYear))
     );
     b.rule_1_terminal("Summer solstice",
@@ -1707,14 +1756,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"herbst(?:zeit|s|es)?|sp[äa]tjahr(?:es)?"#)?,
-                      |_| Ok(helpers::month_day(9, 23)?
-                          .span_to(&helpers::month_day(12, 21)?, false)?
+                      |_| Ok(helpers::season(Season::Autumn)?
                           .form(Form::PartOfYear))
     );
     b.rule_1_terminal("season",
                       b.reg(r#"winter(?:zeit|s)?"#)?,
-                      |_| Ok(helpers::month_day(12, 21)?
-                          .span_to(&helpers::month_day(3, 20)?, false)?
+                      |_| Ok(helpers::season(Season::Winter)?
                           .form(Form::PartOfYear))
     );
     b.rule_1_terminal("Winter solstice",
@@ -1723,8 +1770,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:fr[üu]hlings?|fr[üu]hjahr(?:es)?)(?:zeit)?"#)?,
-                      |_| Ok(helpers::month_day(3, 20)?
-                          .span_to(&helpers::month_day(6, 21)?, false)?
+                      |_| Ok(helpers::season(Season::Spring)?
                           .form(Form::PartOfYear))
     );
     b.rule_2("im <part-of-year>",
@@ -1980,6 +2026,42 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |_, time, _| Ok(time.value().the_nth(-1)?.mark_after_start().not_latent())
              //|_, time, _| Ok(time.value().clone().mark_after_start().not_latent())
     );
+    b.rule_3("in <integer> business days",
+             b.reg(r#"in"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"(?:werk|arbeits)tag(?:en?)?"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_3("within <integer> business days",
+             b.reg(r#"binnen|innerhalb(?: von)?"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"(?:werk|arbeits)tag(?:en?)?"#)?,
+             |_, integer, _| helpers::cycle_nth(Grain::Second, 0)?
+                    .span_to(&helpers::business_day_nth(integer.value().value)?, true)
+    );
+    b.rule_1_terminal("next business day",
+                      b.reg(r#"(?:der |am )?n[äa]chste[nr]? (?:werk|arbeits)tag"#)?,
+                      |_| helpers::business_day_nth(1)
+    );
+    b.rule_1_terminal("previous business day",
+                      b.reg(r#"(?:der |am )?vorherige[nr]? (?:werk|arbeits)tag"#)?,
+                      |_| helpers::business_day_nth(-1)
+    );
+    b.rule_1_terminal("last business day of the month",
+                      b.reg(r#"(?:der |am )?letzte[nr]? (?:werk|arbeits)tag (?:des|im) monats?"#)?,
+                      |_| helpers::business_day()?.last_of(&helpers::cycle_nth(Grain::Month, 0)?)
+    );
+    b.rule_2("last business day of <time>",
+             b.reg(r#"(?:der |am )?letzte[nr]? (?:werk|arbeits)tag (?:des|der|im|von)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day()?.last_of(time.value())
+    );
+    b.rule_3("<ordinal> business day of <time>",
+             ordinal_check!(),
+             b.reg(r#"(?:werk|arbeits)tag (?:des|der|im|von)"#)?,
+             time_check!(),
+             |ordinal, _, time| helpers::business_day()?.the_nth_after(ordinal.value().value - 1, time.value())
+    );
     Ok(())
 }
 
@@ -1989,6 +2071,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"plus"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
+                 precision: temp.value().precision,
                  unit: temp.value().unit,
                  latent: temp.value().latent,
              })
@@ -1998,6 +2081,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"minus"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: -1.0 * temp.value().value,
+                 precision: temp.value().precision,
                  unit: temp.value().unit,
                  latent: temp.value().latent,
              })
@@ -2006,6 +2090,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              number_check!(),
              |a| Ok(TemperatureValue {
                  value: a.value().value(),
+                 precision: a.value().precision(),
                  unit: None,
                  latent: true,
              })
@@ -2017,6 +2102,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  if temp.value().value >= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
+                         precision: temp.value().precision,
                          unit: temp.value().unit,
                          latent: temp.value().latent,
                      })
@@ -2032,6 +2118,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  if temp.value().value >= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
+                         precision: temp.value().precision,
                          unit: temp.value().unit,
                          latent: false,
                      })
@@ -2047,6 +2134,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  if temp.value().value <= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
+                         precision: temp.value().precision,
                          unit: temp.value().unit,
                          latent: false,
                      })
@@ -2062,6 +2150,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  if temp.value().value <= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
+                         precision: temp.value().precision,
                          unit: temp.value().unit,
                          latent: false,
                      })
@@ -2075,6 +2164,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"grade?s?|°"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
+                 precision: temp.value().precision,
                  unit: Some("degree"),
                  latent: false,
              })
@@ -2084,6 +2174,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"c(?:elsius)?\.?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
+                 precision: temp.value().precision,
                  unit: Some("celsius"),
                  latent: false,
              })
@@ -2093,6 +2184,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"k(?:elvin)?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
+                 precision: temp.value().precision,
                  unit: Some("kelvin"),
                  latent: false,
              })
@@ -2102,6 +2194,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"f(?:ah?rh?enh?eit)?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
+                 precision: temp.value().precision,
                  unit: Some("fahrenheit"),
                  latent: false,
              })
@@ -2111,6 +2204,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"f"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
+                 precision: temp.value().precision,
                  unit: Some("fahrenheit"),
                  latent: false,
              })
@@ -2118,6 +2212,88 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> kilometer",
+             number_check!(),
+             b.reg(r#"(?:kilometer[ns]?|km)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Kilometer).precision(a.value().precision()))
+    );
+    b.rule_2("<number> meter",
+             number_check!(),
+             b.reg(r#"(?:meter[ns]?|m)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Meter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> centimeter",
+             number_check!(),
+             b.reg(r#"(?:zentimeter[ns]?|cm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Centimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> millimeter",
+             number_check!(),
+             b.reg(r#"(?:millimeter[ns]?|mm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Millimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> mile",
+             number_check!(),
+             b.reg(r#"(?:meilen?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Mile).precision(a.value().precision()))
+    );
+    b.rule_2("<number> yard",
+             number_check!(),
+             b.reg(r#"(?:yards?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Yard).precision(a.value().precision()))
+    );
+    b.rule_2("<number> foot",
+             number_check!(),
+             b.reg(r#"(?:fu(?:ß|ss))"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Foot).precision(a.value().precision()))
+    );
+    b.rule_2("<number> inch",
+             number_check!(),
+             b.reg(r#"(?:zoll)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Inch).precision(a.value().precision()))
+    );
+    b.rule_2("ungefähr <distance>",
+             b.reg(r#"ungef[äa]hr|zirka|circa|ca\.|etwa|rund"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Approximate))
+    );
+    b.rule_2("genau <distance>",
+             b.reg(r#"genau|exakt"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_ratio(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("ratio (a:b)",
+        b.reg(r#"(\d{1,3}) ?: ?(\d{1,3})"#)?,
+        |text_match| helpers::colon_ratio(text_match.group(1), text_match.group(2))
+    );
+    b.rule_3("<integer> von <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"von"#)?,
+             integer_check_by_range!(1),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value))
+    );
+    b.rule_3("<integer> zu <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"zu"#)?,
+             integer_check_by_range!(0),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value).latent(true))
+    );
+    b.rule_2("Ergebnis von <ratio>",
+             b.reg(r#"(?:das |die )?(?:ergebnis|quote|verhältnis)(?: von| ist| war)?"#)?,
+             ratio_check!(),
+             |_, ratio| Ok(ratio.value().latent(false))
+    );
+    Ok(())
+}
+
+/// Roman numerals which are also German words or unit symbols
+pub const AMBIGUOUS_ROMAN_NUMERALS: &'static [&'static str] = &["mi", "di", "xi", "xl", "cd", "dc", "mm", "cm", "ml", "cl", "dl", "mix"];
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -2239,10 +2415,6 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(\d{1,18})"#)?,
                       |text_match| IntegerValue::new(text_match.group(1).parse()?)
     );
-    b.rule_1_terminal("integer with thousands separator .",
-                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
-                      |text_match| IntegerValue::new(text_match.group(1).replace(".", "").parse()?)
-    );
 
     b.rule_2("number hundreds",
         integer_check_by_range!(1, 99),
@@ -2282,17 +2454,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             ..IntegerValue::default()
         })
     );
-    b.rule_1_terminal("decimal number",
-                      b.reg(r#"(\d*,\d+)"#)?,
-                      |text_match| FloatValue::new(text_match.group(1).replace(",", ".").parse()?)
-    );
     b.rule_3("number dot number",
              number_check!(|number: &NumberValue| !number.prefixed()),
              b.reg(r#"komma"#)?,
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| {
                  let power = b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
@@ -2306,16 +2474,12 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, zeros, b| {
                  let power = zeros.group(0).split_whitespace().count() + b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
                  })
              });
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-                      |text_match| FloatValue::new(text_match.group(1).replace(".", "").replace(",", ".").parse()?)
-    );
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|minus|negativ"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -2360,7 +2524,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                              .into()
                      }
                      NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f32);
+                         let product = float.value * (multiplier as f64);
                          if product.floor() == product {
                              IntegerValue {
                                  value: product as i64,
@@ -2509,5 +2673,48 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             Ok(OrdinalValue::new(integer.value().value + 1))
         }
     );
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"(halbe?|drittel|viertel|f[üu]nftel|sechstel|siebtel|achtel|neuntel|zehntel|hundertstel)"#)?,
+        |integer, text_match| FloatValue::fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    helpers::rules_roman_numerals(b, &helpers::RomanNumeralWords {
+        ordinal_prefix: r#"kapitel|teil|band|buch|akt|szene|abschnitt|artikel"#,
+        ordinal_suffix: Some(r#"\."#),
+        ambiguous: AMBIGUOUS_ROMAN_NUMERALS,
+    })?;
+    b.rule_2("ungefähr <number>",
+             b.reg(r#"(?:ungefähr|etwa|circa|zirka|ca\.?|rund|an die)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> ungefähr",
+             number_check!(),
+             b.reg(r#"ungefähr"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(null|eins|zwei|zwo|drei|vier|fünf|sechs|sieben|acht|neun)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "null" => "0",
+                "eins" => "1",
+                "zwei" | "zwo" => "2",
+                "drei" => "3",
+                "vier" => "4",
+                "fünf" => "5",
+                "sechs" => "6",
+                "sieben" => "7",
+                "acht" => "8",
+                "neun" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
     Ok(())
 }
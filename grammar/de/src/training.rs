@@ -11,6 +11,7 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(3.0, Some("degree")), "3°", "3 °", "plus 3°", "3 ° über null");
     example!(v, check_temperature(71.0, Some("degree")), "71 grad", "71 Grad", "plus 71 Grad", "71 Grad über null", "ein und siebzig Grad");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "an die 20 grad");
     example!(v, check_temperature(-7.0, Some("degree")), "-7°", "-7 Grad", "minus sieben grad", "7 Grad unter null", "sieben grad unter dem gefrierpunkt", "7 Grad unterm gefrierpunkt");
     example!(v, check_temperature(-92.0, None), "zwei und neunzig unter dem gefrierpunkt", "92 unterm gefrierpunkt");
     example!(v, check_temperature(-18.0, Some("degree")), "-18°", "-18 Grad");
@@ -21,8 +22,27 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(130.0, Some("kelvin")), "130 kelvin", "130 Grad Kelvin", "hundert dreißig Grad KELVIN", "130 k", "130°K");
 }
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(3.0, DistanceUnit::Kilometer, Precision::Exact), "3 km", "drei kilometer", "3 Kilometer");
+    example!(v, check_distance(10.0, DistanceUnit::Meter, Precision::Exact), "zehn meter", "10m", "10 Meter");
+    example!(v, check_distance(25.0, DistanceUnit::Centimeter, Precision::Exact), "fünf und zwanzig zentimeter", "25cm");
+    example!(v, check_distance(4.0, DistanceUnit::Millimeter, Precision::Exact), "vier millimeter", "4 mm");
+    example!(v, check_distance(2.0, DistanceUnit::Mile, Precision::Exact), "zwei meilen", "2 Meilen");
+    example!(v, check_distance(6.0, DistanceUnit::Foot, Precision::Exact), "sechs fuß", "6 Fuss");
+    example!(v, check_distance(12.0, DistanceUnit::Inch, Precision::Exact), "zwölf zoll", "12 Zoll");
+    example!(v, check_distance(5.0, DistanceUnit::Kilometer, Precision::Approximate), "ungefähr 5 km", "etwa fünf kilometer", "circa 5 km");
+    example!(v, check_distance(20.0, DistanceUnit::Meter, Precision::Exact), "genau zwanzig meter", "exakt 20 m");
+}
+
+pub fn examples_ratio(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_ratio(3, 2), "3:2", "das ergebnis war 3:2");
+    example!(v, check_ratio(3, 5), "3 von 5", "drei von fünf");
+    example!(v, check_ratio(4, 1), "4 zu 1", "vier zu eins");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800 dollar");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Approximate), "an die 20 euro");
     example!(v, check_finance(2134.0, Some("$"), Precision::Exact), "2.134 $");
     example!(v, check_finance(90.0, Some("$"), Precision::Exact), "präzise neunzig $");
     example!(v, check_finance(478.0, Some("USD"), Precision::Exact), "478 US-Dollar");
@@ -46,7 +66,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(1000.0, Some("GBP"), Precision::Exact), "1000 Pfund Sterling");
     example!(v, check_finance(2.0, Some("CHF"), Precision::Exact), "zwei CHF");
     example!(v, check_finance(5.0, Some("CHF"), Precision::Exact), "Fünf schweizer Franken");
-    example!(v, check_finance(1.0, Some("KR"), Precision::Exact), "einer KRONE");
+    example!(v, check_finance(1.0, Some("kr"), Precision::Exact), "einer KRONE");
     example!(v, check_finance(17000.0, Some("DKK"), Precision::Exact), "siebzehn tausend dänischen kronen");
     example!(v, check_finance(2.2, Some("NOK"), Precision::Exact), "genau 2,2 NOK");
     example!(v, check_finance(5.0, Some("NOK"), Precision::Exact), "5 norwegischer Kronen");
@@ -61,8 +81,10 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(0.0, Some("¥"), Precision::Exact), "0¥");
     example!(v, check_finance(980.0, Some("KRW"), Precision::Exact), "ganz genau neunhundertachtzig Won");
     example!(v, check_finance(10.5, Some("KRW"), Precision::Exact), "10,5 ₩");
-    example!(v, check_finance(1.0, Some("฿"), Precision::Exact), "1 bitcoin");
-    example!(v, check_finance(10.0, Some("฿"), Precision::Exact), "zehn Bitcoins");
+    example!(v, check_finance(1.0, Some("XBT"), Precision::Exact), "1 bitcoin");
+    example!(v, check_finance(10.0, Some("XBT"), Precision::Exact), "zehn Bitcoins");
+    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿");
+    example!(v, check_finance(3.0, Some("THB"), Precision::Exact), "drei Baht", "3 THB");
     example!(v, check_finance(800.0, Some("cent"), Precision::Exact), "achthundert cent");
     example!(v, check_finance(300.0, Some("cent"), Precision::Exact), "300 pennies");
     example!(v, check_finance(1.0, Some("cent"), Precision::Exact), "1 penny", "1 cent", "1 cts", "1 ct");
@@ -183,8 +205,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2011, 2]), "vor zwei jahren");
     example!(v, check_moment!(c, [2013, 2, 19]), "in 7 tagen");
     example!(v, check_moment!(c, [2013, 12]), "ein jahr nach weihnachten");
-    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "diesen sommer");
-    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "diesen winter");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "diesen sommer");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "diesen winter");
     example!(v, check_moment!(c, [2013, 12, 25]), "Weihnachten", "Weihnachtstag");
     example!(v, check_moment!(c, [2013, 12, 31]), "Silvester");
     example!(v, check_moment!(c, [2014, 1, 1]), "Neujahrstag", "Neujahr");
@@ -224,6 +246,12 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 2, 12, 11, 30], [2013, 2, 12, 13, 30]), "11:30-13:30", "11:30-13:30", "11:30-13:30", "11:30-13:30", "11:30-13:30", "11:30-13:30", "11:30-13:30");
     example!(v, check_moment!(c, [2013, 9, 21, 1, 30]), "1:30 am Sa, 21. Sept");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 26]), "binnen 2 wochen", "innerhalb von 2 wochen");
+    example!(v, check_moment!(c, [2013, 2, 15]), "in 3 werktagen", "in 3 arbeitstagen");
+    example!(v, check_moment!(c, [2013, 2, 13]), "nächster werktag", "am nächsten arbeitstag");
+    example!(v, check_moment!(c, [2013, 2, 11]), "vorheriger werktag");
+    example!(v, check_moment!(c, [2013, 3, 29]), "letzter werktag im märz", "der letzte arbeitstag im märz");
+    example!(v, check_moment!(c, [2013, 2, 28]), "letzter werktag des monats");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 20]), "innerhalb von 5 werktagen", "binnen 5 arbeitstagen");
     example!(v, check_moment_with_direction!(c, [2013, 2, 12, 14], Direction::Before), "bis 2 Uhr nachmittag");
     example!(v, check_moment_with_direction!(c, [2013, 2, 13], Direction::Before, Grain::Hour), "bis zum ende des tages");
     example!(v, check_moment_with_direction!(c, [2013, 2, 7], Direction::After), "seit dem ende der woche");
@@ -323,4 +351,32 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(1000000.0), "1.000.000,00");
     example!(v, check_ordinal(44), "der vierundvierzigste");
     example!(v, check_integer(455628), "vierhundertfünfundfünfzigtausendsechshundertachtundzwanzig");
-}
\ No newline at end of file
+    example!(v, check_fraction(2, 3), "zwei drittel");
+    example!(v, check_fraction(3, 4), "drei viertel");
+    example!(v, check_fraction(1, 5), "ein fünftel");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "drei viertel einer stunde");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "an die 3 stunden");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "zwei drittel einer stunde");
+    example!(v, check_integer(4), "IV");
+    example!(v, check_ordinal(14), "XIV.", "Kapitel XIV");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "ungefähr 20", "etwa zwanzig", "ca. 20");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "null sechs eins zwei", "null sechs eins zwo", "0612");
+    example!(v, check_digit_sequence("555"), "fünf fünf fünf");
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "zwischen 20 und 30", "von zwanzig bis dreißig");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3 - 4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "unter 10", "weniger als zehn");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "mindestens 5", "wenigstens fünf");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("EUR")), "zwischen 20 und 30 Euro", "zwischen 20 Euro und 30 Euro", "von 20 bis 30 Euro", "20 - 30 Euro");
+    example!(v, check_finance_interval(Some(IntervalBound::exclusive(100.0)), None, Some("$")), "mehr als 100 dollar", "über 100 dollar");
+    example!(v, check_finance_interval(None, Some(IntervalBound::inclusive(50.0)), Some("EUR")), "höchstens 50 Euro", "bis zu fünfzig Euro");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "zwischen 20 und 25 Grad", "von 20° bis 25°");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("celsius")), "unter 5 Grad Celsius", "unter 5°C");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "zwischen 2 und 3 Stunden", "von zwei bis drei Stunden");
+    example!(v, check_duration_interval(None, Some(IntervalBound::exclusive(period!(0, 0, 0, 0, 0, 30)))), "weniger als 30 Minuten", "unter dreißig Minuten");
+}
@@ -1,10 +1,32 @@
-use std::f32;
+use std::f64;
 
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, Season};
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    let denominators = [
+        ("half", 2),
+        ("halve", 2),
+        ("third", 3),
+        ("quarter", 4),
+        ("fourth", 4),
+        ("fifth", 5),
+        ("sixth", 6),
+        ("seventh", 7),
+        ("eighth", 8),
+        ("ninth", 9),
+        ("tenth", 10),
+        ("twelfth", 12),
+        ("hundredth", 100),
+    ];
+    denominators.iter()
+        .find(|&&(prefix, _)| word.starts_with(prefix))
+        .map(|&(_, denominator)| denominator)
+        .ok_or_else(|| RuleError::Invalid.into())
+}
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -15,6 +37,20 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: Some((r#"between"#, r#"and"#)),
+        from_to: Some((r#"from"#, r#"to|till|until|through"#)),
+        range: r#"-|~"#,
+        open: vec![
+            (OpenInterval::Under, helpers::OpenIntervalMarker::Before(r#"under|less than|below|lower than|fewer than"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::Before(r#"over|more than|above|greater than|higher than"#)),
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::Before(r#"at least|no less than|minimum"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::Before(r#"at most|up to|no more than|maximum"#)),
+        ],
+    })
+}
+
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("second (unit-of-duration)",
                       b.reg(r#"sec(?:ond)?s?"#)?,
@@ -67,13 +103,13 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<integer> more <unit-of-duration>",
              integer_check_by_range!(0),
              b.reg(r#"more|less"#)?,
              unit_of_duration_check!(),
-             |integer, _, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, _, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_2_terminal("number.number hours",
                       b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -90,7 +126,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> and a half hours",
              integer_check_by_range!(0),
              b.reg(r#"and (?:an? )?half hours?"#)?,
-             |integer, _| Ok(DurationValue::new(PeriodComp::minutes(integer.value().value * 60 + 30).into()))
+             |integer, _| Ok(DurationValue::new(PeriodComp::minutes(integer.value().value * 60 + 30).into()).precision(integer.value().precision))
     );
     b.rule_3("<integer> <unit-of-duration> and a half",
              integer_check_by_range!(0),
@@ -98,7 +134,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"and (?:an? )?half"#)?,
              |integer, uod, _| {
                 let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).unwrap_or_else(|| Period::default());
-                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
             }
     );
     b.rule_3("<integer> <unit-of-duration> and a quarter",
@@ -107,7 +143,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"and (?:a? )?quarter"#)?,
              |integer, uod, _| {
                 let quarter_period: Period = uod.value().grain.quarter_period().map(|a| a.into()).unwrap_or_else(|| Period::default());
-                Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
             }
     );
     b.rule_3("<integer> and a half <unit-of-duration>",
@@ -116,7 +152,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, _, uod| {
                 let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).unwrap_or_else(|| Period::default());
-                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
             }
     );
     b.rule_3("<integer> and a quarter <unit-of-duration>",
@@ -125,7 +161,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, _, uod| {
                 let quarter_period: Period = uod.value().grain.quarter_period().map(|a| a.into()).unwrap_or_else(|| Period::default());
-                Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
             }
     );
     b.rule_3("<number> h <number>",
@@ -252,6 +288,12 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |duration, _| Ok(duration.value().clone().precision(Precision::Exact))
     );
 
+    b.rule_3("<fraction> of <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"of an?"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| helpers::fraction_of_grain(fraction.value().fraction().ok_or(RuleError::Invalid)?, uod.value().grain)
+    );
     Ok(())
 }
 
@@ -624,6 +666,46 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 1, &helpers::easter()?)?
                 .form(Form::Celebration))
     );
+    b.rule_1_terminal("Islamic New Year",
+        b.reg(r#"islamic new year|hijri new year|(?:the )?first (?:day )?of muharram"#)?,
+        |_| Ok(helpers::hijri_month_day(1, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Ashura",
+        b.reg(r#"(?:the )?(?:day of )?ashura"#)?,
+        |_| Ok(helpers::hijri_month_day(1, 10)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Mawlid",
+        b.reg(r#"mawlid(?: an[- ]nabi)?|(?:the )?prophet'?s birthday"#)?,
+        |_| Ok(helpers::hijri_month_day(3, 12)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Isra and Mi'raj",
+        b.reg(r#"isra (?:and|&) mi'?raj|lailat al[- ]miraj"#)?,
+        |_| Ok(helpers::hijri_month_day(7, 27)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Ramadan",
+        b.reg(r#"rama[dz]h?an"#)?,
+        |_| Ok(helpers::hijri_month(9)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("beginning of Ramadan",
+        b.reg(r#"(?:the )?(?:beginning|start|first day) of rama[dz]h?an"#)?,
+        |_| Ok(helpers::hijri_month_day(9, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Laylat al-Qadr",
+        b.reg(r#"lail(?:at|a|et) al[- ]qadr|(?:the )?night of (?:power|destiny)"#)?,
+        |_| Ok(helpers::hijri_month_day(9, 27)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Eid al-Fitr",
+        b.reg(r#"eid(?: (?:al|ul|el)[- ]?fitr)?"#)?,
+        |_| Ok(helpers::hijri_month_day(10, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Day of Arafah",
+        b.reg(r#"(?:the )?day of arafa[ht]?|arafa[ht]? day"#)?,
+        |_| Ok(helpers::hijri_month_day(12, 9)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Eid al-Adha",
+        b.reg(r#"eid (?:al|ul|el)[- ]?adha|(?:the )?feast of (?:the )?sacrifice|bakr[ -]?eid"#)?,
+        |_| Ok(helpers::hijri_month_day(12, 10)?.form(Form::Celebration))
+    );
     b.rule_1_terminal("Ascension",
         b.reg(r#"(?:(?:the )?feast of (?:the )?)?ascension(?: holiday|thursday|day)?"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
@@ -1079,7 +1161,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("time-of-day (latent) (half)",
             number_check!(|number: &NumberValue| {
                 let hour = (number.value() - 0.5) as u32;
-                hour as f32 == (number.value() - 0.5) && hour >= 1 && hour <= 23
+                hour as f64 == (number.value() - 0.5) && hour >= 1 && hour <= 23
             }),
              |number| {
                 let hour = number.value().value() as u32;
@@ -1089,7 +1171,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("time-of-day (latent) (quarter)",
             number_check!(|number: &NumberValue| {
                 let hour = (number.value() - 0.25) as u32;
-                hour as f32 == (number.value() - 0.25) && hour >= 1 && hour <= 23
+                hour as f64 == (number.value() - 0.25) && hour >= 1 && hour <= 23
             }),
              |number| {
                 let hour = number.value().value() as u32;
@@ -1486,19 +1568,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:the )?summer"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:the )?(?:fall|autumn)"#)?,
-                      |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:the )?winter"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:the )?spring"#)?,
-                      |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_1_terminal("<hour>ish",
              b.reg(r#"(one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)ish"#)?,
@@ -1604,6 +1686,38 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              duration_check!(),
              |_, a| helpers::cycle_nth(Grain::Second, 0)?.span_to(&a.value().in_present()?, false)
     );
+    b.rule_3("in <integer> business days",
+             b.reg(r#"in"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"(?:business|working|work) days?"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_3("within <integer> business days",
+             b.reg(r#"within"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"(?:business|working|work) days?"#)?,
+             |_, integer, _| helpers::cycle_nth(Grain::Second, 0)?
+                    .span_to(&helpers::business_day_nth(integer.value().value)?, true)
+    );
+    b.rule_1_terminal("next business day",
+                      b.reg(r#"(?:the )?(?:next|following) (?:business|working|work) day"#)?,
+                      |_| helpers::business_day_nth(1)
+    );
+    b.rule_1_terminal("previous business day",
+                      b.reg(r#"(?:the )?previous (?:business|working|work) day"#)?,
+                      |_| helpers::business_day_nth(-1)
+    );
+    b.rule_2("last business day of <time>",
+             b.reg(r#"(?:the )?last (?:business|working|work) day (?:of|in)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day()?.last_of(time.value())
+    );
+    b.rule_3("<ordinal> business day of <time>",
+             ordinal_check!(),
+             b.reg(r#"(?:business|working|work) day (?:of|in)"#)?,
+             time_check!(),
+             |ordinal, _, time| helpers::business_day()?.the_nth_after(ordinal.value().value - 1, time.value())
+    );
     b.rule_2("by <time>",
              b.reg(r#"by"#)?,
              time_check!(|time: &TimeValue|  !time.latent),
@@ -1651,12 +1765,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
              amount_of_money_check!(),
              b.reg(r#"and"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_2("intersect",
              amount_of_money_check!(),
@@ -1669,87 +1783,95 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_1_terminal("$",
                       b.reg(r#"\$|dollars?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("$") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
     );
     b.rule_1_terminal("USD",
                       b.reg(r#"us[d\$]|(?:us|american) dollars?|bucks?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("USD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("AUD",
                       b.reg(r#"au[d\$]|australian dollars?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
     );
     b.rule_1_terminal("CAD",
                       b.reg(r#"cad|canadian dollars?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
     );
     b.rule_1_terminal("HKD",
                       b.reg(r#"hkd|hk dollars?|hong[- ]?kong dollars?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("HKD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
     );
     b.rule_1_terminal("EUR",
                       b.reg(r#"€|(?:[e€]uro?s?)"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
                       b.reg(r#"£|pounds?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("£") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("GBP",
                       b.reg(r#"gbp|(?:sterling|british) pounds?|sterlings?|quids?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("CHF",
                       b.reg(r#"chf|swiss francs?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
     );
     b.rule_1_terminal("KR",
                       b.reg(r#"kroner?|crowns?|kr"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Krone)) })
     );
     b.rule_1_terminal("DKK",
                       b.reg(r#"dkk|danish (?:kroner?|crowns?)"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("DKK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::DKK)) })
     );
     b.rule_1_terminal("NOK",
                       b.reg(r#"nok|norwegian (?:kroner?|crowns?)"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("NOK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::NOK)) })
     );
     b.rule_1_terminal("SEK",
                       b.reg(r#"sek|swedish (?:krona|kronor|crowns?)"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("SEK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SEK)) })
     );
     b.rule_1_terminal("RUB",
                       b.reg(r#"(?:russian )?rubles?|rub"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("RUB") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::RUB)) })
     );
     b.rule_1_terminal("INR",
                       b.reg(r#"inr|rs\.?|(?:indian )?rupees?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
     );
     b.rule_1_terminal("JPY",
                       b.reg(r#"jpy|yens?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
     );
     b.rule_1_terminal("CNY",
                       b.reg(r#"cny|cnh|rmb|yuans?|renmimbis?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CNY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
     );
     b.rule_1_terminal("¥",
                       b.reg(r#"¥"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("¥") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
     );
     b.rule_1_terminal("KRW",
                       b.reg(r#"₩|krw|(?:south[- ]?)?korean wons?|wons?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
     );
     b.rule_1_terminal("฿",
-                      b.reg(r#"฿|bitcoins?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("฿") })
+                      b.reg(r#"฿"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Baht)) })
+    );
+    b.rule_1_terminal("THB",
+                      b.reg(r#"bahts?|thb"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::THB)) })
+    );
+    b.rule_1_terminal("XBT",
+                      b.reg(r#"bitcoins?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"cents?|penn(?:y|ies)|c|¢"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
     );
     b.rule_2("<unit> <amount>",
              money_unit!(),
@@ -1757,6 +1879,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     precision: b.value().precision(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -1767,6 +1890,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -1798,6 +1922,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: None,
                      latent: true,
                  })
@@ -1808,6 +1933,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("degree"),
                      latent: true,
                  })
@@ -1818,6 +1944,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -1828,6 +1955,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -1838,6 +1966,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("kelvin"),
                      latent: false,
                  })
@@ -1845,6 +1974,209 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> kilometer",
+             number_check!(),
+             b.reg(r#"(?:kilomet(?:er|re)s?|kms?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Kilometer).precision(a.value().precision()))
+    );
+    b.rule_2("<number> meter",
+             number_check!(),
+             b.reg(r#"met(?:er|re)s?"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Meter).precision(a.value().precision()))
+    );
+    // "m" is as well an abbreviation for minutes or millions
+    b.rule_2("<number> m",
+             number_check!(),
+             b.reg(r#"m"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Meter).precision(a.value().precision()).latent(true))
+    );
+    b.rule_2("<number> centimeter",
+             number_check!(),
+             b.reg(r#"(?:centimet(?:er|re)s?|cm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Centimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> millimeter",
+             number_check!(),
+             b.reg(r#"(?:millimet(?:er|re)s?|mm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Millimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> mile",
+             number_check!(),
+             b.reg(r#"(?:miles?|mi)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Mile).precision(a.value().precision()))
+    );
+    b.rule_2("<number> yard",
+             number_check!(),
+             b.reg(r#"(?:yards?|yds?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Yard).precision(a.value().precision()))
+    );
+    b.rule_2("<number> foot",
+             number_check!(),
+             b.reg(r#"(?:foot|feet|ft)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Foot).precision(a.value().precision()))
+    );
+    b.rule_2("<number> inch",
+             number_check!(),
+             b.reg(r#"(?:inch(?:es)?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Inch).precision(a.value().precision()))
+    );
+    b.rule_2("about <distance>",
+             b.reg(r#"(?:about|around|approximately|roughly)"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Approximate))
+    );
+    b.rule_2("exactly <distance>",
+             b.reg(r#"exactly|precisely"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("milligram",
+                      b.reg(r#"mg|milligram(?:me)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Milligram })
+    );
+    b.rule_1_terminal("gram",
+                      b.reg(r#"g|gr|gram(?:me)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Gram })
+    );
+    b.rule_1_terminal("kilogram",
+                      b.reg(r#"kg|kilo(?:gram(?:me)?)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Kilogram })
+    );
+    b.rule_1_terminal("ounce",
+                      b.reg(r#"oz|ounces?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Ounce })
+    );
+    b.rule_1_terminal("pound",
+                      b.reg(r#"lbs?|pounds?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Pound })
+    );
+    b.rule_1_terminal("milliliter",
+                      b.reg(r#"ml|millilit(?:er|re)s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Milliliter })
+    );
+    b.rule_1_terminal("centiliter",
+                      b.reg(r#"cl|centilit(?:er|re)s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Centiliter })
+    );
+    b.rule_1_terminal("liter",
+                      b.reg(r#"l|lit(?:er|re)s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Liter })
+    );
+    b.rule_1_terminal("teaspoon",
+                      b.reg(r#"tsp|teaspoons?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Teaspoon })
+    );
+    b.rule_1_terminal("tablespoon",
+                      b.reg(r#"tbsp|tablespoons?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Tablespoon })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"cups?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Cup })
+    );
+    b.rule_2("<number> <quantity unit>",
+             number_check!(),
+             quantity_unit!(),
+             |a, unit| Ok(QuantityValue::new(a.value().value(), unit.value().unit))
+    );
+    b.rule_2("a <quantity unit>",
+             b.reg(r#"an?"#)?,
+             quantity_unit!(),
+             |_, unit| Ok(QuantityValue::new(1.0, unit.value().unit))
+    );
+    b.rule_3("<quantity> of <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"of"#)?,
+             b.reg(r#"\p{L}+"#)?,
+             |quantity, _, product| Ok(quantity.value().clone().product(product.byte_range()))
+    );
+    b.rule_3("<fraction> of <quantity unit>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"of an?"#)?,
+             quantity_unit!(),
+             |fraction, _, unit| Ok(QuantityValue::new(fraction.value().value(), unit.value().unit))
+    );
+    Ok(())
+}
+
+pub fn rules_rate(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("<amount-of-money> per <unit-of-duration>",
+             amount_of_money_check!(),
+             b.reg(r#"\b(?:per|an?|each|every)\b|/"#)?,
+             unit_of_duration_check!(),
+             |amount, _, unit| Ok(RateValue::new(amount.value().clone(), unit.value()))
+    );
+    b.rule_3("<distance> per <unit-of-duration>",
+             distance_check!(),
+             b.reg(r#"\b(?:per|an?|each|every)\b|/"#)?,
+             unit_of_duration_check!(),
+             |distance, _, unit| Ok(RateValue::new(distance.value().clone(), unit.value()))
+    );
+    b.rule_2("<number> mph",
+             number_check!(),
+             b.reg(r#"mph"#)?,
+             |number, _| Ok(RateValue::new(DistanceValue::new(number.value().value(), DistanceUnit::Mile).precision(number.value().precision()), &UnitOfDurationValue::new(Grain::Hour)))
+    );
+    b.rule_3("<number> per <unit-of-duration>",
+             number_check!(),
+             b.reg(r#"per|/"#)?,
+             unit_of_duration_check!(),
+             |number, _, unit| Ok(RateValue::new(number.value().clone(), unit.value()))
+    );
+    b.rule_3("<integer> times per <unit-of-duration>",
+             integer_check_by_range!(1),
+             b.reg(r#"times? (?:per|an?|each|every)\b"#)?,
+             unit_of_duration_check!(),
+             |integer, _, unit| Ok(RateValue::new(NumberValue::from(integer.value().clone()), unit.value()))
+    );
+    b.rule_2("once|twice per <unit-of-duration>",
+             b.reg(r#"(once|twice|thrice) (?:per|an?|each|every)\b"#)?,
+             unit_of_duration_check!(),
+             |text_match, unit| {
+                 let value = match text_match.group(1) {
+                     "once" => 1,
+                     "twice" => 2,
+                     _ => 3,
+                 };
+                 Ok(RateValue::new(NumberValue::from(IntegerValue::new(value)?), unit.value()))
+             }
+    );
+    Ok(())
+}
+
+pub fn rules_ratio(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("ratio (a:b)",
+        b.reg(r#"(\d{1,3}) ?: ?(\d{1,3})"#)?,
+        |text_match| helpers::colon_ratio(text_match.group(1), text_match.group(2))
+    );
+    b.rule_3("<integer> out of <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"out of"#)?,
+             integer_check_by_range!(1),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value))
+    );
+    b.rule_3("<integer> to <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"to"#)?,
+             integer_check_by_range!(0),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value).latent(true))
+    );
+    b.rule_2("score of <ratio>",
+             b.reg(r#"(?:the )?(?:score|odds|ratio)(?: of| was| is)?"#)?,
+             ratio_check!(),
+             |_, ratio| Ok(ratio.value().latent(false))
+    );
+    Ok(())
+}
+
+/// Roman numerals which are also English words or unit symbols
+pub const AMBIGUOUS_ROMAN_NUMERALS: &'static [&'static str] = &["mi", "di", "li", "vi", "ci", "xi", "cc", "cv", "xl", "cd", "dc", "mc", "md", "mm", "cm", "ml", "cl", "dl", "mix", "civ", "cli", "liv", "mdc"];
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_3("intersect (with and)",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -1949,13 +2281,6 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("integer (numeric)",
                       b.reg(r#"(\d{1,18})"#)?,
                       |text_match| IntegerValue::new(text_match.group(0).parse()?));
-    b.rule_1_terminal("integer with thousands separator ,",
-                      b.reg(r#"(\d{1,3}(,\d\d\d){1,5})"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: i64 = reformatted_string.parse()?;
-                          IntegerValue::new(value)
-                      });
     b.rule_2("special composition for missing hundreds like in one twenty two",
              integer_check_by_range!(1, 9),
              integer_check_by_range!(10, 99),
@@ -2013,24 +2338,15 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  })
     });
 
-    b.rule_1("decimal number", 
-        b.reg(r#"(\d*\.\d+)"#)?, 
-        |text_match| {
-            let value: f32 = text_match.group(0).parse()?;
-            Ok(FloatValue {
-                value: value,
-                ..FloatValue::default()
-            })
-    });
     b.rule_2("<integer> and a half",
         integer_check!(),
         b.reg(r#"and a half"#)?,
-        |integer, _| FloatValue::new(integer.value().value as f32 + 0.5)
+        |integer, _| FloatValue::new(integer.value().value as f64 + 0.5)
     );
     b.rule_2("<integer> and a quarter",
         integer_check!(),
         b.reg(r#"and a quarter"#)?,
-        |integer, _| FloatValue::new(integer.value().value as f32 + 0.25)
+        |integer, _| FloatValue::new(integer.value().value as f64 + 0.25)
     );
     b.rule_3("number dot number",
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -2038,7 +2354,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| {
                  let power = b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
@@ -2051,22 +2367,12 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
          number_check!(|number: &NumberValue| !number.suffixed()),
          |a, _, zeros, b| {
              let power = zeros.group(0).split_whitespace().count() + b.value().value().to_string().chars().count();
-             let coeff = 10.0_f32.powf(-1.0 * power as f32);
+             let coeff = 10.0_f64.powf(-1.0 * power as f64);
              Ok(FloatValue {
                  value: b.value().value() * coeff + a.value().value(),
                  ..FloatValue::default()
              })
     });
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: f32 = reformatted_string.parse()?;
-                          Ok(FloatValue {
-                              value: value,
-                              ..FloatValue::default()
-                          })
-                      });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|minus\s?|negative\s?"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -2112,7 +2418,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                              .into()
                      }
                      NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f32);
+                         let product = float.value * (multiplier as f64);
                          if product.floor() == product {
                              IntegerValue {
                                  value: product as i64,
@@ -2258,5 +2564,136 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"the"#)?,
              ordinal_check!(),
              |_, ordinal| Ok((*ordinal.value()).prefixed()));
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"(half|halve|third|quarter|fourth|fifth|sixth|seventh|eighth|ninth|tenth|twelfth|hundredth)s?"#)?,
+        |integer, text_match| FloatValue::fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_1_terminal("a <fraction denominator>",
+        b.reg(r#"an? (half|third|quarter|fourth|fifth|sixth|seventh|eighth|ninth|tenth|twelfth|hundredth)"#)?,
+        |text_match| FloatValue::fraction(1, fraction_denominator(text_match.group(1))?)
+    );
+    helpers::rules_roman_numerals(b, &helpers::RomanNumeralWords {
+        ordinal_prefix: r#"chapter|part|volume|book|act|scene|section|article|world war"#,
+        ordinal_suffix: None,
+        ambiguous: AMBIGUOUS_ROMAN_NUMERALS,
+    })?;
+    b.rule_2("about <number>",
+             b.reg(r#"(?:about|around|approximately|approx\.?|roughly|circa)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> or so",
+             number_check!(),
+             b.reg(r#"or so"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(zero|oh|one|two|three|four|five|six|seven|eight|nine)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "zero" | "oh" => "0",
+                "one" => "1",
+                "two" => "2",
+                "three" => "3",
+                "four" => "4",
+                "five" => "5",
+                "six" => "6",
+                "seven" => "7",
+                "eight" => "8",
+                "nine" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Spoken forms found in speech recognition output, only enabled in the ASR mode of the parser
+pub fn rules_spoken_forms(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<filler> <number>",
+             b.reg(r#"u+h+m*|u+m+|e+r+m*|a+h+|h+m+"#)?,
+             number_check!(),
+             |_, a| Ok(a.value().clone())
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"u+h+m*|u+m+|e+r+m*|a+h+|h+m+"#)?,
+             |a, _| Ok(a.value().clone())
+    );
+    b.rule_2("<filler> <time>",
+             b.reg(r#"u+h+m*|u+m+|e+r+m*|a+h+|h+m+"#)?,
+             time_check!(),
+             |_, a| Ok(a.value().clone())
+    );
+    b.rule_2("<time> <filler>",
+             time_check!(),
+             b.reg(r#"u+h+m*|u+m+|e+r+m*|a+h+|h+m+"#)?,
+             |a, _| Ok(a.value().clone())
+    );
+    b.rule_3("<number> <filler> <number> (disfluency)",
+             integer_check!(),
+             b.reg(r#"u+h+m*|u+m+|e+r+m*|a+h+|h+m+"#)?,
+             integer_check!(),
+             |a, _, b| {
+                 // The zeros the first number ends with are the place left for the rest of it:
+                 // "twenty uh three" goes on with the number, "twenty uh twenty three" starts
+                 // it over
+                 let (first, second) = (a.value().value, b.value().value);
+                 let mut span = 1;
+                 while first != 0 && first % (span * 10) == 0 {
+                     span *= 10;
+                 }
+                 if second < span {
+                     IntegerValue::new(first + second)
+                 } else if second >= first && second - first < span {
+                     Ok(b.value().clone())
+                 } else {
+                     Err(RuleError::Invalid.into())
+                 }
+             }
+    );
+    b.rule_2("a hundred|thousand|million|billion",
+             b.reg(r#"an?"#)?,
+             integer_check!(|integer: &IntegerValue| integer.grain.unwrap_or(0) > 1
+                 && [100, 1_000, 1_000_000, 1_000_000_000].contains(&integer.value)),
+             |_, a| Ok(a.value().clone())
+    );
+    b.rule_3("oh <hour> hundred (military time-of-day)",
+             b.reg(r#"oh|zero"#)?,
+             integer_check_by_range!(1, 9),
+             b.reg(r#"hundred(?: hours)?"#)?,
+             |_, hour, _| helpers::hour(hour.value().value as u32, false)
+    );
+    b.rule_3("oh <hour> <minutes> (military time-of-day)",
+             b.reg(r#"oh|zero"#)?,
+             integer_check_by_range!(1, 9),
+             integer_check_by_range!(10, 59),
+             |_, hour, minute| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    b.rule_3("<hour-of-day> oh <integer> (time-of-day)",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"oh"#)?,
+             integer_check_by_range!(1, 9),
+             |time, _, minute| helpers::hour_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 minute.value().value as u32,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    // Years spoken as two pairs ("twenty twenty") are read by the latent "year composed" rule
+    b.rule_3("year (spoken as <pair> oh <digit>)",
+             b.reg(r#"(nineteen|twenty)"#)?,
+             b.reg(r#"oh"#)?,
+             integer_check_by_range!(1, 9),
+             |pair, _, digit| {
+                 let century = if pair.group(1) == "nineteen" { 1900 } else { 2000 };
+                 Ok(helpers::year(century + digit.value().value as i32)?.latent())
+             }
+    );
+    Ok(())
+}
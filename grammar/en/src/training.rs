@@ -2,6 +2,7 @@ use rustling_ontology_values::check::*;
 use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
+use rustling::Range;
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_percentage(0.3), "0.3%", "zero point three per cent");
@@ -11,6 +12,7 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(3.0, Some("degree")), "three degrees", "3 degrees", "3°", "3 °");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "roughly 20 degrees", "twenty or so degrees");
     example!(v, check_temperature(32.0, Some("celsius")), "thirty two degrees celsius", "thirty two degrees centigrade", "32°C", "32 °c");
     example!(v, check_temperature(-27.0, Some("celsius")), "minus 27 celsius", "-27C", "- 27 c");
     example!(v, check_temperature(-5.0, Some("fahrenheit")), "minus five degrees fahrenheit", "-5 °F", "- 5°f");
@@ -19,8 +21,64 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "21 K", "21k");
 }
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(3.0, DistanceUnit::Kilometer, Precision::Exact), "3 km", "three kilometers", "3 kilometres");
+    example!(v, check_distance(10.0, DistanceUnit::Meter, Precision::Exact), "ten meters", "10m", "10 metres");
+    example!(v, check_distance(25.0, DistanceUnit::Centimeter, Precision::Exact), "twenty five centimeters", "25cm");
+    example!(v, check_distance(4.0, DistanceUnit::Millimeter, Precision::Exact), "four millimeters", "4 mm");
+    example!(v, check_distance(2.5, DistanceUnit::Mile, Precision::Exact), "2.5 miles", "2.5 mi");
+    example!(v, check_distance(100.0, DistanceUnit::Yard, Precision::Exact), "one hundred yards", "100 yds");
+    example!(v, check_distance(6.0, DistanceUnit::Foot, Precision::Exact), "six feet", "6 ft");
+    example!(v, check_distance(12.0, DistanceUnit::Inch, Precision::Exact), "twelve inches", "12 inches");
+    example!(v, check_distance(5.0, DistanceUnit::Kilometer, Precision::Approximate), "about 5 km", "around five kilometers", "roughly 5 kms");
+    example!(v, check_distance(3.0, DistanceUnit::Kilometer, Precision::Approximate), "circa 3 km", "three or so kilometers");
+    example!(v, check_distance(20.0, DistanceUnit::Mile, Precision::Exact), "exactly twenty miles", "precisely 20 mi");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(2.0, QuantityUnit::Liter, Some(Range(12, 16))), "2 liters of milk");
+    example!(v, check_quantity(2.0, QuantityUnit::Liter, Some(Range(14, 18))), "two litres of milk");
+    example!(v, check_quantity(2.0, QuantityUnit::Liter, Some(Range(6, 10))), "2l of milk");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Some(Range(9, 14))), "500 g of flour");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Some(Range(22, 27))), "five hundred grams of flour");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Some(Range(8, 13))), "500g of flour");
+    example!(v, check_quantity(3.0, QuantityUnit::Cup, Some(Range(10, 15))), "3 cups of sugar");
+    example!(v, check_quantity(3.0, QuantityUnit::Cup, Some(Range(14, 19))), "three cups of sugar");
+    example!(v, check_quantity(1.0, QuantityUnit::Pound, Some(Range(11, 17))), "a pound of apples");
+    example!(v, check_quantity(1.0, QuantityUnit::Pound, Some(Range(13, 19))), "one pound of apples");
+    example!(v, check_quantity(1.0, QuantityUnit::Pound, Some(Range(8, 14))), "1 lb of apples");
+    example!(v, check_quantity(2.0, QuantityUnit::Kilogram, None), "2 kg", "two kilos", "2 kilograms");
+    example!(v, check_quantity(250.0, QuantityUnit::Milliliter, None), "250 ml", "two hundred and fifty milliliters");
+    example!(v, check_quantity(33.0, QuantityUnit::Centiliter, None), "33 cl", "33 centiliters");
+    example!(v, check_quantity(10.0, QuantityUnit::Milligram, None), "10 mg", "ten milligrams");
+    example!(v, check_quantity(8.0, QuantityUnit::Ounce, None), "8 oz", "eight ounces");
+    example!(v, check_quantity(2.0, QuantityUnit::Tablespoon, Some(Range(10, 16))), "2 tbsp of butter");
+    example!(v, check_quantity(2.0, QuantityUnit::Tablespoon, Some(Range(19, 25))), "two tablespoons of butter");
+    example!(v, check_quantity(1.0, QuantityUnit::Teaspoon, Some(Range(14, 18))), "a teaspoon of salt");
+    example!(v, check_quantity(1.0, QuantityUnit::Teaspoon, Some(Range(9, 13))), "1 tsp of salt");
+    example!(v, check_quantity(0.75, QuantityUnit::Cup, None), "three quarters of a cup", "3/4 cup");
+    example!(v, check_quantity(0.25, QuantityUnit::Pound, Some(Range(24, 30))), "a quarter of a pound of butter");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_rate(20.0, Some("$"), Grain::Hour), "$20 per hour", "20 dollars an hour", "$20/h");
+    example!(v, check_finance_rate(500.0, Some("EUR"), Grain::Month), "500 euros per month", "500€ a month");
+    example!(v, check_rate(3.0, Grain::Day), "3 times a day", "three times per day");
+    example!(v, check_rate(2.0, Grain::Week), "twice a week", "twice every week");
+    example!(v, check_distance_rate(60.0, DistanceUnit::Kilometer, Grain::Hour), "60 km/h", "sixty kilometers per hour");
+    example!(v, check_distance_rate(60.0, DistanceUnit::Mile, Grain::Hour), "60 mph", "60 miles an hour");
+}
+
+pub fn examples_ratio(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_ratio(3, 2), "3:2", "the score was 3:2");
+    example!(v, check_ratio(3, 5), "3 out of 5", "three out of five");
+    example!(v, check_ratio(4, 1), "4 to 1", "odds of four to one");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800$", "eight hundred dollars", "eight hundred dollar");
+    example!(v, check_finance(20.0, Some("$"), Precision::Approximate), "roughly 20 dollars", "twenty or so dollars");
+    example!(v, check_finance(1234567.89, Some("$"), Precision::Exact), "1234567.89$", "1,234,567.89 dollars");
     example!(v, check_finance(10.0, Some("USD"), Precision::Approximate), "around ten us dollars", "almost 10US$");
     example!(v, check_finance(3.0, Some("AUD"), Precision::Exact), "exactly 3 australian dollar", "precisely 3 AUD");
     example!(v, check_finance(0.0, Some("HKD"), Precision::Exact), "zero hk dollar");
@@ -29,7 +87,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(2.0, Some("£"), Precision::Exact), "2 pounds", "two £");
     example!(v, check_finance(20.0, Some("GBP"), Precision::Exact), "twenty british pounds", "20 sterlings", "20 GBP");
     example!(v, check_finance(38.0, Some("CHF"), Precision::Exact), "38 swiss francs");
-    example!(v, check_finance(447.0, Some("KR"), Precision::Exact), "447 kroner", "447 crowns");
+    example!(v, check_finance(447.0, Some("kr"), Precision::Exact), "447 kroner", "447 crowns");
     example!(v, check_finance(10000.0, Some("DKK"), Precision::Exact), "10,000 DKK");
     example!(v, check_finance(100.0, Some("NOK"), Precision::Exact), "one hundred norwegian crowns", "100 norwegian kroner");
     example!(v, check_finance(2005.0, Some("SEK"), Precision::Exact), "2005 SEK");
@@ -38,7 +96,9 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(89.0, Some("JPY"), Precision::Approximate), "about 89 yen");
     example!(v, check_finance(200.0, Some("CNY"), Precision::Exact), "two hundred yuan");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "7 wons", "7₩");
-    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿", "three bitcoins");
+    example!(v, check_finance(3.0, Some("XBT"), Precision::Exact), "three bitcoins", "3 bitcoins");
+    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿");
+    example!(v, check_finance(3.0, Some("THB"), Precision::Exact), "three baht", "3 THB");
 }
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -148,7 +208,7 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 7, 0, 0]), "in 2.5 hours", "in 2 and an half hours");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "in one hour", "in 1h");
     example!(v, check_moment!(c, [2013, 2, 12, 6, 30]), "in a couple hours", "in a couple of hours");
-    example!(v, check_moment!(c, [2013, 2, 12, 7, 30]), "in a few hours", "in few hours");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 7, 30], Precision::Approximate), "in a few hours", "in few hours");
     example!(v, check_moment!(c, [2013, 2, 13, 4, 30]), "in 24 hours", "in 24hrs", "in 24 hrs");
     example!(v, check_moment!(c, [2013, 2, 13]), "in a day", "a day from now");
     example!(v, check_moment!(c, [2016, 2]), "3 years from today");
@@ -168,8 +228,11 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 5, 12]), "three months hence");
     example!(v, check_moment!(c, [2015, 2]), "two years hence");
     example!(v, check_moment!(c, [2013, 12]), "one year after christmas");
-    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "this summer", "current summer");
-    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "this winter");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "this summer", "current summer");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "this winter");
+    let southern = c.clone().with_hemisphere(Hemisphere::Southern);
+    example!(v, check_moment_span!(southern, [2012, 12, 21], [2013, 3, 20]), "this summer");
+    example!(v, check_moment_span!(southern, [2013, 6, 21], [2013, 9, 23]), "this winter");
     example!(v, check_moment!(c, [2013, 12, 25]), "xmas", "christmas", "christmas day");
     example!(v, check_moment!(c, [2013, 12, 31]), "new year's eve", "new years eve");
     example!(v, check_moment!(c, [2014, 1, 1]), "new year's day", "new years day");
@@ -182,6 +245,16 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 9, 2]), "labor day");
     example!(v, check_moment_span!(c, [2013, 8, 30, 18], [2013, 9, 3, 0]), "labor day weekend");
     example!(v, check_moment!(c, [2013, 10, 31]), "halloween");
+    example!(v, check_moment_span!(c, [2013, 7, 9], [2013, 8, 8]), "ramadan", "ramadhan");
+    example!(v, check_moment!(c, [2013, 7, 9]), "beginning of ramadan", "the first day of ramadan");
+    example!(v, check_moment!(c, [2013, 8, 4]), "laylat al-qadr", "the night of power");
+    example!(v, check_moment!(c, [2013, 8, 8]), "eid al-fitr", "eid ul fitr", "eid");
+    example!(v, check_moment!(c, [2013, 10, 14]), "day of arafah", "arafat day");
+    example!(v, check_moment!(c, [2013, 10, 15]), "eid al-adha", "feast of the sacrifice");
+    example!(v, check_moment!(c, [2013, 11, 5]), "islamic new year", "hijri new year");
+    example!(v, check_moment!(c, [2013, 11, 14]), "ashura", "the day of ashura");
+    example!(v, check_moment!(c, [2014, 1, 14]), "mawlid", "mawlid an-nabi", "the prophet's birthday");
+    example!(v, check_moment!(c, [2013, 6, 6]), "isra and miraj");
     example!(v, check_moment!(c, [2013, 11, 28]), "thanksgiving day", "thanksgiving");
     example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 00]), "this evening", "today evening", "tonight");
     example!(v, check_moment_span!(c, [2013, 2, 12, 00], [2013, 2, 12, 05]), "this night");
@@ -210,6 +283,13 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 2, 12, 11, 30], [2013, 2, 12, 13, 30]), "11:30-1:30", "11:30-1:30", "11:30-1:30", "11:30-1:30", "11:30-1:30", "11:30-1:30", "11:30-1:30");
     example!(v, check_moment!(c, [2013, 9, 21, 13, 30]), "1:30 PM on Sat, Sep 21");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 26]), "within 2 weeks");
+    example!(v, check_moment!(c, [2013, 2, 15]), "in 3 business days", "in 3 working days");
+    example!(v, check_moment!(c, [2013, 2, 13]), "next business day", "the next working day", "in 1 business day");
+    example!(v, check_moment!(c, [2013, 2, 11]), "previous business day", "the previous working day");
+    example!(v, check_moment!(c, [2013, 3, 29]), "last business day of march", "the last working day in march");
+    example!(v, check_moment!(c, [2013, 2, 28]), "last business day of the month", "the last business day of this month");
+    example!(v, check_moment!(c, [2013, 3, 4]), "second business day of march");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 20]), "within 5 business days", "within 5 working days");
     example!(v, check_moment_with_direction!(c, [2013, 2, 12, 14, 0], Direction::Before), "until 2:00pm", "through 2:00pm");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 12, 14]), "by 2:00pm");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 13, 0]), "by EOD");
@@ -240,6 +320,7 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 2]), "during two hours", "for 2 hours");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "circa 3 hours", "three or so hours");
     example!(v, check_duration!([0, 0, 0, 1], Precision::Approximate), "about one day", "approximately 1 day");
     example!(v, check_duration!([0, 2, 0]), "during two months", "for 2 months");
     example!(v, check_duration!([1]), "during a year");
@@ -253,6 +334,9 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 1]), "during one hour", "for 1h");
     example!(v, check_duration!([0, 0, 2]), "for 2 weeks");
     example!(v, check_duration!([0, 0, 0, 2], Precision::Approximate), "around two days");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "two thirds of an hour");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "three quarters of an hour");
+    example!(v, check_duration!([0, 0, 0, 0, 6]), "a quarter of a day");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -311,4 +395,49 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "the 3rd", "3rd", "third");
     example!(v, check_ordinal(2), "the 2nd", "2nd", "second");
     example!(v, check_ordinal(21), "the twenty first");
-}
\ No newline at end of file
+    example!(v, check_fraction(2, 3), "two thirds");
+    example!(v, check_fraction(3, 4), "three quarters", "three fourths");
+    example!(v, check_fraction(1, 5), "a fifth", "one fifth");
+    example!(v, check_fraction(1, 2), "a half", "one half");
+    example!(v, check_integer(53), "LIII");
+    example!(v, check_integer(2019), "MMXIX");
+    example!(v, check_ordinal(14), "chapter XIV");
+    example!(v, check_ordinal(2), "world war II");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "about 20", "around twenty", "roughly 20", "20 or so");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("5551234"), "five five five one two three four");
+    example!(v, check_digit_sequence("0612"), "zero six one two", "oh six one two", "0612");
+    example!(v, check_digit_sequence("0612345678"), "06 12 34 56 78");
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "between 20 and 30", "from twenty to thirty");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3 - 4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "under 10", "less than ten");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "at least 5", "no less than five");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("EUR")), "between 20 and 30 euros", "between 20 euros and 30 euros", "from 20 to 30 euros", "20 - 30 euros");
+    example!(v, check_finance_interval(Some(IntervalBound::exclusive(100.0)), None, Some("$")), "over 100$", "more than 100 dollars");
+    example!(v, check_finance_interval(None, Some(IntervalBound::inclusive(50.0)), Some("EUR")), "at most 50 euros", "up to fifty euros");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "between 20 and 25 degrees", "from 20° to 25°");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("celsius")), "below 5 degrees celsius", "under 5°C");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "between 2 and 3 hours", "from two to three hours");
+    example!(v, check_duration_interval(None, Some(IntervalBound::exclusive(period!(0, 0, 0, 0, 0, 30)))), "less than 30 minutes", "under thirty minutes");
+}
+
+pub fn examples_spoken_forms(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(3), "uh three", "three um");
+    example!(v, check_integer(23), "twenty uh twenty three", "twenty uh three");
+    example!(v, check_integer(250), "two hundred um fifty");
+    example!(v, check_integer(123), "a hundred and twenty three");
+    example!(v, check_integer(1000), "a thousand");
+    example!(v, check_moment!(c, [2013, 2, 12, 5]), "oh five hundred", "zero five hundred hours");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "oh five thirty");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 5]), "nine oh five");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 30]), "nine uh thirty", "uh nine thirty");
+    example!(v, check_moment!(c, [2013, 2, 12, 9]), "at uh nine");
+    example!(v, check_moment!(c, [2020]), "twenty twenty");
+    example!(v, check_moment!(c, [2005]), "twenty oh five");
+}
@@ -7,33 +7,54 @@ mod rules;
 mod training;
 
 use rustling_ontology_values::DimensionKind::*;
+use rustling_ontology_values::helpers::{self, SeparatorPolicy};
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    rule_set_with_separators(&separators())
+}
+
+/// Digit grouping and decimal separators read by default
+pub fn separators() -> Vec<SeparatorPolicy> {
+    vec![SeparatorPolicy::DotGroupsCommaDecimal]
+}
+
+pub fn rule_set_with_separators(separators: &[SeparatorPolicy]) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules::rules_numbers(&mut b)?;
+    helpers::rules_separated_numbers(&mut b, separators)?;
+    helpers::rules_digit_fractions(&mut b)?;
+    rules::rules_digit_sequence(&mut b)?;
+    helpers::rules_digit_sequences(&mut b)?;
     rules::rules_time(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;
+    rules::rules_distance(&mut b)?;
+    rules::rules_ratio(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    rules::rules_interval(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Time, Duration, Temperature, Distance, AmountOfMoney, Percentage, NumberInterval, AmountOfMoneyInterval, TemperatureInterval, DurationInterval, Ratio, DigitSequence];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     let mut v = vec![];
     training::examples_numbers(&mut v);
+    training::examples_digit_sequence(&mut v);
     training::examples_percentage(&mut v);
     training::examples_temperature(&mut v);
+    training::examples_distance(&mut v);
+    training::examples_ratio(&mut v);
     training::examples_finance(&mut v);
     training::examples_durations(&mut v);
     training::examples_time(&mut v);
+    training::examples_interval(&mut v);
     v
 }
 
@@ -59,4 +80,10 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+    #[test]
+    fn test_ambiguous_roman_numerals() {
+        for numeral in rules::AMBIGUOUS_ROMAN_NUMERALS {
+            assert!(helpers::roman_numeral(numeral).is_ok(), format!("Not a Roman numeral: {:?}", numeral));
+        }
+    }
 }
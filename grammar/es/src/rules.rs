@@ -2,7 +2,29 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, Season};
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    let denominators = [
+        ("medi", 2),
+        ("terci", 3),
+        ("cuart", 4),
+        ("quint", 5),
+        ("sext", 6),
+        ("septim", 7),
+        ("séptim", 7),
+        ("octav", 8),
+        ("noven", 9),
+        ("decim", 10),
+        ("décim", 10),
+        ("centesim", 100),
+        ("centésim", 100),
+    ];
+    denominators.iter()
+        .find(|&&(prefix, _)| word.starts_with(prefix))
+        .map(|&(_, denominator)| denominator)
+        .ok_or_else(|| RuleError::Invalid.into())
+}
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -14,99 +36,121 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: Some((r#"entre"#, r#"y"#)),
+        from_to: Some((r#"de|desde"#, r#"a"#)),
+        range: r#"-|~"#,
+        open: vec![
+            (OpenInterval::Under, helpers::OpenIntervalMarker::Before(r#"menos de|por debajo de"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::Before(r#"m[aá]s de|por encima de"#)),
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::Before(r#"al menos|por lo menos|como m[ií]nimo"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::Before(r#"como m[aá]ximo|hasta"#)),
+        ],
+    })
+}
+
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              b.reg(r#"y"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_2("intersect",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_1_terminal("$",
         b.reg(r#"\$|d[oóò]lar(?:es)?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
     );
     b.rule_1_terminal("EUR",
         b.reg(r#"€|(?:[e€]uro?s?)"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
         b.reg(r#"(?:pound|libra)s?|£"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("USD",
         b.reg(r#"us[d\$]|d[oóò]lar(?:es)? (?:estadounidense|americano)s?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("CAD",
                       b.reg(r#"cad|d[oóò]lar(?:es)? canadienses?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
     );
     b.rule_1_terminal("AUD",
                       b.reg(r#"d[oóò]lar(?:es)? australianos?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
+    );
+    b.rule_1_terminal("฿",
+        b.reg(r#"฿"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Baht)) })
+    );
+    b.rule_1_terminal("THB",
+        b.reg(r#"bahts?|thb"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::THB)) })
     );
     b.rule_1_terminal("Bitcoin",
-        b.reg(r#"฿|bitc[oóò]in(?:e?s)?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
+        b.reg(r#"bitc[oóò]in(?:e?s)?"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
     );
     b.rule_1_terminal("GBP",
         b.reg(r#"gbp|libras? esterlinas?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("JPY",
                       b.reg(r#"jpy|yen(?:es)?(?: japoneses?)?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
     );
     b.rule_1_terminal("¥",
                       b.reg(r#"¥"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("¥") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
     );
     b.rule_1_terminal("KRW",
                       b.reg(r#"₩|krw|won(?:es)?(?: surcoreanos?)?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
     );
     b.rule_1_terminal("RMB|CNH|CNY",
                       b.reg(r#"cny|cnh|rmb|yuan(?:es)?(?: chinos?)?|renmimbis?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CNY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
     );
     b.rule_1_terminal("INR",
                       b.reg(r#"rupias?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
     );
     b.rule_1_terminal("HKD",
                       b.reg(r#"hkd|d[oóò]lar(?:es)? de hong[- ]kong"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("HKD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
     );
     b.rule_1_terminal("CHF",
                       b.reg(r#"chf|francos suizos?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
     );
     b.rule_1_terminal("KR",
                       b.reg(r#"kr|coronas?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Krone)) })
     );
     b.rule_1_terminal("DKK",
                       b.reg(r#"dkk|coronas? danesas?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("DKK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::DKK)) })
     );
     b.rule_1_terminal("NOK",
                       b.reg(r#"nok|coronas? noruegas?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("NOK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::NOK)) })
     );
     b.rule_1_terminal("SEK",
                       b.reg(r#"sek|coronas? suecas?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("SEK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SEK)) })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"c[eéè]nt(?:avo|imo)s?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
     );
     b.rule_2("<amount> <unit>",
              number_check!(),
@@ -114,6 +158,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -125,6 +170,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -135,6 +181,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     precision: b.value().precision(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -223,7 +270,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<integer> <unit-of-duration> and a half",
              integer_check_by_range!(0),
@@ -231,7 +278,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"y media"#)?,
              |integer, uod, _| {
                  let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
              }
     );
     b.rule_3("<integer> <unit-of-duration> and a quarter",
@@ -240,7 +287,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"y cuarto"#)?,
              |integer, uod, _| {
                  let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
              }
     );
     b.rule_2("in <duration> (future moment)",
@@ -274,6 +321,12 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"m[aáà]s tarde|despu[eéè]s"#)?,
              |duration, _| duration.value().in_present()
     );
+    b.rule_3("<fraction> de <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"de(?: una?)?"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| helpers::fraction_of_grain(fraction.value().fraction().ok_or(RuleError::Invalid)?, uod.value().grain)
+    );
     Ok(())
 }
 
@@ -1212,23 +1265,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"verano"#)?,
-                      |_| helpers::month_day(6, 21)?
-                          .span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"oto[ñn]o"#)?,
-                      |_| helpers::month_day(9, 23)?
-                          .span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"invierno"#)?,
-                      |_| helpers::month_day(12, 21)?
-                          .span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"primavera"#)?,
-                      |_| helpers::month_day(3, 20)?
-                          .span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_2("el <time>",
              b.reg(r#"el|la"#)?,
@@ -1382,6 +1431,43 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"en adelante"#)?,
              |time, _| Ok(time.value().clone().mark_after_start())
     );
+    b.rule_3("en <integer> días hábiles",
+             b.reg(r#"en|dentro de"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"d[ií]as? (?:h[aá]bil(?:es)?|laborables?)"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_3("en menos de <integer> días hábiles",
+             b.reg(r#"en (?:los pr[oó]ximos|menos de)|en un plazo de"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"d[ií]as? (?:h[aá]bil(?:es)?|laborables?)"#)?,
+             |_, integer, _| helpers::cycle_nth(Grain::Second, 0)?
+                    .span_to(&helpers::business_day_nth(integer.value().value)?, true)
+    );
+    b.rule_1_terminal("próximo día hábil",
+                      b.reg(r#"(?:el )?(?:pr[oó]ximo d[ií]a (?:h[aá]bil|laborable)|d[ií]a (?:h[aá]bil|laborable) siguiente)"#)?,
+                      |_| helpers::business_day_nth(1)
+    );
+    b.rule_1_terminal("día hábil anterior",
+                      b.reg(r#"(?:el )?d[ií]a (?:h[aá]bil|laborable) anterior"#)?,
+                      |_| helpers::business_day_nth(-1)
+    );
+    b.rule_1_terminal("último día hábil del mes",
+                      b.reg(r#"(?:el )?[uú]ltimo d[ií]a (?:h[aá]bil|laborable) del mes"#)?,
+                      |_| helpers::business_day()?.last_of(&helpers::cycle_nth(Grain::Month, 0)?)
+    );
+    b.rule_2("último día hábil de <time>",
+             b.reg(r#"(?:el )?[uú]ltimo d[ií]a (?:h[aá]bil|laborable) (?:de(?:l)?)"#)?,
+             time_check!(),
+             |_, time| helpers::business_day()?.last_of(time.value())
+    );
+    b.rule_4("<ordinal> día hábil de <time>",
+             b.reg(r#"el"#)?,
+             ordinal_check!(),
+             b.reg(r#"d[ií]a (?:h[aá]bil|laborable) (?:de(?:l)?)"#)?,
+             time_check!(),
+             |_, ordinal, _, time| helpers::business_day()?.the_nth_after(ordinal.value().value - 1, time.value())
+    );
     Ok(())
 }
 
@@ -1389,6 +1475,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     b.rule_1("number as temp", number_check!(), |a| {
         Ok(TemperatureValue {
             value: a.value().value(),
+            precision: a.value().precision(),
             unit: None,
             latent: true,
         })
@@ -1399,6 +1486,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("degree"),
                      latent: false,
                  })
@@ -1409,6 +1497,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -1420,6 +1509,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("kelvin"),
                      latent: false,
                  })
@@ -1430,6 +1520,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -1440,6 +1531,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
+                     precision: a.value().precision,
                      latent: false,
                      ..*a.value()
                  })
@@ -1447,6 +1539,88 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> kilometer",
+             number_check!(),
+             b.reg(r#"(?:kil[oó]metros?|kms?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Kilometer).precision(a.value().precision()))
+    );
+    b.rule_2("<number> meter",
+             number_check!(),
+             b.reg(r#"(?:metros?|m)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Meter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> centimeter",
+             number_check!(),
+             b.reg(r#"(?:cent[ií]metros?|cm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Centimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> millimeter",
+             number_check!(),
+             b.reg(r#"(?:mil[ií]metros?|mm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Millimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> mile",
+             number_check!(),
+             b.reg(r#"(?:millas?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Mile).precision(a.value().precision()))
+    );
+    b.rule_2("<number> yard",
+             number_check!(),
+             b.reg(r#"(?:yardas?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Yard).precision(a.value().precision()))
+    );
+    b.rule_2("<number> foot",
+             number_check!(),
+             b.reg(r#"(?:pies?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Foot).precision(a.value().precision()))
+    );
+    b.rule_2("<number> inch",
+             number_check!(),
+             b.reg(r#"(?:pulgadas?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Inch).precision(a.value().precision()))
+    );
+    b.rule_2("aproximadamente <distance>",
+             b.reg(r#"aproximadamente|cerca de|casi|m[aáà]s o menos"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Approximate))
+    );
+    b.rule_2("exactamente <distance>",
+             b.reg(r#"exactamente"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_ratio(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("ratio (a:b)",
+        b.reg(r#"(\d{1,3}) ?: ?(\d{1,3})"#)?,
+        |text_match| helpers::colon_ratio(text_match.group(1), text_match.group(2))
+    );
+    b.rule_3("<integer> sobre <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"sobre|de cada"#)?,
+             integer_check_by_range!(1),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value))
+    );
+    b.rule_3("<integer> a <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"a"#)?,
+             integer_check_by_range!(0),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value).latent(true))
+    );
+    b.rule_2("resultado de <ratio>",
+             b.reg(r#"(?:el |la )?(?:resultado|marcador|cuota|proporción|relación)(?: de| es| fue)?"#)?,
+             ratio_check!(),
+             |_, ratio| Ok(ratio.value().latent(false))
+    );
+    Ok(())
+}
+
+/// Roman numerals which are also Spanish words or unit symbols
+pub const AMBIGUOUS_ROMAN_NUMERALS: &'static [&'static str] = &["mi", "di", "vi", "xi", "cv", "xl", "cd", "dc", "mm", "cm", "ml", "cl", "dl", "mix"];
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -1631,27 +1805,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("integer (numeric)",
                       b.reg(r#"(\d{1,18})"#)?,
                       |text_match| IntegerValue::new(text_match.group(0).parse()?));
-    b.rule_1_terminal("integer with thousands separator .",
-                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "");
-                          let value: i64 = reformatted_string.parse()?;
-                          IntegerValue::new(value)
-                      });
-    b.rule_1_terminal("decimal number",
-                      b.reg(r#"(\d*,\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", ".");
-                          let value: f32 = reformatted_string.parse()?;
-                          FloatValue::new(value)
-                      });
     b.rule_3("number dot number",
              number_check!(|number: &NumberValue| !number.prefixed()),
              b.reg(r#"punto|coma"#)?,
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| {
                  let power = b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
@@ -1664,20 +1824,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, zeros, b| {
                  let power = zeros.group(0).split_whitespace().count() + b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
                  })
              });
 
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                          let value: f32 = reformatted_string.parse()?;
-                          FloatValue::new(value)
-                      });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|menos"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -1723,7 +1876,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                              .into()
                      }
                      NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f32);
+                         let product = float.value * (multiplier as f64);
                          if product.floor() == product {
                              IntegerValue {
                                  value: product as i64,
@@ -1826,5 +1979,48 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           Ok(OrdinalValue::new(value))
                       }
     );
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"((?:medi|terci|cuart|quint|sext|s[eé]ptim|octav|noven|d[eé]cim|cent[eé]sim)[oa]s?)"#)?,
+        |integer, text_match| FloatValue::fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    helpers::rules_roman_numerals(b, &helpers::RomanNumeralWords {
+        ordinal_prefix: r#"cap[ií]tulo|parte|volumen|tomo|libro|acto|escena|secci[oó]n|art[ií]culo|siglo"#,
+        ordinal_suffix: None,
+        ambiguous: AMBIGUOUS_ROMAN_NUMERALS,
+    })?;
+    b.rule_2("aproximadamente <number>",
+             b.reg(r#"(?:aproximadamente|alrededor de|cerca de|más o menos|unos|unas)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> aproximadamente",
+             number_check!(),
+             b.reg(r#"aproximadamente|más o menos"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(cero|uno|dos|tres|cuatro|cinco|seis|siete|ocho|nueve)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "cero" => "0",
+                "uno" => "1",
+                "dos" => "2",
+                "tres" => "3",
+                "cuatro" => "4",
+                "cinco" => "5",
+                "seis" => "6",
+                "siete" => "7",
+                "ocho" => "8",
+                "nueve" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
     Ok(())
 }
@@ -120,6 +120,13 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 19]), "dentro de siete dias", "en siete dias");
     example!(v, check_moment!(c, [2013, 2, 19]), "dentro de una semana");
     example!(v, check_moment!(c, [2013, 4, 12]), "dentro de dos meses", "en dos meses");
+    example!(v, check_moment!(c, [2013, 2, 15]), "en 3 días hábiles", "dentro de 3 días laborables");
+    example!(v, check_moment!(c, [2013, 2, 13]), "el próximo día hábil", "el día laborable siguiente");
+    example!(v, check_moment!(c, [2013, 2, 11]), "el día hábil anterior");
+    example!(v, check_moment!(c, [2013, 3, 29]), "último día hábil de marzo", "el último día laborable de marzo");
+    example!(v, check_moment!(c, [2013, 2, 28]), "el último día hábil del mes");
+    example!(v, check_moment!(c, [2013, 3, 4]), "el segundo día hábil de marzo");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 20]), "en los próximos 5 días hábiles", "en un plazo de 5 días laborables");
     example!(v, check_moment!(c, [2014, 2]), "dentro de un año", "en un año");
     example!(v, check_moment!(c, [2013, 2, 12, 2, 30]), "hace dos horas");
     example!(v, check_moment!(c, [2013, 1, 22]), "hace tres semanas");
@@ -127,8 +134,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2011, 2]), "hace dos años");
 
     // Seasons
-    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "este verano");
-    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "este invierno");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "este verano");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "este invierno");
 
     // Holidays
     // TODO
@@ -250,6 +257,7 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 2]), "durante dos horas", "por dos horas");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "alrededor de 3 horas");
     // TODO: support "un día entero", "todo el día"
     example!(v, check_duration!([0, 0, 0, 1]), "durante un día", "durante un dia", "todo un día");
     example!(v, check_duration!([0, 1, 0]), "durante un mes", "por un mes");
@@ -260,6 +268,8 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante un cuarto de hora más o menos", "durante un cuarto de hora aproximadamente");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante una hora", "por una hora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 semanas", "por dos semanas");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "tres cuartos de hora");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "dos tercios de hora");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -295,6 +305,17 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "3o", "3o", "3a", "3º", "3ª", "tercero", "tercera");
     example!(v, check_ordinal(2), "segundo");
     example!(v, check_ordinal(5), "quintos");
+    example!(v, check_fraction(2, 3), "dos tercios");
+    example!(v, check_fraction(3, 4), "tres cuartos");
+    example!(v, check_fraction(1, 5), "un quinto");
+    example!(v, check_integer(53), "LIII");
+    example!(v, check_ordinal(20), "siglo XX", "capítulo XX");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "aproximadamente 20", "unos veinte", "alrededor de 20");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "cero seis uno dos", "0612");
+    example!(v, check_digit_sequence("555"), "cinco cinco cinco");
 }
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -305,6 +326,7 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(3.0, Some("degree")), "tres grados", "3 grados", "3°", "3 °");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "alrededor de 20 grados");
     example!(v, check_temperature(32.0, Some("celsius")), "treinta y dos grados celsius", "treinta y dos grados centígrados", "32°C", "32° C", "32° c", "32°c", "32 °c");
     example!(v, check_temperature(-27.0, Some("degree")), "menos 27 grados", "27 grados bajo cero","menos veintisiete grados");
     example!(v, check_temperature(-27.0, Some("celsius")), "menos 27 grados celsius", "menos 27 grados centigrados", "-27C", "-27°C", "-27° C", "-27°c", "-27° c", "- 27 c");
@@ -313,6 +335,24 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(10.0, Some("kelvin")), "diez kelvin", "10°K", "10° K", "10 °K", "10°k", "diez grados kelvin");
 }
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(3.0, DistanceUnit::Kilometer, Precision::Exact), "3 km", "tres kilómetros", "3 kilometros");
+    example!(v, check_distance(10.0, DistanceUnit::Meter, Precision::Exact), "diez metros", "10m", "10 metros");
+    example!(v, check_distance(25.0, DistanceUnit::Centimeter, Precision::Exact), "veinticinco centímetros", "25cm");
+    example!(v, check_distance(4.0, DistanceUnit::Millimeter, Precision::Exact), "cuatro milímetros", "4 mm");
+    example!(v, check_distance(2.0, DistanceUnit::Mile, Precision::Exact), "dos millas", "2 millas");
+    example!(v, check_distance(6.0, DistanceUnit::Foot, Precision::Exact), "seis pies", "6 pies");
+    example!(v, check_distance(12.0, DistanceUnit::Inch, Precision::Exact), "doce pulgadas", "12 pulgadas");
+    example!(v, check_distance(5.0, DistanceUnit::Kilometer, Precision::Approximate), "aproximadamente 5 km", "cerca de cinco kilómetros", "más o menos 5 km");
+    example!(v, check_distance(20.0, DistanceUnit::Meter, Precision::Exact), "exactamente veinte metros", "exactamente 20 m");
+}
+
+pub fn examples_ratio(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_ratio(3, 2), "3:2", "el resultado fue 3:2");
+    example!(v, check_ratio(3, 5), "3 sobre 5", "tres de cada cinco");
+    example!(v, check_ratio(4, 1), "4 a 1", "cuatro a uno");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800 $", "800$", "ochocientos dólares");
     example!(v, check_finance(10.0, Some("$"), Precision::Approximate), "unos diez dólares", "diez dólares más o menos");
@@ -322,11 +362,12 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(0.0, Some("HKD"), Precision::Exact), "cero dólares de hong kong");
     example!(v, check_finance(125.0, Some("CAD"), Precision::Exact), "125 CAD", "ciento veinticinco dólares canadienses");
     example!(v, check_finance(45.0, Some("EUR"), Precision::Exact), "45€", "45 €", "cuarenta y cinco euros");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Approximate), "alrededor de 20 euros");
     example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "2,05 €", "2,05€", "dos euros y cinco céntimos");
     example!(v, check_finance(2.0, Some("£"), Precision::Exact), "2 £", "2£", "dos libras");
     example!(v, check_finance(20.0, Some("GBP"), Precision::Exact), "veinte libras esterlinas");
     example!(v, check_finance(38.0, Some("CHF"), Precision::Exact), "38 francos suizos", "treinta y ocho francos suizos");
-    example!(v, check_finance(447.0, Some("KR"), Precision::Exact), "447 coronas", "cuatrocientas cuarenta y siete coronas");
+    example!(v, check_finance(447.0, Some("kr"), Precision::Exact), "447 coronas", "cuatrocientas cuarenta y siete coronas");
     example!(v, check_finance(10000.0, Some("DKK"), Precision::Exact), "diez mil DKK", "10.000 coronas danesas");
     example!(v, check_finance(100.0, Some("NOK"), Precision::Exact), "100 coronas noruegas", "cien coronas noruegas");
     example!(v, check_finance(2005.0, Some("SEK"), Precision::Exact), "2005 coronas suecas", "dos mil cinco coronas suecas");
@@ -337,7 +378,23 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(8.0, Some("¥"), Precision::Exact), "8¥");
     example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "cien yuanes exactos", "exactamente 100 yuanes chinos");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "7 wones", "7₩", "siete wones surcoreanos");
-    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿", "tres bitcoins");
+    example!(v, check_finance(3.0, Some("XBT"), Precision::Exact), "tres bitcoins");
+    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿");
+    example!(v, check_finance(3.0, Some("THB"), Precision::Exact), "tres bahts", "3 THB");
     example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "unos quince dólares", "unos quince dolares");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "tres millones de euros");
-}
\ No newline at end of file
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "entre 20 y 30", "de veinte a treinta");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3 - 4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "menos de 10", "menos de diez");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "al menos 5", "por lo menos cinco");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("EUR")), "entre 20 y 30 euros", "entre 20 euros y 30 euros", "de 20 a 30 euros", "20 - 30 euros");
+    example!(v, check_finance_interval(Some(IntervalBound::exclusive(100.0)), None, Some("$")), "más de 100 $", "mas de 100$");
+    example!(v, check_finance_interval(None, Some(IntervalBound::inclusive(50.0)), Some("EUR")), "como máximo 50 euros", "hasta cincuenta euros");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "entre 20 y 25 grados", "de 20° a 25°");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("celsius")), "por debajo de 5 grados celsius", "menos de 5°C");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "entre 2 y 3 horas", "de dos a tres horas");
+    example!(v, check_duration_interval(None, Some(IntervalBound::exclusive(period!(0, 0, 0, 0, 0, 30)))), "menos de 30 minutos", "menos de treinta minutos");
+}
@@ -7,32 +7,69 @@ pub mod rules;
 pub mod training;
 
 use rustling_ontology_values::DimensionKind::*;
+use rustling_ontology_values::helpers::{self, SeparatorPolicy};
 
+/// Rules for plain text, without the spoken forms of speech recognition output
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    rule_set_with_separators(&separators())
+}
+
+/// Digit grouping and decimal separators read by default
+pub fn separators() -> Vec<SeparatorPolicy> {
+    vec![SeparatorPolicy::DotGroupsCommaDecimal]
+}
+
+pub fn rule_set_with_separators(separators: &[SeparatorPolicy]) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    rule_set_with_options(separators, false)
+}
+
+/// Rules with the given separators, and the spoken forms of speech recognition output when
+/// `spoken_forms` is set. The spoken form rules come last so that the other rules keep the
+/// symbols the model was trained with.
+pub fn rule_set_with_options(separators: &[SeparatorPolicy], spoken_forms: bool) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules::rules_numbers(&mut b)?;
+    helpers::rules_separated_numbers(&mut b, separators)?;
+    helpers::rules_digit_fractions(&mut b)?;
+    rules::rules_digit_sequence(&mut b)?;
+    helpers::rules_digit_sequences(&mut b)?;
     rules::rules_time(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;
+    rules::rules_distance(&mut b)?;
+    rules::rules_quantity(&mut b)?;
+    rules::rules_rate(&mut b)?;
+    rules::rules_ratio(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    rules::rules_interval(&mut b)?;
+    if spoken_forms {
+        rules::rules_spoken_forms(&mut b)?;
+    }
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Duration, Time, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Duration, Time, Temperature, Distance, Quantity, Rate, AmountOfMoney, Percentage, NumberInterval, AmountOfMoneyInterval, TemperatureInterval, DurationInterval, Ratio, DigitSequence];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     let mut v = vec![];
     training::examples_numbers(&mut v);
+    training::examples_digit_sequence(&mut v);
     training::examples_time(&mut v);
     training::examples_temperature(&mut v);
+    training::examples_distance(&mut v);
+    training::examples_quantity(&mut v);
+    training::examples_rate(&mut v);
+    training::examples_ratio(&mut v);
     training::examples_finance(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_interval(&mut v);
+    training::examples_spoken_forms(&mut v);
     v
 }
 
@@ -54,8 +91,20 @@ mod test {
     }
     #[test]
     fn test_examples() {
-        let rules = rule_set().unwrap();
+        let rules = rule_set_with_options(&separators(), true).unwrap();
         let examples = examples();
         assert_examples(&rules, examples);
     }
+    #[test]
+    fn test_spoken_forms_are_opt_in() {
+        let rules = rule_set().unwrap();
+        let stash = rules.apply_all("euh trois").unwrap();
+        assert!(stash.iter().all(|candidate| candidate.root_node.byte_range != Range(0, 9)));
+    }
+    #[test]
+    fn test_ambiguous_roman_numerals() {
+        for numeral in rules::AMBIGUOUS_ROMAN_NUMERALS {
+            assert!(helpers::roman_numeral(numeral).is_ok(), format!("Not a Roman numeral: {:?}", numeral));
+        }
+    }
 }
@@ -2,7 +2,26 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, Season};
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    let denominators = [
+        ("demi", 2),
+        ("tiers", 3),
+        ("quart", 4),
+        ("cinqui", 5),
+        ("sixi", 6),
+        ("septi", 7),
+        ("huiti", 8),
+        ("neuvi", 9),
+        ("dixi", 10),
+        ("centi", 100),
+    ];
+    denominators.iter()
+        .find(|&&(prefix, _)| word.starts_with(prefix))
+        .map(|&(_, denominator)| denominator)
+        .ok_or_else(|| RuleError::Invalid.into())
+}
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -13,103 +32,125 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: Some((r#"entre"#, r#"et"#)),
+        from_to: Some((r#"de"#, r#"[àa]"#)),
+        range: r#"-|~"#,
+        open: vec![
+            (OpenInterval::Under, helpers::OpenIntervalMarker::Before(r#"moins de|en dessous de|inf[ée]rieure? [àa]"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::Before(r#"plus de|au dessus de|sup[ée]rieure? [àa]"#)),
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::Before(r#"au moins|minimum"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::Before(r#"au plus|au maximum|maximum|jusqu'[àa]"#)),
+        ],
+    })
+}
+
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
              amount_of_money_check!(),
              b.reg(r#"et"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_2("intersect",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_1_terminal("$",
         b.reg(r#"\$|dollars?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
     );
     b.rule_1_terminal("EUR",
         b.reg(r#"€|(?:[e€]uro?s?)"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
         b.reg(r#"£|livres?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("USD",
         b.reg(r#"us[d\$]|dollars? am[eé]ricains?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("AUD",
         b.reg(r#"au[d\$]|dollars? australiens?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
     );
     b.rule_1_terminal("CAD",
         b.reg(r#"cad|dollars? canadiens?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
     );
     b.rule_1_terminal("HKD",
         b.reg(r#"hkd|dollars? de hong[- ]kong"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("HKD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
     );
     b.rule_1_terminal("KR",
         b.reg(r#"kr|couronnes?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("KR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Krone)) })
     );
     b.rule_1_terminal("DKK",
         b.reg(r#"dkk|couronnes? danoises?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("DKK") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::DKK)) })
     );
     b.rule_1_terminal("NOK",
         b.reg(r#"nok|couronnes? norv[ée]giennes?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("NOK") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::NOK)) })
     );
     b.rule_1_terminal("SEK",
         b.reg(r#"sek|couronnes? su[ée]doises?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("SEK") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SEK)) })
     );
     b.rule_1_terminal("CHF",
         b.reg(r#"chf|francs? suisses?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
     );
     b.rule_1_terminal("RUB",
         b.reg(r#"rub|roubles?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("RUB") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::RUB)) })
     );
     b.rule_1_terminal("INR",
         b.reg(r#"inr|roupies?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("INR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
     );
     b.rule_1_terminal("JPY",
         b.reg(r#"jpy|yens?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
     );
     b.rule_1_terminal("RMB|CNH|CNY",
         b.reg(r#"cny|cnh|rmb|yuans?|renmimbis?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CNY") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
     );
     b.rule_1_terminal("¥",
         b.reg(r#"¥"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("¥") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
     );
     b.rule_1_terminal("KRW",
         b.reg(r#"₩|krw|wons? (?:sud[- ])?cor[ée]ns?|wons?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
+    );
+    b.rule_1_terminal("฿",
+        b.reg(r#"฿"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Baht)) })
+    );
+    b.rule_1_terminal("THB",
+        b.reg(r#"bahts?|thb"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::THB)) })
     );
     b.rule_1_terminal("Bitcoin",
-        b.reg(r#"฿|bitcoins?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
+        b.reg(r#"bitcoins?"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
     );
     b.rule_1_terminal("GBP",
         b.reg(r#"gbp|livres? sterlings?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"centimes?|cents?|penn(?:y|ies)|fens?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
     );
     b.rule_1_terminal("unnamed currency",
                       b.reg(r#"(?:balle)s?"#)?,
@@ -121,6 +162,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -131,8 +173,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         money_unit!(),
         |a, _, b| {
             Ok(AmountOfMoneyValue {
-                value: a.value().value as f32,
-                precision: Exact,
+                value: a.value().value as f64,
+                precision: a.value().precision,
                 unit: b.value().unit,
                 ..AmountOfMoneyValue::default()
             })
@@ -143,7 +185,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         money_unit!(),
         |a, _, b| {
             Ok(AmountOfMoneyValue {
-                value: a.value().value as f32,
+                value: a.value().value as f64,
                 precision: Approximate,
                 unit: b.value().unit,
                 ..AmountOfMoneyValue::default()
@@ -224,13 +266,13 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()))
+             |integer, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<integer> de <unit-of-duration>",
         integer_check!(|integer: &IntegerValue| integer.value >= 0 && integer.group),
         b.reg(r#"d[e']"#)?,
         unit_of_duration_check!(),
-        |integer, _, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()))
+        |integer, _, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<number> h <number>",
              integer_check_by_range!(0),
@@ -248,7 +290,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         b.reg(r#"et quart"#)?,
         |integer, uod, _| {
            let quarter_period: Period = uod.value().grain.quarter_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-           Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+           Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
         }
     );
     b.rule_3("<integer> <unit-of-duration> et demie",
@@ -257,7 +299,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         b.reg(r#"et demie?"#)?,
         |integer, uod, _| {
            let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-           Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+           Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
         }
     );
     b.rule_3("<duration> et <duration>",
@@ -320,6 +362,12 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              time_check!(),
              |duration, _, time| duration.value().before(time.value())
     );
+    b.rule_3("<fraction> de <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"d'une?|d'|de"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| helpers::fraction_of_grain(fraction.value().fraction().ok_or(RuleError::Invalid)?, uod.value().grain)
+    );
     Ok(())
 }
 
@@ -657,6 +705,42 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 1, &helpers::easter()?)?
                 .form(Form::Celebration))
     );
+    b.rule_1_terminal("nouvel an hégirien",
+        b.reg(r#"(?:le )?(?:nouvel an (?:h[ée]girien|musulman|islamique)|ras[- ]as[- ]sana|premier (?:jour )?de mouharram)"#)?,
+        |_| Ok(helpers::hijri_month_day(1, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("achoura",
+        b.reg(r#"(?:le jour d'|l')?ach(?:o|ou)ura"#)?,
+        |_| Ok(helpers::hijri_month_day(1, 10)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("mawlid",
+        b.reg(r#"(?:le )?(?:mawlid|mouloud)|la naissance du proph[èe]te"#)?,
+        |_| Ok(helpers::hijri_month_day(3, 12)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("ramadan",
+        b.reg(r#"(?:le |pendant le |durant le )?rama[dt]h?an"#)?,
+        |_| Ok(helpers::hijri_month(9)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("début du ramadan",
+        b.reg(r#"(?:le )?(?:d[ée]but|premier jour) du rama[dt]h?an"#)?,
+        |_| Ok(helpers::hijri_month_day(9, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("nuit du destin",
+        b.reg(r#"(?:la )?nuit du destin|laylat al[- ]qadr"#)?,
+        |_| Ok(helpers::hijri_month_day(9, 27)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("aïd el-fitr",
+        b.reg(r#"(?:l')?a[ïi]d (?:el|al)[- ]?fitr|(?:la )?f[êe]te de (?:la )?rupture du je[ûu]ne"#)?,
+        |_| Ok(helpers::hijri_month_day(10, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("jour d'arafat",
+        b.reg(r#"(?:le )?jour d'arafa[th]?"#)?,
+        |_| Ok(helpers::hijri_month_day(12, 9)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("aïd el-kébir",
+        b.reg(r#"(?:l')?a[ïi]d (?:el|al)[- ]?(?:adha|k[ée]bir)|(?:la )?f[êe]te du (?:sacrifice|mouton)|tabaski"#)?,
+        |_| Ok(helpers::hijri_month_day(12, 10)?.form(Form::Celebration))
+    );
     b.rule_1_terminal("ascension",
         b.reg(r#"(?:la f[eê]te de l'|le jeudi de l'|l'|le jour de l')ascension"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
@@ -1300,19 +1384,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:cet )?(?:été|ete)"#)?,
-        |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+        |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:cet )?automne"#)?,
-        |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+        |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:cet )?hiver"#)?,
-        |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+        |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
         b.reg(r#"(?:ce )?printemps"#)?,
-        |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+        |_| helpers::season(Season::Spring)
     );
     b.rule_2("le <time>",
              b.reg(r#"l[ea]"#)?,
@@ -1573,6 +1657,43 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(1, 31),
              |_, integer| Ok(helpers::day_of_month(integer.value().value as u32)?.mark_after_start())
     );
+    b.rule_3("dans <integer> jours ouvrés",
+             b.reg(r#"dans"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"jours? ouvr(?:é|e|able)s?"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_3("sous <integer> jours ouvrés",
+             b.reg(r#"sous|d'ici"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"jours? ouvr(?:é|e|able)s?"#)?,
+             |_, integer, _| helpers::cycle_nth(Grain::Second, 0)?
+                    .span_to(&helpers::business_day_nth(integer.value().value)?, true)
+    );
+    b.rule_1_terminal("prochain jour ouvré",
+                      b.reg(r#"(?:le )?(?:prochain jour ouvr(?:é|e|able)|jour ouvr(?:é|e|able) (?:prochain|suivant))"#)?,
+                      |_| helpers::business_day_nth(1)
+    );
+    b.rule_1_terminal("jour ouvré précédent",
+                      b.reg(r#"(?:le )?jour ouvr(?:é|e|able) pr[ée]c[ée]dent"#)?,
+                      |_| helpers::business_day_nth(-1)
+    );
+    b.rule_1_terminal("dernier jour ouvré du mois",
+                      b.reg(r#"(?:le )?dernier jour ouvr(?:é|e|able) du mois"#)?,
+                      |_| helpers::business_day()?.last_of(&helpers::cycle_nth(Grain::Month, 0)?)
+    );
+    b.rule_2("dernier jour ouvré de <time>",
+             b.reg(r#"(?:le )?dernier jour ouvr(?:é|e|able) (?:de|du|d')"#)?,
+             time_check!(),
+             |_, time| helpers::business_day()?.last_of(time.value())
+    );
+    b.rule_4("<ordinal> jour ouvré de <time>",
+             b.reg(r#"le"#)?,
+             ordinal_check!(),
+             b.reg(r#"jour ouvr(?:é|e|able) (?:de|du|d')"#)?,
+             time_check!(),
+             |_, ordinal, _, time| helpers::business_day()?.the_nth_after(ordinal.value().value - 1, time.value())
+    );
     Ok(())
 }
 
@@ -1582,6 +1703,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             |a| {
                  Ok(TemperatureValue {
                     value: a.value().value(),
+                    precision: a.value().precision(),
                     unit: None,
                     latent: true,
                 })
@@ -1592,6 +1714,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("degree"),
                      latent: false,
                  })
@@ -1602,6 +1725,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -1612,6 +1736,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -1622,6 +1747,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("kelvin"),
                      latent: false,
                  })
@@ -1632,6 +1758,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
+                     precision: a.value().precision,
                      latent: false,
                      ..*a.value()
                  })
@@ -1639,6 +1766,187 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> kilometer",
+             number_check!(),
+             b.reg(r#"(?:kilom[eè]tres?|kms?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Kilometer).precision(a.value().precision()))
+    );
+    b.rule_2("<number> meter",
+             number_check!(),
+             b.reg(r#"(?:m[eè]tres?|m)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Meter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> centimeter",
+             number_check!(),
+             b.reg(r#"(?:centim[eè]tres?|cm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Centimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> millimeter",
+             number_check!(),
+             b.reg(r#"(?:millim[eè]tres?|mm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Millimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> mile",
+             number_check!(),
+             b.reg(r#"(?:miles?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Mile).precision(a.value().precision()))
+    );
+    b.rule_2("<number> yard",
+             number_check!(),
+             b.reg(r#"(?:yards?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Yard).precision(a.value().precision()))
+    );
+    b.rule_2("<number> foot",
+             number_check!(),
+             b.reg(r#"(?:pieds?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Foot).precision(a.value().precision()))
+    );
+    b.rule_2("<number> inch",
+             number_check!(),
+             b.reg(r#"(?:pouces?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Inch).precision(a.value().precision()))
+    );
+    b.rule_2("environ <distance>",
+             b.reg(r#"environ|presque|(?:autour|pr[eè]s) d[e']|(?:approximative|quasi)ment"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Approximate))
+    );
+    b.rule_2("exactement <distance>",
+             b.reg(r#"(?:tr[eè]s )?exactement|pr[eé]cis[eé]ment"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("milligramme",
+                      b.reg(r#"mg|milligrammes?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Milligram })
+    );
+    b.rule_1_terminal("gramme",
+                      b.reg(r#"g|gr|grammes?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Gram })
+    );
+    b.rule_1_terminal("kilogramme",
+                      b.reg(r#"kg|kilo(?:gramme)?s?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Kilogram })
+    );
+    b.rule_1_terminal("once",
+                      b.reg(r#"onces?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Ounce })
+    );
+    b.rule_1_terminal("livre",
+                      b.reg(r#"livres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Pound })
+    );
+    b.rule_1_terminal("millilitre",
+                      b.reg(r#"ml|millilitres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Milliliter })
+    );
+    b.rule_1_terminal("centilitre",
+                      b.reg(r#"cl|centilitres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Centiliter })
+    );
+    b.rule_1_terminal("litre",
+                      b.reg(r#"l|litres?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Liter })
+    );
+    b.rule_1_terminal("cuillère à café",
+                      b.reg(r#"cuill[eè]res? [àa] caf[ée]"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Teaspoon })
+    );
+    b.rule_1_terminal("cuillère à soupe",
+                      b.reg(r#"cuill[eè]res? [àa] soupe"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Tablespoon })
+    );
+    b.rule_1_terminal("tasse",
+                      b.reg(r#"tasses?"#)?,
+                      |_| Ok(QuantityUnitValue { unit: QuantityUnit::Cup })
+    );
+    b.rule_2("<number> <quantity unit>",
+             number_check!(),
+             quantity_unit!(),
+             |a, unit| Ok(QuantityValue::new(a.value().value(), unit.value().unit))
+    );
+    b.rule_3("<number> de <quantity unit>",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             b.reg(r#"d[e']"#)?,
+             quantity_unit!(),
+             |a, _, unit| Ok(QuantityValue::new(a.value().value(), unit.value().unit))
+    );
+    b.rule_3("<quantity> de <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"de|d'"#)?,
+             b.reg(r#"\p{L}+"#)?,
+             |quantity, _, product| Ok(quantity.value().clone().product(product.byte_range()))
+    );
+    b.rule_3("<fraction> de <quantity unit>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"d'une?|d'|de"#)?,
+             quantity_unit!(),
+             |fraction, _, unit| Ok(QuantityValue::new(fraction.value().value(), unit.value().unit))
+    );
+    Ok(())
+}
+
+pub fn rules_rate(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("<amount-of-money> par <unit-of-duration>",
+             amount_of_money_check!(),
+             b.reg(r#"par|/|(?:de |à )?l'"#)?,
+             unit_of_duration_check!(),
+             |amount, _, unit| Ok(RateValue::new(amount.value().clone(), unit.value()))
+    );
+    b.rule_3("<distance> par <unit-of-duration>",
+             distance_check!(),
+             b.reg(r#"par|/|(?:de |à )?l'"#)?,
+             unit_of_duration_check!(),
+             |distance, _, unit| Ok(RateValue::new(distance.value().clone(), unit.value()))
+    );
+    b.rule_3("<number> par <unit-of-duration>",
+             number_check!(),
+             b.reg(r#"par|/"#)?,
+             unit_of_duration_check!(),
+             |number, _, unit| Ok(RateValue::new(number.value().clone(), unit.value()))
+    );
+    b.rule_3("<integer> fois par <unit-of-duration>",
+             integer_check_by_range!(1),
+             b.reg(r#"fois par"#)?,
+             unit_of_duration_check!(),
+             |integer, _, unit| Ok(RateValue::new(NumberValue::from(integer.value().clone()), unit.value()))
+    );
+    Ok(())
+}
+
+pub fn rules_ratio(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("ratio (a:b)",
+        b.reg(r#"(\d{1,3}) ?: ?(\d{1,3})"#)?,
+        |text_match| helpers::colon_ratio(text_match.group(1), text_match.group(2))
+    );
+    b.rule_3("<integer> sur <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"sur"#)?,
+             integer_check_by_range!(1),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value))
+    );
+    b.rule_3("<integer> à <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"à"#)?,
+             integer_check_by_range!(0),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value).latent(true))
+    );
+    b.rule_2("score de <ratio>",
+             b.reg(r#"(?:le |la |une? )?(?:score|cote|ratio|rapport)(?: de| d'| est de)?"#)?,
+             ratio_check!(),
+             |_, ratio| Ok(ratio.value().latent(false))
+    );
+    Ok(())
+}
+
+/// Roman numerals which are also French words or unit symbols
+pub const AMBIGUOUS_ROMAN_NUMERALS: &'static [&'static str] = &["mi", "ci", "xi", "cv", "xl", "cd", "dc", "mm", "cm", "ml", "cl", "dl", "dix", "mix"];
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -1812,27 +2120,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             let value: i64 = text_match.group(1).parse()?;
             IntegerValue::new(value)
     });
-    b.rule_1_terminal("integer with thousands separator .",
-             b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
-             |text_match| {
-                 let reformatted_string = text_match.group(1).replace(".", "");
-                 let value: i64 = reformatted_string.parse()?;
-                 IntegerValue::new(value)
-             });
-    b.rule_1_terminal("decimal number", 
-        b.reg(r#"(\d*,\d+)"#)?, 
-        |text_match| {
-            let reformatted_string = text_match.group(1).replace(",", ".");
-            let value: f32 = reformatted_string.parse()?;
-            FloatValue::new(value)
-    });
     b.rule_3("number dot number",
         number_check!(|number: &NumberValue| !number.prefixed()),
         b.reg(r#"virgule|point"#)?,
         number_check!(|number: &NumberValue| !number.suffixed()),
         |a, _, b| {
             let power = b.value().value().to_string().chars().count();
-            let coeff = 10.0_f32.powf(-1.0 * power as f32);
+            let coeff = 10.0_f64.powf(-1.0 * power as f64);
             Ok(FloatValue {
                 value: b.value().value() * coeff + a.value().value(),
                 ..FloatValue::default()
@@ -1845,19 +2139,12 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
          number_check!(|number: &NumberValue| !number.suffixed()),
          |a, _, zeros, b| {
              let power = zeros.group(0).split_whitespace().count() + b.value().value().to_string().chars().count();
-             let coeff = 10.0_f32.powf(-1.0 * power as f32);
+             let coeff = 10.0_f64.powf(-1.0 * power as f64);
              Ok(FloatValue {
                  value: b.value().value() * coeff + a.value().value(),
                  ..FloatValue::default()
              })
     });
-    b.rule_1_terminal("decimal with thousands separator",
-             b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-             |text_match| {
-                 let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                 let value: f32 = reformatted_string.parse()?;
-                 FloatValue::new(value)
-             });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|moins"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -1902,7 +2189,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                        .into()
                }
                NumberValue::Float(float) => {
-            let product = float.value * (multiplier as f32);
+            let product = float.value * (multiplier as f64);
             if product.floor() == product {
                 IntegerValue {
                         value: product as i64,
@@ -2079,7 +2366,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         integer_check_by_range!(0, 99),
         b.reg(r#"et demie?"#)?,
         |integer, _| {
-            FloatValue::new(integer.value().value as f32 + 0.5)
+            FloatValue::new(integer.value().value as f64 + 0.5)
         }
     );
     b.rule_1_terminal("70, 80, 90 (Belgium and Switzerland)",
@@ -2188,5 +2475,96 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              ordinal_check!(),
              |_, a| Ok((*a.value()).prefixed())
     );
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"(demie?|tiers|quart|cinqui[eè]me|sixi[eè]me|septi[eè]me|huiti[eè]me|neuvi[eè]me|dixi[eè]me|centi[eè]me)s?"#)?,
+        |integer, text_match| FloatValue::fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_1_terminal("half",
+        b.reg(r#"la moiti[ée]"#)?,
+        |_| FloatValue::fraction(1, 2)
+    );
+    helpers::rules_roman_numerals(b, &helpers::RomanNumeralWords {
+        ordinal_prefix: r#"chapitre|partie|volume|tome|livre|acte|sc[eè]ne|section|article"#,
+        ordinal_suffix: Some(r#"e|ème|eme"#),
+        ambiguous: AMBIGUOUS_ROMAN_NUMERALS,
+    })?;
+    // "ire" is as well a word
+    b.rule_1_terminal("Ier (ordinal)",
+        b.reg(r#"i(er|ère|re)"#)?,
+        |text_match| Ok(OrdinalValue::new(1).latent(text_match.group(1) == "re"))
+    );
+    b.rule_2("environ <number>",
+             b.reg(r#"(?:environ|à peu près|approximativement|autour de|dans les)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> environ",
+             number_check!(),
+             b.reg(r#"environ"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(zéro|zero|un|deux|trois|quatre|cinq|six|sept|huit|neuf)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "zéro" | "zero" => "0",
+                "un" => "1",
+                "deux" => "2",
+                "trois" => "3",
+                "quatre" => "4",
+                "cinq" => "5",
+                "six" => "6",
+                "sept" => "7",
+                "huit" => "8",
+                "neuf" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
+    Ok(())
+}
+
+/// Spoken forms found in speech recognition output, only enabled in the ASR mode of the parser
+pub fn rules_spoken_forms(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<filler> <number>",
+             b.reg(r#"e+u+h*|h+e+u+|h+u+m+|b+a+h+"#)?,
+             number_check!(),
+             |_, a| Ok(a.value().clone())
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"e+u+h*|h+e+u+|h+u+m+|b+a+h+"#)?,
+             |a, _| Ok(a.value().clone())
+    );
+    b.rule_2("<filler> <time>",
+             b.reg(r#"e+u+h*|h+e+u+|h+u+m+|b+a+h+"#)?,
+             time_check!(),
+             |_, a| Ok(a.value().clone())
+    );
+    b.rule_2("<time> <filler>",
+             time_check!(),
+             b.reg(r#"e+u+h*|h+e+u+|h+u+m+|b+a+h+"#)?,
+             |a, _| Ok(a.value().clone())
+    );
+    b.rule_2("<hour-of-day> (latent) <integer> (time-of-day)",
+             time_check!(|time: &TimeValue| time.latent && form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))(time)),
+             integer_check_by_range!(10, 59),
+             |time, minute| helpers::hour_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 minute.value().value as u32,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("zéro <hour> heures (time-of-day)",
+             b.reg(r#"z[ée]ro"#)?,
+             integer_check_by_range!(1, 9),
+             b.reg(r#"heures?"#)?,
+             |_, hour, _| helpers::hour(hour.value().value as u32, false)
+    );
     Ok(())
 }
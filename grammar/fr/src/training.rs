@@ -2,6 +2,7 @@ use rustling_ontology_values::check::*;
 use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
+use rustling::Range;
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_percentage(0.3), "0,3%", "zéro virgule trois pour cent");
@@ -11,6 +12,7 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(3.0, Some("degree")), "trois degrés", "3 degrés", "3°", "3 °");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "à peu près 20 degrés");
     example!(v, check_temperature(32.0, Some("celsius")), "trente deux degrés celsius", "trente deux degrés centigrade", "32°C", "32 °c");
     example!(v, check_temperature(-27.0, Some("celsius")), "moins 27 celsius", "-27C", "- 27 c");
     example!(v, check_temperature(-5.0, Some("fahrenheit")), "moins cinq degrés fahrenheit", "-5 °F", "- 5°f");
@@ -19,8 +21,57 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "21 K", "21k");
 }
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(3.0, DistanceUnit::Kilometer, Precision::Exact), "3 km", "trois kilomètres", "3 kilometres");
+    example!(v, check_distance(10.0, DistanceUnit::Meter, Precision::Exact), "dix mètres", "10m", "10 metres");
+    example!(v, check_distance(25.0, DistanceUnit::Centimeter, Precision::Exact), "vingt cinq centimètres", "25cm");
+    example!(v, check_distance(4.0, DistanceUnit::Millimeter, Precision::Exact), "quatre millimètres", "4 mm");
+    example!(v, check_distance(2.0, DistanceUnit::Mile, Precision::Exact), "deux miles", "2 miles");
+    example!(v, check_distance(6.0, DistanceUnit::Foot, Precision::Exact), "six pieds", "6 pieds");
+    example!(v, check_distance(12.0, DistanceUnit::Inch, Precision::Exact), "douze pouces", "12 pouces");
+    example!(v, check_distance(5.0, DistanceUnit::Kilometer, Precision::Approximate), "environ 5 km", "approximativement cinq kilomètres", "près de 5 km");
+    example!(v, check_distance(20.0, DistanceUnit::Meter, Precision::Exact), "exactement vingt mètres", "précisément 20 m");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(2.0, QuantityUnit::Liter, Some(Range(12, 16))), "2 litres de lait");
+    example!(v, check_quantity(2.0, QuantityUnit::Liter, Some(Range(15, 19))), "deux litres de lait");
+    example!(v, check_quantity(2.0, QuantityUnit::Liter, Some(Range(6, 10))), "2l de lait");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Some(Range(9, 15))), "500 g de farine");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Some(Range(22, 28))), "cinq cents grammes de farine");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Some(Range(8, 14))), "500g de farine");
+    example!(v, check_quantity(3.0, QuantityUnit::Cup, Some(Range(12, 17))), "3 tasses de sucre");
+    example!(v, check_quantity(3.0, QuantityUnit::Cup, Some(Range(16, 21))), "trois tasses de sucre");
+    example!(v, check_quantity(1.0, QuantityUnit::Pound, Some(Range(13, 19))), "une livre de pommes");
+    example!(v, check_quantity(1.0, QuantityUnit::Pound, Some(Range(11, 17))), "1 livre de pommes");
+    example!(v, check_quantity(2.0, QuantityUnit::Kilogram, Some(Range(7, 14))), "2 kg d'oranges");
+    example!(v, check_quantity(2.0, QuantityUnit::Kilogram, Some(Range(13, 20))), "deux kilos d'oranges");
+    example!(v, check_quantity(250.0, QuantityUnit::Milliliter, None), "250 ml", "deux cent cinquante millilitres");
+    example!(v, check_quantity(33.0, QuantityUnit::Centiliter, None), "33 cl", "trente trois centilitres");
+    example!(v, check_quantity(10.0, QuantityUnit::Milligram, None), "10 mg", "dix milligrammes");
+    example!(v, check_quantity(2.0, QuantityUnit::Tablespoon, Some(Range(28, 34))), "deux cuillères à soupe de beurre");
+    example!(v, check_quantity(2.0, QuantityUnit::Tablespoon, Some(Range(25, 31))), "2 cuillères à soupe de beurre");
+    example!(v, check_quantity(1.0, QuantityUnit::Teaspoon, Some(Range(26, 29))), "une cuillère à café de sel");
+    example!(v, check_quantity(0.75, QuantityUnit::Liter, None), "trois quarts de litre");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_rate(20.0, Some("EUR"), Grain::Hour), "20 € de l'heure", "vingt euros par heure", "20€/h");
+    example!(v, check_finance_rate(500.0, Some("EUR"), Grain::Month), "500 euros par mois");
+    example!(v, check_rate(3.0, Grain::Day), "3 fois par jour", "trois fois par jour");
+    example!(v, check_rate(2.0, Grain::Week), "deux fois par semaine");
+    example!(v, check_distance_rate(60.0, DistanceUnit::Kilometer, Grain::Hour), "60 km/h", "soixante kilomètres par heure", "60 km à l'heure");
+}
+
+pub fn examples_ratio(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_ratio(3, 2), "3:2", "le score est de 3:2");
+    example!(v, check_ratio(3, 5), "3 sur 5", "trois sur cinq");
+    example!(v, check_ratio(4, 1), "4 à 1", "une cote de quatre à un");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800 $", "huit cents dollars");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Approximate), "à peu près 20 euros");
     example!(v, check_finance(10.0, Some("USD"), Precision::Approximate), "environ dix dollars américains", "près de 10 USD", "presque 10US$");
     example!(v, check_finance(3.0, Some("AUD"), Precision::Exact), "3 dollars australiens");
     example!(v, check_finance(3.5, Some("AUD"), Precision::Exact), "3 dollars australiens et cinquante cents");
@@ -31,7 +82,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(2.0, Some("£"), Precision::Exact), "2 £", "deux livres");
     example!(v, check_finance(20.0, Some("GBP"), Precision::Exact), "vingt livres sterling");
     example!(v, check_finance(38.0, Some("CHF"), Precision::Exact), "38 francs suisses");
-    example!(v, check_finance(447.0, Some("KR"), Precision::Exact), "447 couronnes");
+    example!(v, check_finance(447.0, Some("kr"), Precision::Exact), "447 couronnes");
     example!(v, check_finance(10000.0, Some("DKK"), Precision::Exact), "dix mille DKK");
     example!(v, check_finance(100.0, Some("NOK"), Precision::Exact), "100 couronnes norvégiennes");
     example!(v, check_finance(2005.0, Some("SEK"), Precision::Exact), "2005 couronnes suedoises");
@@ -41,7 +92,9 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(8.0, Some("¥"), Precision::Exact), "8¥");
     example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "pile cent yuans", "100 yuan pile");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "7 wons", "7₩");
-    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿", "trois bitcoins");
+    example!(v, check_finance(3.0, Some("XBT"), Precision::Exact), "trois bitcoins");
+    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿");
+    example!(v, check_finance(3.0, Some("THB"), Precision::Exact), "trois bahts", "3 THB");
     example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "une quinzaine de dollars");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "trois millions d'euros");
 }
@@ -149,12 +202,21 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2014, 2]), "dans une année", "dans 1 an");
     example!(v, check_moment!(c, [2011, 2]), "il y a deux ans");
     //Seasons
-    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "cet été");
-    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "cet hiver");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "cet été");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "cet hiver");
     example!(v, check_moment!(c, [2013, 12, 25]), "Noel", "noël", "jour de noel");
     example!(v, check_moment_span!(c, [2013, 12, 24, 18], [2013, 12, 25, 00]), "le soir de noël");
     example!(v, check_moment!(c, [2014, 1, 1]), "jour de l'an", "nouvel an", "premier janvier");
     example!(v, check_moment!(c, [2013, 11, 1]), "la toussaint", "le jour de la toussaint", "la journée de la toussaint", "toussaint", "le jour des morts");
+    example!(v, check_moment_span!(c, [2013, 7, 9], [2013, 8, 8]), "ramadan", "pendant le ramadan");
+    example!(v, check_moment!(c, [2013, 7, 9]), "le début du ramadan", "premier jour du ramadan");
+    example!(v, check_moment!(c, [2013, 8, 4]), "la nuit du destin");
+    example!(v, check_moment!(c, [2013, 8, 8]), "l'aïd el-fitr", "aid al fitr", "la fête de la rupture du jeûne");
+    example!(v, check_moment!(c, [2013, 10, 14]), "le jour d'arafat");
+    example!(v, check_moment!(c, [2013, 10, 15]), "l'aïd el-kébir", "aïd al-adha", "la fête du sacrifice", "tabaski");
+    example!(v, check_moment!(c, [2013, 11, 5]), "le nouvel an hégirien", "nouvel an musulman");
+    example!(v, check_moment!(c, [2013, 11, 14]), "achoura", "le jour d'achoura");
+    example!(v, check_moment!(c, [2014, 1, 14]), "le mawlid", "mouloud");
     example!(v, check_moment!(c, [2013, 05, 1]), "fête du travail");
     //Part of day (morning, afternoon...)
     example!(v, check_moment_span!(c, [2013, 2, 12, 12], [2013, 2, 12, 19]), "cet après-midi", "l'après-midi");
@@ -226,6 +288,13 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 2, 20], [2013, 2, 20, 18]), "le 20 jusqu'à 18h");
     example!(v, check_moment_span!(c, [2014, 9, 14], [2014, 9, 21]), "14 - 20 sept. 2014");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 26]), "d'ici 2 semaines");
+    example!(v, check_moment!(c, [2013, 2, 15]), "dans 3 jours ouvrés", "dans 3 jours ouvrables");
+    example!(v, check_moment!(c, [2013, 2, 13]), "le prochain jour ouvré", "jour ouvré suivant");
+    example!(v, check_moment!(c, [2013, 2, 11]), "le jour ouvré précédent");
+    example!(v, check_moment!(c, [2013, 3, 29]), "dernier jour ouvré de mars", "le dernier jour ouvrable de mars");
+    example!(v, check_moment!(c, [2013, 2, 28]), "dernier jour ouvré du mois");
+    example!(v, check_moment!(c, [2013, 3, 4]), "le deuxième jour ouvré de mars");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 20]), "sous 5 jours ouvrés", "d'ici 5 jours ouvrés");
     //15j != 2 semaines
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 5, 12]), "d'ici 3 mois");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 27]), "dans les 15 jours");
@@ -249,6 +318,7 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 2]), "pendant deux heures", "durant deux heures");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "à peu près 3 heures");
     example!(v, check_duration!([0, 0, 0, 1]), "pendant un jour", "une journée");
     example!(v, check_duration!([0, 1, 0]), "durant un mois");
     example!(v, check_duration!([1]), "durant une année");
@@ -257,6 +327,8 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "pendant environ un quart d'heure", "environ 1/4h");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durant une heure");
     example!(v, check_duration!([0, 0, 2]), "pendant 2 semaines");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "trois quarts d'heure");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "deux tiers d'heure");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -332,4 +404,41 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "3ème",
              "troisième",
              "troisieme");
+    example!(v, check_fraction(2, 3), "deux tiers");
+    example!(v, check_fraction(3, 4), "trois quarts");
+    example!(v, check_fraction(1, 5), "un cinquième");
+    example!(v, check_fraction(1, 2), "la moitié", "un demi");
+    example!(v, check_integer(14), "XIV");
+    example!(v, check_ordinal(20), "XXe", "XXème", "chapitre XX");
+    example!(v, check_ordinal(1), "Ier");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "environ 20", "à peu près vingt", "20 environ");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "zéro six un deux", "0612");
+    example!(v, check_digit_sequence("0612345678"), "06 12 34 56 78", "06.12.34.56.78");
+    example!(v, check_digit_sequence("555"), "cinq cinq cinq");
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "entre 20 et 30", "de vingt à trente");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3 - 4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "moins de 10", "moins de dix");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "au moins 5", "au moins cinq");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("EUR")), "entre 20 et 30 euros", "entre 20 euros et 30 euros", "de 20 à 30 euros", "20 - 30 euros");
+    example!(v, check_finance_interval(Some(IntervalBound::exclusive(100.0)), None, Some("$")), "plus de 100 $", "plus de cent dollars");
+    example!(v, check_finance_interval(None, Some(IntervalBound::inclusive(50.0)), Some("EUR")), "au plus 50 euros", "au maximum cinquante euros");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "entre 20 et 25 degrés", "de 20° à 25°");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("celsius")), "en dessous de 5 degrés celsius", "moins de 5°C");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "entre 2 et 3 heures", "de deux à trois heures");
+    example!(v, check_duration_interval(None, Some(IntervalBound::exclusive(period!(0, 0, 0, 0, 0, 30)))), "moins de 30 minutes", "moins de trente minutes");
+}
+
+pub fn examples_spoken_forms(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(3), "euh trois", "trois euh");
+    example!(v, check_integer(250), "deux cent euh cinquante");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 30]), "neuf trente", "euh neuf trente");
+    example!(v, check_moment!(c, [2013, 2, 12, 9]), "à euh neuf heures", "euh à neuf heures");
+    example!(v, check_moment!(c, [2013, 2, 12, 5]), "zéro cinq heures");
 }
@@ -2,7 +2,26 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, Season};
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    let denominators = [
+        ("mezz", 2),
+        ("terz", 3),
+        ("quart", 4),
+        ("quint", 5),
+        ("sest", 6),
+        ("settim", 7),
+        ("ottav", 8),
+        ("non", 9),
+        ("decim", 10),
+        ("centesim", 100),
+    ];
+    denominators.iter()
+        .find(|&&(prefix, _)| word.starts_with(prefix))
+        .map(|&(_, denominator)| denominator)
+        .ok_or_else(|| RuleError::Invalid.into())
+}
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -13,114 +32,136 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: Some((r#"tra|fra"#, r#"e"#)),
+        from_to: Some((r#"da"#, r#"a"#)),
+        range: r#"-|~"#,
+        open: vec![
+            (OpenInterval::Under, helpers::OpenIntervalMarker::Before(r#"meno di|sotto"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::Before(r#"pi[uù] di|sopra"#)),
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::Before(r#"almeno"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::Before(r#"al massimo|fino a"#)),
+        ],
+    })
+}
+
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              b.reg(r#"e"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_3("intersect (and X)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              b.reg(r#"e"#)?,
              number_check!(),
              |a, _, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_2("intersect",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_3("intersect",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              b.reg(r#"e"#)?,
              number_check!(),
              |a, _, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_1_terminal("$",
         b.reg(r#"\$|dollar[oi]"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
     );
     b.rule_1_terminal("EUR",
         b.reg(r#"€|[e€]ur(?:o?s?|i)"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
         b.reg(r#"lir[ae]|pound|£"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("GBP",
                       b.reg(r#"gbp|(?:lir[ae] )?sterlin[ae](?: britannich?[ae]| ingles[ei])?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("USD",
         b.reg(r#"\$|us[d\$]|dollar[oi]? (?:american[oi]|u\.?s\.?a\.?|statunitens[ei])"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("AUD",
                       b.reg(r#"au[d\$]|dollar[oi] australian[oi]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
     );
     b.rule_1_terminal("CAD",
                       b.reg(r#"cad|dollar[oi] canades[ei]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
     );
     b.rule_1_terminal("HKD",
                       b.reg(r#"hkd|dollar[oi] di hong[- ]?kong"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("HKD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
     );
     b.rule_1_terminal("KR",
                       b.reg(r#"kr|coron[ae]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Krone)) })
     );
     b.rule_1_terminal("DKK",
                       b.reg(r#"dkk|coron[ae] danes[ei]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("DKK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::DKK)) })
     );
     b.rule_1_terminal("NOK",
                       b.reg(r#"nok|coron[ae] norveges[ei]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("NOK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::NOK)) })
     );
     b.rule_1_terminal("SEK",
                       b.reg(r#"sek|coron[ae] svedes[ei]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("SEK") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SEK)) })
     );
     b.rule_1_terminal("CHF",
                       b.reg(r#"chf|franch?[oi] svizzer[oi]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
     );
     // This is not recognized for a very obscure reason
     b.rule_1_terminal("RUB",
                       b.reg(r#"rub(?:l[oi])?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("RUB") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::RUB)) })
     );
     b.rule_1_terminal("INR",
                       b.reg(r#"inr|rupi[ae]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
     );
     b.rule_1_terminal("JPY",
                       b.reg(r#"jpy|yens?(?: giappones[ei])?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
     );
     b.rule_1_terminal("RMB|CNH|CNY",
                       b.reg(r#"cny|cnh|rmb|yuans?(?: cines[ei])?|renminbis?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CNY") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
     );
     b.rule_1_terminal("¥",
                       b.reg(r#"¥"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("¥") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
     );
     b.rule_1_terminal("KRW",
                       b.reg(r#"₩|krw|won(?: (?:sud[- ]?)?corean[oi])?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
+    );
+    b.rule_1_terminal("฿",
+        b.reg(r#"฿"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Baht)) })
+    );
+    b.rule_1_terminal("THB",
+        b.reg(r#"baht|thb"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::THB)) })
     );
     b.rule_1_terminal("Bitcoin",
-        b.reg(r#"฿|bitcoins?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
+        b.reg(r#"bitcoins?"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"cent(?:esim[oi]|s)?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
     );
     b.rule_2("<amount> <unit>",
              number_check!(),
@@ -128,6 +169,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -138,6 +180,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     precision: b.value().precision(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -148,8 +191,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              money_unit!(),
              |a, _, b| {
                  Ok(AmountOfMoneyValue {
-                     value: a.value().value as f32,
-                     precision: Exact,
+                     value: a.value().value as f64,
+                     precision: a.value().precision,
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -240,7 +283,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<number> hours <number>",
              integer_check_by_range!(0),
@@ -258,7 +301,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"e un quarto"#)?,
              |integer, uod, _| {
                  let quarter_period: Period = uod.value().grain.quarter_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-                 Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                 Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
              }
     );
     b.rule_3("<integer> <unit-of-duration> and a half",
@@ -267,7 +310,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"e mezz[oa]"#)?,
              |integer, uod, _| {
                  let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
              }
     );
     b.rule_3("<integer> <unit-of-duration> and 3 quarters of an hour",
@@ -276,7 +319,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"e tre quarti"#)?,
              |integer, uod, _| {
                  let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
              }
     );
     // Duration combinations
@@ -347,6 +390,12 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |duration, _, time| duration.value().after(time.value())
     );
 
+    b.rule_3("<fraction> di <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"d'una?|d'|di(?: una?)?"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| helpers::fraction_of_grain(fraction.value().fraction().ok_or(RuleError::Invalid)?, uod.value().grain)
+    );
     Ok(())
 }
 
@@ -1337,19 +1386,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     // Seasons
     b.rule_1_terminal("summer",
                       b.reg(r#"(?:quest(a |'))?estate"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("autumn",
                       b.reg(r#"(?:quest(o |'))?autunno"#)?,
-                      |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("winter",
                       b.reg(r#"(?:quest(o |'))?inverno"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("spring",
                       b.reg(r#"(?:questa )?primavera"#)?,
-                      |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     // Dates
     // TODO x1 - Understand what this is ????
@@ -1684,6 +1733,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a| {
         Ok(TemperatureValue {
             value: a.value().value(),
+            precision: a.value().precision(),
             unit: None,
             latent: true,
         })
@@ -1695,6 +1745,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("degree"),
                      latent: false,
                  })
@@ -1705,6 +1756,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -1715,6 +1767,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -1725,6 +1778,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("kelvin"),
                      latent: false,
                  })
@@ -1735,6 +1789,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
+                     precision: a.value().precision,
                      latent: false,
                      ..*a.value()
                  })
@@ -1742,6 +1797,9 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+/// Roman numerals which are also Italian words or unit symbols
+pub const AMBIGUOUS_ROMAN_NUMERALS: &'static [&'static str] = &["mi", "di", "vi", "ci", "li", "xi", "cv", "xl", "cd", "dc", "mm", "cm", "ml", "cl", "dl", "mix"];
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -1921,27 +1979,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
 
 
-    b.rule_1_terminal("integer with thousands separator .",
-                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "");
-                          let value: i64 = reformatted_string.parse()?;
-                          IntegerValue::new(value)
-                      });
-    b.rule_1_terminal("decimal number",
-                      b.reg(r#"(\d*,\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", ".");
-                          let value: f32 = reformatted_string.parse()?;
-                          FloatValue::new(value)
-                      });
     b.rule_3("number dot number",
              number_check!(|number: &NumberValue| !number.prefixed()),
              b.reg(r#"punto|virgola"#)?,
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| {
                  let power = b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
@@ -1955,20 +1999,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, zeros, b| {
                  let power = zeros.group(0).split_whitespace().count() + b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
                  })
              });
 
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                          let value: f32 = reformatted_string.parse()?;
-                          FloatValue::new(value)
-                      });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|meno"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -2014,7 +2051,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                              .into()
                      }
                      NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f32);
+                         let product = float.value * (multiplier as f64);
                          if product.floor() == product {
                              IntegerValue {
                                  value: product as i64,
@@ -2066,5 +2103,48 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           };
                           Ok(OrdinalValue::new(value))
                       });
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"((?:mezz|terz|quart|quint|sest|settim|ottav|non|decim|centesim)[oaie])"#)?,
+        |integer, text_match| FloatValue::fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    helpers::rules_roman_numerals(b, &helpers::RomanNumeralWords {
+        ordinal_prefix: r#"capitolo|parte|volume|tomo|libro|atto|scena|sezione|articolo|secolo"#,
+        ordinal_suffix: None,
+        ambiguous: AMBIGUOUS_ROMAN_NUMERALS,
+    })?;
+    b.rule_2("circa <number>",
+             b.reg(r#"(?:circa|all'incirca|approssimativamente|più o meno|intorno a)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> circa",
+             number_check!(),
+             b.reg(r#"circa"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(zero|uno|due|tre|quattro|cinque|sei|sette|otto|nove)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "zero" => "0",
+                "uno" => "1",
+                "due" => "2",
+                "tre" => "3",
+                "quattro" => "4",
+                "cinque" => "5",
+                "sei" => "6",
+                "sette" => "7",
+                "otto" => "8",
+                "nove" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
     Ok(())
 }
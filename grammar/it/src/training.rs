@@ -128,8 +128,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2011, 2]), "due anni fa");
 
     // Seasons
-    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "quest'estate", "questa estate");
-    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "quest'inverno", "questo inverno");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "quest'estate", "questa estate");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "quest'inverno", "questo inverno");
 
     // Holidays
     // TODO
@@ -258,6 +258,7 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 2]), "durante due ore", "per due ore");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "intorno a 3 ore");
     example!(v, check_duration!([0, 0, 0, 1]), "durante un giorno", "per una giornata", "durante una giornata", "per un giorno");
     example!(v, check_duration!([0, 1, 0]), "durante un mese", "per un mese");
     example!(v, check_duration!([1]), "durante un anno", "per un anno");
@@ -266,6 +267,8 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante un quarto d'ora all'incirca", "durante circa un quarto d'ora", "per più o meno 1/4 d'ora");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante un'ora", "per un'ora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 settimane", "per due settimane");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "tre quarti d'ora");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "due terzi di ora");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -298,6 +301,18 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(10.08), "10,08", "dieci punto zero otto");//, "dieci virgola zero otto");
     example!(v, check_ordinal(1), "1o", "1a", "il 1o", "la 1a", "1°");
     example!(v, check_ordinal(3), "3o", "il 3o", "3a", "la 3a", "3°", "terzo", "terza");
+    example!(v, check_fraction(2, 3), "due terzi");
+    example!(v, check_fraction(3, 4), "tre quarti");
+    example!(v, check_fraction(1, 5), "un quinto");
+    example!(v, check_ordinal(20), "secolo XX", "capitolo XX");
+    example!(v, check_integer(4), "IV");
+    example!(v, check_ordinal(16), "XVI");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "circa 20", "più o meno venti");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "zero sei uno due", "0612");
+    example!(v, check_digit_sequence("555"), "cinque cinque cinque");
 }
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -308,6 +323,7 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(3.0, Some("degree")), "tre gradi", "3 gradi", "3°", "3 °");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "intorno a 20 gradi");
     example!(v, check_temperature(32.0, Some("celsius")), "trenta due gradi celsius", "trenta due gradi centigradi", "32°C", "32° C", "32° c", "32°c", "32 °c");
     example!(v, check_temperature(-27.0, Some("celsius")), "meno 27 celsius", "meno 27 gradi celsius", "meno venti sette gradi celsius", "-27C", "-27°C", "-27° C", "-27°c", "-27° c", "- 27 c");
     example!(v, check_temperature(-5.0, Some("fahrenheit")), "meno cinque gradi fahrenheit", "-5 °F", "-5°F", "-5°f", "-5° f", "- 5°f");
@@ -325,11 +341,12 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(0.0, Some("HKD"), Precision::Exact), "zero dollari di hong kong");
     example!(v, check_finance(125.0, Some("CAD"), Precision::Exact), "125 CAD", "cento venti cinque dollari canadesi");
     example!(v, check_finance(45.0, Some("EUR"), Precision::Exact), "45€", "45 €", "quaranta cinque euro");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Approximate), "intorno a 20 euro");
     example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "2,05 €", "2,05€", "due euro e cinque centesimi");
     example!(v, check_finance(2.0, Some("£"), Precision::Exact), "2 £", "2£", "due lire");
     example!(v, check_finance(20.0, Some("GBP"), Precision::Exact), "venti sterline", "venti sterline britanniche", "venti lire sterline", "venti sterline inglesi");
     example!(v, check_finance(38.0, Some("CHF"), Precision::Exact), "38 franchi svizzeri", "trent otto franchi svizzeri");
-    example!(v, check_finance(447.0, Some("KR"), Precision::Exact), "447 corone", "quattro cento quaranta sette corone");
+    example!(v, check_finance(447.0, Some("kr"), Precision::Exact), "447 corone", "quattro cento quaranta sette corone");
     example!(v, check_finance(10000.0, Some("DKK"), Precision::Exact), "dieci mila DKK", "10.000 corone danesi");
     example!(v, check_finance(100.0, Some("NOK"), Precision::Exact), "100 corone norvegesi", "cento corone norvegesi");
     example!(v, check_finance(2005.0, Some("SEK"), Precision::Exact), "2005 corone svedesi", "due mila cinque corone svedesi");
@@ -339,8 +356,24 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(8.0, Some("¥"), Precision::Exact), "8¥");
     example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "cento yuan esatti", "esattamente 100 yuan cinesi", "100 renminbi precisi");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "7 won", "7₩", "sette won sudcoreani");
-    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿", "tre bitcoin", "tre bitcoins");
+    example!(v, check_finance(3.0, Some("XBT"), Precision::Exact), "tre bitcoin", "tre bitcoins");
+    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿");
+    example!(v, check_finance(3.0, Some("THB"), Precision::Exact), "tre baht", "3 THB");
     // TODO: FIXME - add approx. numbers in IT rules
     // todo_example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "una quindicina di dollari", "una 15ina di dollari");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "tre milioni di euro");
-}
\ No newline at end of file
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "tra 20 e 30", "da venti a trenta");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3 - 4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "meno di 10", "meno di dieci");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "almeno 5", "almeno cinque");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("EUR")), "tra 20 e 30 euro", "tra 20 euro e 30 euro", "da 20 a 30 euro", "20 - 30 euro");
+    example!(v, check_finance_interval(Some(IntervalBound::exclusive(100.0)), None, Some("$")), "più di 100 $", "più di 100$");
+    example!(v, check_finance_interval(None, Some(IntervalBound::inclusive(50.0)), Some("EUR")), "al massimo 50 euro", "fino a cinquanta euro");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "tra 20 e 25 gradi", "da 20° a 25°");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("celsius")), "sotto 5 gradi celsius", "meno di 5°C");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "tra 2 e 3 ore", "da due a tre ore");
+    example!(v, check_duration_interval(None, Some(IntervalBound::exclusive(period!(0, 0, 0, 0, 0, 30)))), "meno di 30 minuti", "meno di trenta minuti");
+}
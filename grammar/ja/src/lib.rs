@@ -7,33 +7,50 @@ pub mod rules;
 pub mod training;
 
 use rustling_ontology_values::DimensionKind::*;
+use rustling_ontology_values::helpers::{self, SeparatorPolicy};
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    rule_set_with_separators(&separators())
+}
+
+/// Digit grouping and decimal separators read by default
+pub fn separators() -> Vec<SeparatorPolicy> {
+    vec![SeparatorPolicy::CommaGroupsDotDecimal]
+}
+
+pub fn rule_set_with_separators(separators: &[SeparatorPolicy]) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::no_check(),
                     ::rustling::BoundariesChecker::no_check());
     rules::rules_numbers(&mut b)?;
+    helpers::rules_separated_numbers(&mut b, separators)?;
+    helpers::rules_digit_fractions(&mut b)?;
+    rules::rules_digit_sequence(&mut b)?;
+    helpers::rules_digit_sequences(&mut b)?;
     rules::rules_time(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;            
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    rules::rules_interval(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Duration, Time, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Duration, Time, Temperature, AmountOfMoney, Percentage, NumberInterval, AmountOfMoneyInterval, TemperatureInterval, DurationInterval, DigitSequence];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     let mut v = vec![];
     training::examples_numbers(&mut v);
+    training::examples_digit_sequence(&mut v);
     training::examples_time(&mut v);
     training::examples_durations(&mut v);
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_interval(&mut v);
     v
 }
 
@@ -59,4 +76,27 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+
+    #[test]
+    fn test_comma_groups_digits() {
+        use rustling_ontology_values::check::{check_float, check_integer};
+        let rules = rule_set().unwrap();
+        let stash = rules.apply_all("1,000").unwrap();
+        let full_matches = stash.iter()
+            .filter(|candidate| candidate.root_node.byte_range == Range(0, 5))
+            .collect::<Vec<_>>();
+        assert!(full_matches.iter().any(|candidate| check_integer(1000).check(candidate)));
+        assert!(!full_matches.iter().any(|candidate| check_float(1.0).check(candidate)));
+    }
+
+    #[test]
+    fn test_comma_is_no_decimal_point_in_percentages() {
+        use rustling_ontology_values::check::check_percentage;
+        let rules = rule_set().unwrap();
+        // "0,3 %" was read as 0.3 percent, the comma only groups digits
+        for text in &["0,3 %", "0,3%", "0,3％"] {
+            let stash = rules.apply_all(text).unwrap();
+            assert!(!stash.iter().any(|candidate| check_percentage(0.3).check(candidate)), format!("Comma read as a decimal point: {:?}", text));
+        }
+    }
 }
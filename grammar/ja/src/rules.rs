@@ -2,12 +2,23 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, Season};
 
 fn ja_quantifier_regex() -> &'static str {
     r#"(?:尾|台|名|枚|話|部|面|両|問|拍子|条|段|輪|門|倍|番|畳|合|膳|錠|ページ|頁|例|字|文|行|語|把|羽|頭|つ|人|個|冊|匹|回|曲|本|杯|点|種類|種|等|足|階|カ国|ヶ国|クラス|丁|件|体|勝|区|口|坪|基|局|席|式|振|挺|敗|束|校|株|機|歩|滴|発|社|票|組|艦|行|通り|通|隻|首|客|戸|着|箱|脚|軒|切れ|品|斤|粒|貫|句|巻|画|稿|筆|言|級)"#
 }
 
+fn ja_counter_class(counter: &str) -> Option<CounterClass> {
+    match counter {
+        "個" | "つ" => Some(CounterClass::Object),
+        "本" => Some(CounterClass::LongObject),
+        "枚" => Some(CounterClass::FlatObject),
+        "人" | "名" => Some(CounterClass::Person),
+        "匹" | "頭" | "羽" => Some(CounterClass::Animal),
+        _ => None,
+    }
+}
+
 pub trait JapaneseReplace {
    fn replace_japanese_digit(&self) -> String;
    fn replace_comma(&self) -> String;
@@ -225,7 +236,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
      b.rule_2("cardinal number with quantifier",
             integer_check_by_range!(0),
             b.reg(ja_quantifier_regex())?,
-            |integer, _| Ok(integer.value().clone())
+            |integer, counter| integer.value().clone().with_counter(ja_counter_class(counter.group(0)))
     );
 
     b.rule_3("ordinal number",
@@ -240,9 +251,9 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_1("float number", 
-        b.reg(r#"((?:\d|〇)*[、,，\.](?:\d|〇)+)"#)?, |text_match| {
+        b.reg(r#"((?:\d|〇)*[、\.](?:\d|〇)+)"#)?, |text_match| {
           let res = text_match.group(1).replace_japanese_digit().replace_comma();
-          let value: f32 = res.parse()?;
+          let value: f64 = res.parse()?;
           Ok(FloatValue {
               value: value,
               ..FloatValue::default()
@@ -250,11 +261,11 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     });
     b.rule_3("number dot number",
         number_check!(|number: &NumberValue| !number.prefixed()),
-        b.reg(r#"てん|テン|[、,，\.]|点"#)?,
+        b.reg(r#"てん|テン|[、\.]|点"#)?,
         number_check!(|number: &NumberValue| !number.suffixed()),
         |a, _, b| {
             let power = b.value().value().to_string().chars().count();
-            let coeff = 10.0_f32.powf(-1.0 * power as f32);
+            let coeff = 10.0_f64.powf(-1.0 * power as f64);
             Ok(FloatValue {
                 value: b.value().value() * coeff + a.value().value(),
                 ..FloatValue::default()
@@ -262,7 +273,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     });
     b.rule_3("number dot number",
          number_check!(|number: &NumberValue| !number.prefixed()),
-         b.reg(r#"てん|テン|[、,，\.]|点"#)?,
+         b.reg(r#"てん|テン|[、\.]|点"#)?,
          b.reg(r#"((?:零|一|二|三|四|五|六|七|八|九|ゼロ)+)"#)?,
          |a, _, decimals| {
               fn number_mapping(c: char) -> Option<char> {
@@ -285,7 +296,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                                              decimal_part_string.chars()
                                                  .filter_map(number_mapping)
                                                  .collect::<String>());
-              let decimal_part: f32 = decimal_part_string.parse()?;
+              let decimal_part: f64 = decimal_part_string.parse()?;
               Ok(FloatValue {
                  value: a.value().value() + decimal_part,
                  ..FloatValue::default()
@@ -315,6 +326,45 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      }
                  })
              });
+    b.rule_3("<integer> 分の <integer> (fraction)",
+        integer_check_by_range!(1),
+        b.reg(r#"分の"#)?,
+        integer_check_by_range!(0),
+        |denominator, _, numerator| FloatValue::fraction(numerator.value().value, denominator.value().value)
+    );
+    b.rule_2("約 <number>",
+             b.reg(r#"(?:約|およそ|大体|だいたい)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> ぐらい",
+             number_check!(),
+             b.reg(r#"(?:ぐらい|くらい|前後|ほど)"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(〇|零|ゼロ|一|二|三|四|五|六|七|八|九)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "〇" | "零" | "ゼロ" => "0",
+                "一" => "1",
+                "二" => "2",
+                "三" => "3",
+                "四" => "4",
+                "五" => "5",
+                "六" => "6",
+                "七" => "7",
+                "八" => "8",
+                "九" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
     Ok(())
 }
 
@@ -342,95 +392,103 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect <money> (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_2("intersect <money>",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_1_terminal("USD",
         b.reg(r#"米ドル|アメリカドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("$",
         b.reg(r#"ドル|\$|＄"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
     );
     b.rule_1_terminal("EUR",
         b.reg(r#"ユーロ|€"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
         b.reg(r#"ポンド|£"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("GBP",
         b.reg(r#"GBP|英ポンド|イギリスポンド"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("JPY",
         b.reg(r#"JPY|(?:日本)?円"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
     );
     b.rule_1_terminal("CNY",
         b.reg(r#"CNY|(?:人民)?元"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CNY") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
     );
     b.rule_1_terminal("¥",
         b.reg(r#"¥"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("¥") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
     );
     b.rule_1_terminal("Krones (Swedish, Danish, ...)",
         b.reg(r#"KR|クローネ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("KR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Krone)) })
     );
     b.rule_1_terminal("Danish Krones",
         b.reg(r#"DKK|デンマーククローネ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("DKK") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::DKK)) })
     );
     b.rule_1_terminal("Swedish Krones",
         b.reg(r#"SEK|スウェーデンクローナ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("SEK") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SEK)) })
     );
     b.rule_1_terminal("Norwegian Krones",
         b.reg(r#"NOK|ノルウェークローネ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("NOK") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::NOK)) })
     );
     b.rule_1_terminal("Swiss Francs",
         b.reg(r#"CHF|スイスフラン"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
     );
     b.rule_1_terminal("KRW",
         b.reg(r#"韓国ウォン|ウォン|₩"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
     );
     b.rule_1_terminal("INR",
         b.reg(r#"インドルピー|ルピー"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("INR") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
     );
     b.rule_1_terminal("RUB",
         b.reg(r#"ルーブル|ルーブリ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("RUB") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::RUB)) })
     );
     b.rule_1_terminal("AUD",
         b.reg(r#"豪ドル|オーストラリアドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
     );
     b.rule_1_terminal("HKD",
         b.reg(r#"香港ドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("HKD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
     );
     b.rule_1_terminal("CAD",
         b.reg(r#"カナダドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
+    );
+    b.rule_1_terminal("฿",
+        b.reg(r#"฿"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Baht)) })
+    );
+    b.rule_1_terminal("THB",
+        b.reg(r#"タイバーツ|バーツ"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::THB)) })
     );
     b.rule_1_terminal("Bitcoin",
-        b.reg(r#"ビットコイン|ビット|฿"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
+        b.reg(r#"ビットコイン|ビット"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"銭"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
     );
     b.rule_2("<amount> <unit>",
              number_check!(),
@@ -438,6 +496,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -480,6 +539,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("degree"),
                      latent: false,
                  })
@@ -490,6 +550,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -500,6 +561,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -510,6 +572,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("kelvin"),
                      latent: false,
                  })
@@ -521,6 +584,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             |_, a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -532,6 +596,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             |_, a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -543,6 +608,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |_, a, _| {
             Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -555,6 +621,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |_, a, _| {
             Ok(TemperatureValue {
                      value: -1.0 * a.value().value(),
+                     precision: a.value().precision(),
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -566,6 +633,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |_, a| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
+                     precision: a.value().precision,
                      latent: false,
                      ..*a.value()
                  })
@@ -573,6 +641,20 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: None,
+        from_to: None,
+        range: r#"から|〜|~|-"#,
+        open: vec![
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::After(r#"以上"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::After(r#"以下"#)),
+            (OpenInterval::Under, helpers::OpenIntervalMarker::After(r#"未満"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::After(r#"超|を超える"#)),
+        ],
+    })
+}
+
 pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect <time>",
              time_check!(|time: &TimeValue| !time.latent && excluding_form!(Form::PartOfDay(_))(time)),
@@ -1288,6 +1370,60 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(helpers::year(integer.value().value as i32)?.latent())
              }
     );
+    // The last year of each era is checked in the production
+    b.rule_3("year (imperial era)",
+             b.reg(r#"(令和|平成|昭和|大正|明治)"#)?,
+             integer_check_by_range!(1, 99),
+             b.reg(r#"年"#)?,
+             |era, integer, _| {
+                 let (first_year, last_era_year) = match era.group(1).as_ref() {
+                     "令和" => (2019, 99),
+                     "平成" => (1989, 31),
+                     "昭和" => (1926, 64),
+                     "大正" => (1912, 15),
+                     "明治" => (1868, 45),
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 let era_year = integer.value().value as i32;
+                 if era_year > last_era_year { return Err(RuleError::Invalid.into()) }
+                 helpers::year(first_year + era_year - 1)
+             }
+    );
+    b.rule_1_terminal("first year (imperial era)",
+             b.reg(r#"(令和|平成|昭和|大正|明治)元年"#)?,
+             |text_match| {
+                 let first_year = match text_match.group(1).as_ref() {
+                     "令和" => 2019,
+                     "平成" => 1989,
+                     "昭和" => 1926,
+                     "大正" => 1912,
+                     "明治" => 1868,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::year(first_year)
+             }
+    );
+    // "named-month" only reads months written in kanji, these read "2018年1月23日" as well
+    b.rule_5("<year> mm月dd日",
+             time_check!(form!(Form::Year(_))),
+             integer_check_by_range!(1, 12),
+             b.reg(r#"月"#)?,
+             integer_check_by_range!(1, 31),
+             b.reg(r#"日"#)?,
+             |year, month, _, day, _| {
+                 let y = match year.value().form {
+                     Form::Year(y) => y,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::year_month_day(y, month.value().value as u32, day.value().value as u32)
+             }
+    );
+    b.rule_3("<year> mm月",
+             time_check!(form!(Form::Year(_))),
+             integer_check_by_range!(1, 12),
+             b.reg(r#"月"#)?,
+             |year, month, _| year.value().intersect(&helpers::month(month.value().value as u32)?)
+    );
 
     b.rule_1("the first day of month",
              b.reg(r#"初日"#)?,
@@ -1804,19 +1940,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"夏"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"秋"#)?,
-                      |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"冬"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"春"#)?,
-                      |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_2("<time-of-day> approximately",
              b.reg(r#"だいたい"#)?,
@@ -2092,19 +2228,19 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<integer> more <unit-of-duration>",
              b.reg(r#"もう|後|あと"#)?,
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |_, integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |_, integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<integer> less <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
              b.reg(r#"短く"#)?,
-             |integer, uod, _| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod, _| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     // b.rule_2_terminal("number.number hours",
     //                   b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -2124,7 +2260,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"半"#)?,
              |integer, uod, _| {
                 let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).unwrap_or_else(|| Period::default());
-                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
             }
     );
     b.rule_2("in <duration>",
@@ -32,10 +32,23 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(11), "十一番目", "十一句目", "十一面目", "十一ページ目", "十一口目");
     example!(v, check_ordinal(91), "九十一番目", "九十一滴目", "九十一段目", "九十一拍子目", "九十一切れ目");
     example!(v, check_ordinal(40020), "四万二十坪目", "四万二十つ目", "四万二十カ国目", "四万二十字目", "四万二十拍子目");
+    example!(v, check_fraction(2, 3), "三分の二", "2/3");
+    example!(v, check_fraction(1, 4), "四分の一");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "約20", "20ぐらい");
+    example!(v, check_integer_with_counter(3, CounterClass::Person), "三人", "3人");
+    example!(v, check_integer_with_counter(5, CounterClass::Object), "五個", "5個", "五つ");
+    example!(v, check_integer_with_counter(2, CounterClass::LongObject), "二本", "2本");
+    example!(v, check_integer_with_counter(10, CounterClass::FlatObject), "十枚", "10枚");
+    example!(v, check_integer_with_counter(2, CounterClass::Animal), "二匹", "2匹");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "〇六一二", "ゼロ六一二", "0612");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800$", "800ドル", "八百ドル");
+    example!(v, check_finance(20.0, Some("$"), Precision::Approximate), "大体20ドル");
     example!(v, check_finance(10.0, Some("USD"), Precision::Exact), "10アメリカドル", "十米ドル");
     example!(v, check_finance(3.0, Some("AUD"), Precision::Exact), "3豪ドル", "三オーストラリアドル");
     example!(v, check_finance(0.0, Some("HKD"), Precision::Exact), "0香港ドル", "零香港ドル");
@@ -44,7 +57,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(2.0, Some("£"), Precision::Exact), "2ポンド");
     example!(v, check_finance(20.0, Some("GBP"), Precision::Exact), "ちょうど二十イギリスポンド", "まさに20英ポンド");
     example!(v, check_finance(38.0, Some("CHF"), Precision::Exact), "38スイスフラン");
-    example!(v, check_finance(447.0, Some("KR"), Precision::Approximate), "四百四十七クローネ位");
+    example!(v, check_finance(447.0, Some("kr"), Precision::Approximate), "四百四十七クローネ位");
     example!(v, check_finance(10000.0, Some("DKK"), Precision::Exact), "一万デンマーククローネ");
     example!(v, check_finance(100.0, Some("NOK"), Precision::Exact), "100ノルウェークローネ");
     example!(v, check_finance(2005.0, Some("SEK"), Precision::Exact), "二千五スウェーデンクローナ");
@@ -53,11 +66,13 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(89.0, Some("JPY"), Precision::Exact), "八十九円", "89日本円");
     example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "100元", "百人民元");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "七ウォン", "7韓国ウォン");
-    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3ビット", "三ビットコイン");
+    example!(v, check_finance(3.0, Some("XBT"), Precision::Exact), "3ビット", "三ビットコイン");
+    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿");
+    example!(v, check_finance(3.0, Some("THB"), Precision::Exact), "3バーツ", "三タイバーツ");
     }
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_percentage(0.3), "0,3 %", "0,3%", "0,3％");
+    example!(v, check_percentage(0.3), "0.3 %", "0.3%", "0.3％");
     example!(v, check_percentage(25.0), "25%", "25％", "25パーセント", "二十五パーセント");
     example!(v, check_percentage(10.0), "割");
     example!(v, check_percentage(1.0), "分");
@@ -67,6 +82,7 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(0.0, Some("degree")), "零度", "0度", "零ど", "0ど", "0 °", "0°");
     example!(v, check_temperature(5.0, Some("degree")), "五度", "5度", "5 °", "5°");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "大体20度");
     example!(v, check_temperature(6.0, Some("degree")), "六ど", "6ど");
     example!(v, check_temperature(14.0, Some("degree")), "14度");
     example!(v, check_temperature(25.0, Some("degree")), "二十五度");
@@ -88,6 +104,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 0, 1]), "一秒間");
     example!(v, check_duration!([0, 0, 0, 0, 0, 1]), "一分間");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "一時間");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "大体3時間");
     example!(v, check_duration!([0, 0, 0, 5]), "五日間");
     example!(v, check_duration!([0, 10]), "十ヶ月間", "十カ月間");
     example!(v, check_duration!([0, 0, 0, 0, 2], Precision::Approximate), "およそ二時間", "二時間程", "二時間位");
@@ -122,6 +139,12 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2012]), "去年", "昨年", "前年", "前の年");
     example!(v, check_moment!(c, [2013]), "今年", "当年");
     example!(v, check_moment!(c, [2014]), "来年", "新年");
+    example!(v, check_moment!(c, [2020]), "令和2年", "令和二年");
+    example!(v, check_moment!(c, [2019]), "令和元年", "平成31年", "平成三十一年");
+    example!(v, check_moment!(c, [1989]), "平成元年", "昭和64年");
+    example!(v, check_moment!(c, [2019, 4]), "平成31年4月");
+    example!(v, check_moment!(c, [2020, 5, 1]), "令和2年5月1日");
+    example!(v, check_moment!(c, [2013, 2, 12]), "平成25年2月12日", "平成二十五年二月十二日");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4], [2013, 2, 12, 12]), "朝", "午前", "今朝");
     example!(v, check_moment!(c, [2013, 12, 25]), "クリスマス", "次のクリスマス",  "二千十三年十二月二十五日", "十二月二十五日");
     example!(v, check_moment_span!(c, [2013, 2, 11, 18], [2013, 2, 12, 3]), "昨晚");
@@ -163,8 +186,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 3, 8]), "女性の日");
     example!(v, check_moment!(c, [2013, 2, 15]), "三日後");
     example!(v, check_moment!(c, [2013, 2, 9]), "三日前");
-    example!(v, check_moment!(c, [2018, 1, 23]), "2018.1.23");
-    // TODO: date as "2018年1月23日"
+    example!(v, check_moment!(c, [2018, 1, 23]), "2018.1.23", "2018年1月23日");
+    example!(v, check_moment!(c, [2018, 4]), "2018年4月");
 
     example!(v, check_moment_span!(c, [2013, 8, 1], [2013, 8, 11]), "八月の上旬");
     example!(v, check_moment_span!(c, [2013, 10, 1], [2013, 10, 11]), "十月の上旬");
@@ -186,4 +209,17 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 3, 1], [2013, 3, 6]), "一日から五日", "一日から五日まで");
     example!(v, check_moment_span!(c, [2013, 2, 28], [2013, 3, 4]), "二十八日から三日", "二十八日から三日まで");
     example!(v, check_moment!(c, [2013, 2, 10, 14, 57]), "一昨日の午後三時三分前");
-}
\ No newline at end of file
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "20から30", "20〜30");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3〜4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "10未満");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "5以上");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("$")), "20から30ドル", "20ドルから30ドル");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(100.0)), None, Some("$")), "100ドル以上");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "20度から25度", "20から25度");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("degree")), "5度未満");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "二時間から三時間", "2から3時間");
+    example!(v, check_duration_interval(None, Some(IntervalBound::inclusive(period!(0, 0, 0, 0, 3)))), "三時間以下");
+}
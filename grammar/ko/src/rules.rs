@@ -3,57 +3,57 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
 use regex::Regex;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Season};
 
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value())
     );
     b.rule_1_terminal("KRW",
                       b.reg(r#"₩|원|krw"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
     );
     b.rule_1_terminal("$",
                       b.reg(r#"\$|달러|불"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("$") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"cents?|센[트|츠]|c|¢"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
     );
     b.rule_1_terminal("EUR",
                       b.reg(r#"€|유로|euro?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
                       b.reg(r#"£|파운드|영국파운드"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("£") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("GBP",
                       b.reg(r#"gbp"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("AUD",
                       b.reg(r#"aud|호주달러"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
     );
     b.rule_1_terminal("USD",
                       b.reg(r#"us[d\$]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("USD") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("PTS",
                       b.reg(r#"pta?s?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("PTS") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::ESP)) })
     );
     b.rule_1_terminal("INR",
                       b.reg(r#"inr|rs(?:. )?|(?:R|r)upees?|루피|인도루피"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
     );
     b.rule_1_terminal("AED", //  Emirates Currency
                       b.reg(r#"디르함|aed|dirhams?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AED") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AED)) })
     );
     b.rule_2("<unit> <amount>",
              money_unit!(),
@@ -61,6 +61,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     precision: b.value().precision(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -70,6 +71,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              money_unit!(),
              |a, b| Ok(AmountOfMoneyValue {
                  value: a.value().value(),
+                 precision: a.value().precision(),
                  unit: b.value().unit,
                  ..AmountOfMoneyValue::default()
              })
@@ -104,11 +106,21 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> per cent",
+             number_check!(),
+             b.reg(r#"%|％|퍼센트|프로"#)?,
+             |number, _| Ok(PercentageValue(number.value().value()))
+    );
+    Ok(())
+}
+
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("number as temp",
              number_check!(),
              |a| Ok(TemperatureValue {
                  value: a.value().value(),
+                 precision: a.value().precision(),
                  unit: None,
                  latent: true,
              })
@@ -120,6 +132,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  if temp.value().value >= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
+                         precision: temp.value().precision,
                          unit: temp.value().unit,
                          latent: false,
                      })
@@ -135,6 +148,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  if temp.value().value <= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
+                         precision: temp.value().precision,
                          unit: temp.value().unit,
                          latent: false,
                      })
@@ -149,6 +163,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"도|°"#)?,
              |a, _| Ok(TemperatureValue {
                  value: a.value().value,
+                 precision: a.value().precision,
                  unit: Some("degree"),
                  latent: false,
              })
@@ -158,6 +173,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              temperature_check!(),
              |_, a| Ok(TemperatureValue {
                  value: a.value().value,
+                 precision: a.value().precision,
                  unit: Some("celsius"),
                  latent: false,
              })
@@ -167,6 +183,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"c"#)?,
              |a, _| Ok(TemperatureValue {
                  value: a.value().value,
+                 precision: a.value().precision,
                  unit: Some("celsius"),
                  latent: false,
              })
@@ -176,6 +193,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              temperature_check!(),
              |_, a| Ok(TemperatureValue {
                  value: a.value().value,
+                 precision: a.value().precision,
                  unit: Some("fahrenheit"),
                  latent: false,
              })
@@ -185,6 +203,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"f"#)?,
              |a, _| Ok(TemperatureValue {
                  value: a.value().value,
+                 precision: a.value().precision,
                  unit: Some("fahrenheit"),
                  latent: false,
              })
@@ -192,6 +211,20 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: None,
+        from_to: None,
+        range: r#"에서|부터|~|-"#,
+        open: vec![
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::After(r#"이상"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::After(r#"이하"#)),
+            (OpenInterval::Under, helpers::OpenIntervalMarker::After(r#"미만"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::After(r#"초과"#)),
+        ],
+    })
+}
+
 pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              time_check!(|time: &TimeValue| !time.latent),
@@ -922,19 +955,19 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("season",
                       b.reg(r#"여름"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"가을"#)?,
-                      |_| helpers::month_day(9, 23)?.span_to(&helpers::month_day(12, 21)?, false)
+                      |_| helpers::season(Season::Autumn)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"겨울"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
+                      |_| helpers::season(Season::Winter)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"봄"#)?,
-                      |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+                      |_| helpers::season(Season::Spring)
     );
     b.rule_2("<time> approximately",
              time_check!(),
@@ -1089,7 +1122,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_2_terminal("number.number hours",
                       b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -1102,7 +1135,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> and an half hours",
              integer_check_by_range!(0),
              b.reg(r#"시간반"#)?,
-             |integer, _| Ok(DurationValue::new(PeriodComp::new(Grain::Minute, integer.value().value * 60 + 30).into()))
+             |integer, _| Ok(DurationValue::new(PeriodComp::new(Grain::Minute, integer.value().value * 60 + 30).into()).precision(integer.value().precision))
     );
     b.rule_2("in <duration>",
              duration_check!(),
@@ -1267,14 +1300,6 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           let value: i64 = text_match.group(1).parse()?;
                           IntegerValue::new(value)
                       });
-    b.rule_1_terminal("integer with thousands separator ,",
-                      b.reg(r#"(\d{1,3}(,\d\d\d){1,5})"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: i64 = reformatted_string.parse()?;
-                          IntegerValue::new(value)
-                      }
-    );
     b.rule_1_terminal("integer 0",
                       b.reg(r#"영|공|빵"#)?,
                       |_| IntegerValue::new(0)
@@ -1477,10 +1502,6 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| IntegerValue::new(a.value().value + b.value().value)
     );
 
-    b.rule_1_terminal("decimal number",
-                      b.reg(r#"(\d*\.\d+)"#)?,
-                      |text_match| FloatValue::new(text_match.group(1).parse()?)
-    );
 
     b.rule_2("number dot number - 삼점사",
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -1505,14 +1526,10 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                                              text_match.group(2).chars()
                                                  .filter_map(number_mapping)
                                                  .collect::<String>());
-                 FloatValue::new(a.value().value() + number_string.parse::<f32>()?)
+                 FloatValue::new(a.value().value() + number_string.parse::<f64>()?)
              }
     );
 
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
-                      |text_match| FloatValue::new(text_match.group(1).replace(",", "").parse()?)
-    );
     b.rule_2("numbers prefix with -, 마이너스, or 마이나스",
              b.reg(r#"-|마이너스\s?|마이나스\s?"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -1554,5 +1571,59 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| FloatValue::new(a.value().value() / b.value().value())
     );
+    b.rule_3("<integer> 분의 <integer> (fraction)",
+        integer_check_by_range!(1),
+        b.reg(r#"분의"#)?,
+        integer_check_by_range!(0),
+        |denominator, _, numerator| FloatValue::fraction(numerator.value().value, denominator.value().value)
+    );
+    b.rule_2("약 <number>",
+             b.reg(r#"(?:약|대략)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> 정도",
+             number_check!(),
+             b.reg(r#"(?:정도|쯤)"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<integer> <counter>",
+             integer_check_by_range!(1),
+             b.reg(r#"개|명|사람|마리"#)?,
+             |integer, counter| {
+                 let counter = match counter.group(0).as_ref() {
+                     "개" => CounterClass::Object,
+                     "명" | "사람" => CounterClass::Person,
+                     "마리" => CounterClass::Animal,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 integer.value().clone().with_counter(Some(counter))
+             }
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digits",
+        b.reg(r#"([공영일이삼사오육륙칠팔구]+)"#)?,
+        |text_match| {
+            let digits = text_match.group(1).chars()
+                .map(|c| match c {
+                    '공' | '영' => Ok('0'),
+                    '일' => Ok('1'),
+                    '이' => Ok('2'),
+                    '삼' => Ok('3'),
+                    '사' => Ok('4'),
+                    '오' => Ok('5'),
+                    '육' | '륙' => Ok('6'),
+                    '칠' => Ok('7'),
+                    '팔' => Ok('8'),
+                    '구' => Ok('9'),
+                    _ => Err(RuleError::Invalid),
+                })
+                .collect::<Result<String, _>>()?;
+            Ok(DigitSequenceValue::new(&digits))
+        }
+    );
     Ok(())
 }
@@ -16,10 +16,18 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(10.0, Some("£"), Precision::Exact), "딱 10파운드");
 }
 
+pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_percentage(20.0), "20%", "20 %", "20퍼센트", "20 퍼센트", "이십 퍼센트", "이십 프로", "20프로");
+    example!(v, check_percentage(0.5), "0.5%", "0.5 퍼센트");
+    example!(v, check_percentage(100.0), "100%", "백 퍼센트", "백프로");
+    example!(v, check_percentage(35.0), "삼십오 퍼센트", "35프로");
+}
+
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(37.0, Some("celsius")), "37°C", "섭씨37°", "섭씨37도");
     example!(v, check_temperature(70.0, Some("fahrenheit")), "70°F", "화씨70°", "화씨70도");
     example!(v, check_temperature(45.0, Some("degree")), "45°", "45도");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "대략 20도");
     example!(v, check_temperature(-15.0, Some("degree")), "영하 15도");
     example!(v, check_temperature(-3.0, Some("degree")), "영하 삼도");
     example!(v, check_temperature(15.0, Some("degree")), "영상 15도");
@@ -130,9 +138,9 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 5, 30]),"한시간안에", "한시간내");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 12, 6, 0, 0]), "한시간반안", "한시간반내");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 12, 7, 0, 0]), "두시간반안", "두시간반내");
-    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 7, 30]), "몇시간안", "몇시간내");
-    example!(v, check_moment!(c, [2013, 2, 12, 7, 30]), "몇시간후");
-    example!(v, check_moment_with_direction!(c, [2013, 2, 12, 7, 30], Direction::After), "몇시간이후");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 7, 30], Precision::Approximate), "몇시간안", "몇시간내");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 7, 30], Precision::Approximate), "몇시간후");
+    example!(v, check_moment(c.clone(), moment!(2013, 2, 12, 7, 30), grain!(2013, 2, 12, 7, 30), Precision::Approximate, Some(Direction::After)), "몇시간이후");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30], [2013, 2, 13, 4, 30]), "24시간안에", "24시간내");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30], [2013, 2, 13]), "하루안에", "하루내");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30], [2016, 2]), "삼년안에", "삼년내");
@@ -144,8 +152,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 1, 22]), "3주전", "3주이전");
     example!(v, check_moment!(c, [2011, 2]), "2년전", "2년이전");
     example!(v, check_moment!(c, [1999]), "99년");
-    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "이번여름");
-    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "이번겨울");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "이번여름");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "이번겨울");
     example!(v, check_moment!(c, [2013, 12, 25]), "크리스마스");
     example!(v, check_moment!(c, [2013, 12, 24]), "크리스마스이브");
     example!(v, check_moment!(c, [2014, 1, 1]), "신정");
@@ -313,4 +321,28 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(3.0 / 4.0), "3/4", "사분의삼");
     example!(v, check_ordinal(25), "스물다섯번째", "이십오번째");
     example!(v, check_ordinal(1), "첫번째", "첫째", "첫번", "첫");
+    example!(v, check_fraction(2, 3), "3분의 2");
+    example!(v, check_fraction(1, 4), "4분의 1");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "약 20", "20 정도");
+    example!(v, check_integer_with_counter(3, CounterClass::Person), "세 명", "세명", "3명");
+    example!(v, check_integer_with_counter(2, CounterClass::Animal), "두 마리", "2마리");
+    example!(v, check_integer_with_counter(5, CounterClass::Object), "다섯 개", "5개");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "공육일이", "공 육 일 이", "0612");
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "20에서 30", "20~30");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3~4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "10미만", "10 미만");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "5 이상");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("$")), "20에서 30달러", "20달러에서 30달러");
+    example!(v, check_finance_interval(None, Some(IntervalBound::inclusive(50.0)), Some("EUR")), "50유로 이하");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "20도에서 25도", "20에서 25도");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("degree")), "5도 미만");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "2에서 3시간", "2시간에서 3시간");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "대략 3시간");
+    example!(v, check_duration_interval(None, Some(IntervalBound::inclusive(period!(0, 0, 0, 0, 3)))), "3시간 이하");
 }
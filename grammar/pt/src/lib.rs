@@ -1,3 +1,4 @@
+extern crate regex;
 extern crate rustling;
 #[macro_use]
 extern crate rustling_ontology_values;
@@ -7,33 +8,70 @@ mod rules;
 mod training;
 
 use rustling_ontology_values::DimensionKind::*;
+use rustling_ontology_values::helpers::{self, SeparatorPolicy};
+
+/// Regional variety of Portuguese
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Variant {
+    /// Brazilian Portuguese, e.g. "dezesseis", "bilhão" (10^9), "centavos"
+    Brazilian,
+    /// European Portuguese, e.g. "dezasseis", "bilião" (10^12), "cêntimos"
+    European,
+}
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    rule_set_with_separators(&separators())
+}
+
+/// Digit grouping and decimal separators read by default
+pub fn separators() -> Vec<SeparatorPolicy> {
+    vec![SeparatorPolicy::DotGroupsCommaDecimal]
+}
+
+pub fn rule_set_with_separators(separators: &[SeparatorPolicy]) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    rule_set_with_variant(separators, None)
+}
+
+/// Rules restricted to the wordings of one variant, or reading both of them when none is given.
+/// "bilhão" is 10^9 in Brazil but "bilião" is 10^12 in Portugal.
+pub fn rule_set_with_variant(separators: &[SeparatorPolicy], variant: Option<Variant>) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
-    rules::rules_numbers(&mut b)?;
-    rules::rules_time(&mut b)?;
+    rules::rules_numbers(&mut b, variant)?;
+    helpers::rules_separated_numbers(&mut b, separators)?;
+    helpers::rules_digit_fractions(&mut b)?;
+    rules::rules_digit_sequence(&mut b)?;
+    helpers::rules_digit_sequences(&mut b)?;
+    rules::rules_time(&mut b, variant)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;
-    rules::rules_finance(&mut b)?;
+    rules::rules_distance(&mut b)?;
+    rules::rules_ratio(&mut b)?;
+    rules::rules_finance(&mut b, variant)?;
     rules::rules_percentage(&mut b)?;
+    rules::rules_interval(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Time, Duration, Temperature, Distance, AmountOfMoney, Percentage, NumberInterval, AmountOfMoneyInterval, TemperatureInterval, DurationInterval, Ratio, DigitSequence];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     let mut v = vec![];
     training::examples_numbers(&mut v);
+    training::examples_digit_sequence(&mut v);
     training::examples_percentage(&mut v);
     training::examples_temperature(&mut v);
+    training::examples_distance(&mut v);
+    training::examples_ratio(&mut v);
     training::examples_finance(&mut v);
     training::examples_durations(&mut v);
     training::examples_time(&mut v);
+    training::examples_interval(&mut v);
+    training::examples_european(&mut v);
     v
 }
 
@@ -59,4 +97,21 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+    #[test]
+    fn test_european_variant() {
+        let rules = rule_set_with_variant(&separators(), Some(Variant::European)).unwrap();
+        let mut examples = vec![];
+        training::examples_european(&mut examples);
+        assert_examples(&rules, examples);
+        for text in &["dezesseis", "vinte centavos", "no café da manhã"] {
+            let stash = rules.apply_all(text).unwrap();
+            assert!(stash.iter().all(|candidate| candidate.root_node.byte_range != Range(0, text.len())), format!("Brazilian wording read: {:?}", text));
+        }
+    }
+    #[test]
+    fn test_ambiguous_roman_numerals() {
+        for numeral in rules::AMBIGUOUS_ROMAN_NUMERALS {
+            assert!(helpers::roman_numeral(numeral).is_ok(), format!("Not a Roman numeral: {:?}", numeral));
+        }
+    }
 }
@@ -2,7 +2,64 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use regex::Regex;
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, Season};
+use super::Variant;
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    let denominators = [
+        ("mei", 2),
+        ("terç", 3),
+        ("terc", 3),
+        ("quart", 4),
+        ("quint", 5),
+        ("sext", 6),
+        ("sétim", 7),
+        ("setim", 7),
+        ("oitav", 8),
+        ("non", 9),
+        ("décim", 10),
+        ("decim", 10),
+        ("centésim", 100),
+        ("centesim", 100),
+    ];
+    denominators.iter()
+        .find(|&&(prefix, _)| word.starts_with(prefix))
+        .map(|&(_, denominator)| denominator)
+        .ok_or_else(|| RuleError::Invalid.into())
+}
+
+/// Brazilian and European wordings of a pattern. The regex of the rule is their alternation
+/// whatever the variant, so that the rule sets of the variants intern the same symbols as the
+/// one the model is trained with, and the production rejects the wordings of the other variant.
+struct VariantWording {
+    brazilian: &'static str,
+    european: &'static str,
+    selected: Option<Regex>,
+}
+
+impl VariantWording {
+    fn new(variant: Option<Variant>, brazilian: &'static str, european: &'static str) -> RustlingResult<VariantWording> {
+        let selected = match variant {
+            Some(Variant::Brazilian) => Some(Regex::new(brazilian)?),
+            Some(Variant::European) => Some(Regex::new(european)?),
+            None => None,
+        };
+        Ok(VariantWording { brazilian, european, selected })
+    }
+
+    fn reg(&self) -> String {
+        format!("{}|{}", self.brazilian, self.european)
+    }
+
+    /// Rejects a matched text which holds no wording of the selected variant
+    fn check(&self, text: &str) -> RuleResult<()> {
+        match self.selected {
+            Some(ref regex) if !regex.is_match(text) => Err(RuleError::Invalid.into()),
+            _ => Ok(()),
+        }
+    }
+}
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -13,103 +70,138 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     Ok(())
 }
 
-pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: Some((r#"entre"#, r#"e"#)),
+        from_to: Some((r#"de"#, r#"a"#)),
+        range: r#"-|~"#,
+        open: vec![
+            (OpenInterval::Under, helpers::OpenIntervalMarker::Before(r#"menos de|abaixo de"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::Before(r#"mais de|acima de"#)),
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::Before(r#"pelo menos|no m[ií]nimo"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::Before(r#"no m[aá]ximo|at[ée]"#)),
+        ],
+    })
+}
+
+pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>, variant: Option<Variant>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              b.reg(r#"e"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_2("intersect",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
+    b.rule_3("intersect (and number)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
+             b.reg(r#"e"#)?,
+             number_check!(),
+             |a, _, b| helpers::compose_money_number(&a.value(), &b.value()));
     b.rule_1_terminal("$",
         b.reg(r#"\$|d[oó]lar(?:es)?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
+    );
+    b.rule_1_terminal("BRL",
+        b.reg(r#"r\$|brl|reais|real(?: brasileiro)?"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::BRL)) })
     );
     b.rule_1_terminal("EUR",
-        b.reg(r#"€|euros?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+        b.reg(r#"€|eur|euros?"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
     );
     b.rule_1_terminal("£",
         b.reg(r#"£|libras?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
     );
     b.rule_1_terminal("GBP",
-                      b.reg(r#"gbp|libras? esterlinas?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+                      b.reg(r#"gbp|libras? (?:esterlinas?|inglesas?)"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
     );
     b.rule_1_terminal("USD",
-        b.reg(r#"d[oó]lar(?:es)? americanos?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
+        b.reg(r#"us[d\$]|d[oó]lar(?:es)? (?:americanos?|norte-americanos?|estadunidenses?)"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
     );
     b.rule_1_terminal("CAD",
-                      b.reg(r#"d[oó]lar(?:es)? canadenses?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+                      b.reg(r#"cad|d[oó]lar(?:es)? canadenses?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
     );
     b.rule_1_terminal("AUD",
-                      b.reg(r#"d[oó]lar(?:es) australianos?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+                      b.reg(r#"aud|d[oó]lar(?:es)? australianos?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
     );
-    b.rule_1_terminal("Bitcoin",
-        b.reg(r#"฿|bitcoins?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
+    b.rule_1_terminal("฿",
+        b.reg(r#"฿"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Baht)) })
     );
-    b.rule_1_terminal("GBP",
-        b.reg(r#"libras? inglesas?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+    b.rule_1_terminal("THB",
+        b.reg(r#"bahts?|thb"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::THB)) })
+    );
+    b.rule_1_terminal("Bitcoin",
+        b.reg(r#"bitcoins?"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
     );
     b.rule_1_terminal("JPY",
-                      b.reg(r#"jpy|[yi]en(?:es)?(?: japoneses?)?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+                      b.reg(r#"jpy|(?:iene|yen)s?(?: japoneses?)?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
     );
     b.rule_1_terminal("¥",
                       b.reg(r#"¥"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("¥") })
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
     );
     b.rule_1_terminal("KRW",
-                      b.reg(r#"wons?|₩"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+                      b.reg(r#"₩|krw|wons?(?: sul-coreanos?)?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
+    );
+    b.rule_1_terminal("RMB|CNH|CNY",
+                      b.reg(r#"cny|cnh|rmb|yuans?(?: chineses?)?|renminbis?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
     );
-//    b.rule_1_terminal("RMB|CNH|CNY",
-//                      b.reg(r#""#)?,
-//                      |_| Ok(MoneyUnitValue { unit: Some("CNY") })
-//    );
     b.rule_1_terminal("INR",
-                      b.reg(r#"r[uú]pias?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+                      b.reg(r#"inr|r[uú]pias?(?: indianas?)?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
+    );
+    b.rule_1_terminal("HKD",
+                      b.reg(r#"hkd|d[oó]lar(?:es)? de hong[- ]kong"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
     );
-//    b.rule_1_terminal("HKD",
-//                      b.reg(r#""#)?,
-//                      |_| Ok(MoneyUnitValue { unit: Some("HKD") })
-//    );
     b.rule_1_terminal("CHF",
-                      b.reg(r#"francos? suíços?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CHF") })
-    );
-//    b.rule_1_terminal("KR",
-//                      b.reg(r#""#)?,
-//                      |_| Ok(MoneyUnitValue { unit: Some("KR") })
-//    );
-//    b.rule_1_terminal("DKK",
-//                      b.reg(r#""#)?,
-//                      |_| Ok(MoneyUnitValue { unit: Some("DKK") })
-//    );
-//    b.rule_1_terminal("NOK",
-//                      b.reg(r#""#)?,
-//                      |_| Ok(MoneyUnitValue { unit: Some("NOK") })
-//    );
-//    b.rule_1_terminal("SEK",
-//                      b.reg(r#""#)?,
-//                      |_| Ok(MoneyUnitValue { unit: Some("SEK") })
-//    );
+                      b.reg(r#"chf|francos? su[ií][cç]os?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
+    );
+    b.rule_1_terminal("RUB",
+                      b.reg(r#"rub|rublos?(?: russos?)?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::RUB)) })
+    );
+    b.rule_1_terminal("KR",
+                      b.reg(r#"kr|coroas?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Krone)) })
+    );
+    b.rule_1_terminal("DKK",
+                      b.reg(r#"dkk|coroas? dinamarquesas?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::DKK)) })
+    );
+    b.rule_1_terminal("NOK",
+                      b.reg(r#"nok|coroas? norueguesas?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::NOK)) })
+    );
+    b.rule_1_terminal("SEK",
+                      b.reg(r#"sek|coroas? suecas?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SEK)) })
+    );
+    let cent = VariantWording::new(variant, r#"centavos?"#, r#"c[eê]ntimos?"#)?;
     b.rule_1_terminal("cent",
-                      b.reg(r#"centavos?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+                      b.reg(&cent.reg())?,
+                      move |text_match| {
+                          cent.check(text_match.group(0))?;
+                          Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
+                      }
     );
     b.rule_2("<amount> <unit>",
              number_check!(),
@@ -117,6 +209,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -128,6 +221,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -138,12 +232,13 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     precision: b.value().precision(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
              });
     b.rule_2("about <amount-of-money>",
-             b.reg(r#"cerca de"#)?,
+             b.reg(r#"aproximadamente|cerca de|por volta de|quase|uns|umas"#)?,
              amount_of_money_check!(),
              |_, a| {
                  Ok(AmountOfMoneyValue {
@@ -151,48 +246,52 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
-//    b.rule_2("<amount-of-money> about",
-//             amount_of_money_check!(),
-//             b.reg(r#""#)?,
-//             |a, _| {
-//                 Ok(AmountOfMoneyValue {
-//                     precision: Approximate,
-//                     ..a.value().clone()
-//                 })
-//             });
+    b.rule_2("<amount-of-money> about",
+             amount_of_money_check!(),
+             b.reg(r#"mais ou menos|aproximadamente"#)?,
+             |a, _| {
+                 Ok(AmountOfMoneyValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    let exactly = VariantWording::new(variant, r#"exatamente"#, r#"exa(?:c)?tamente"#)?;
     b.rule_2("exactly <amount-of-money>",
-             b.reg(r#"exatamente"#)?,
+             b.reg(&exactly.reg())?,
              amount_of_money_check!(),
-             |_, a| {
+             move |text_match, a| {
+                 exactly.check(text_match.group(0))?;
                  Ok(AmountOfMoneyValue {
                      precision: Exact,
                      ..a.value().clone()
                  })
              });
-//    b.rule_2("<amount-of-money> exactly",
-//             amount_of_money_check!(),
-//             b.reg(r#""#)?,
-//             |a, _| {
-//                 Ok(AmountOfMoneyValue {
-//                     precision: Exact,
-//                     ..a.value().clone()
-//                 })
-//             }
-//    );
+    let exactly = VariantWording::new(variant, r#"exat[oa]s|certinhos?"#, r#"exa(?:c)?t[oa]s"#)?;
+    b.rule_2("<amount-of-money> exactly",
+             amount_of_money_check!(),
+             b.reg(&exactly.reg())?,
+             move |a, text_match| {
+                 exactly.check(text_match.group(0))?;
+                 Ok(AmountOfMoneyValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             }
+    );
     Ok(())
 }
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("seconde (unit-of-duration)",
-                      b.reg(r#"segundos?"#)?,
+                      b.reg(r#"seg(?:undo)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
     );
     b.rule_1_terminal("minute (unit-of-duration)",
-                      b.reg(r#"minutos?"#)?,
+                      b.reg(r#"min(?:uto)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Minute))
     );
     b.rule_1_terminal("hour (unit-of-duration)",
-                      b.reg(r#"horas?"#)?,
+                      b.reg(r#"h(?:ora)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Hour))
     );
     b.rule_1_terminal("day (unit-of-duration)",
@@ -212,21 +311,21 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| Ok(UnitOfDurationValue::new(Grain::Year))
     );
     b.rule_1_terminal("quarter of an hour",
-                      b.reg(r#"quarto de hora"#)?,
+                      b.reg(r#"(?:um )?(?:quarto|1/4) de hora"#)?,
                       |_| Ok(DurationValue::new(PeriodComp::minutes(15).into()))
     );
     b.rule_1_terminal("half an hour",
-                      b.reg(r#"meia hora"#)?,
+                      b.reg(r#"(?:meia|1/2) hora"#)?,
                       |_| Ok(DurationValue::new(PeriodComp::minutes(30).into()))
     );
     b.rule_1_terminal("three-quarters of an hour",
-                      b.reg(r#"tr[eê]s quartos de hora"#)?,
+                      b.reg(r#"(?:tr[eê]s quartos|3/4) de hora"#)?,
                       |_| Ok(DurationValue::new(PeriodComp::minutes(45).into()))
     );
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
     b.rule_3("<integer> <unit-of-duration> and a half",
              integer_check_by_range!(0),
@@ -234,24 +333,24 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"e meia"#)?,
              |integer, uod, _| {
                  let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)).precision(integer.value().precision))
+             }
+    );
+    b.rule_3("<integer> hours and a quarter",
+             integer_check_by_range!(0),
+             b.reg(r#"h(?:ora)?s?"#)?,
+             b.reg(r#"e (?:um )?quarto"#)?,
+             |integer, _, _| {
+                 let quarter: Period = PeriodComp::minutes(15).into();
+                 Ok(DurationValue::new(quarter + PeriodComp::hours(integer.value().value)).precision(integer.value().precision))
              }
     );
-//    b.rule_3("<integer> <unit-of-duration> and a quarter",
-//             integer_check_by_range!(0),
-//             unit_of_duration_check!(),
-//             b.reg(r#""#)?,
-//             |integer, uod, _| {
-//                 let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-//                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
-//             }
-//    );
     b.rule_2("in <duration> (future moment)",
-             b.reg(r#"em"#)?,
+             b.reg(r#"(?:em|dentro de|daqui a)(?: (?:os|as) pr[oó]xim[oa]s)?"#)?,
              duration_check!(),
              |_, duration| duration.value().in_present()
     );
-    b.rule_3("<duration> y <duration>",
+    b.rule_3("<duration> e <duration>",
              duration_check!(|duration: &DurationValue| !duration.suffixed),
              b.reg(r#"e"#)?,
              duration_check!(|duration: &DurationValue| !duration.prefixed),
@@ -272,16 +371,47 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"atr[aá]s"#)?,
              |duration, _| duration.value().ago()
     );
+    b.rule_2("ago <duration>",
+             b.reg(r#"h[aá]|faz"#)?,
+             duration_check!(),
+             |_, duration| duration.value().ago()
+    );
     b.rule_2("<duration> later",
              duration_check!(),
-             b.reg(r#"depois"#)?,
+             b.reg(r#"depois|mais tarde"#)?,
              |duration, _| duration.value().in_present()
     );
     b.rule_2("during <duration>",
-             b.reg(r#"por"#)?,
+             b.reg(r#"(?:durante|por)(?: (?:o|a|uma?|todo o|toda a))?"#)?,
              duration_check!(),
              |_, duration| Ok(duration.value().clone().prefixed())
     );
+    b.rule_2("exactly <duration>",
+             b.reg(r#"exa(?:c)?tamente"#)?,
+             duration_check!(),
+             |_, duration| Ok(duration.value().clone().prefixed().precision(Precision::Exact))
+    );
+    b.rule_2("<duration> exactly",
+             duration_check!(),
+             b.reg(r#"exa(?:c)?tamente|exat[oa]s?"#)?,
+             |duration, _| Ok(duration.value().clone().prefixed().precision(Precision::Exact))
+    );
+    b.rule_2("approx <duration>",
+             b.reg(r#"cerca de|por volta de|aproximadamente|quase"#)?,
+             duration_check!(),
+             |_, duration| Ok(duration.value().clone().prefixed().precision(Precision::Approximate))
+    );
+    b.rule_2("<duration> approx",
+             duration_check!(),
+             b.reg(r#"mais ou menos|aproximadamente"#)?,
+             |duration, _| Ok(duration.value().clone().prefixed().precision(Precision::Approximate))
+    );
+    b.rule_3("<fraction> de <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"de(?: uma?)?"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| helpers::fraction_of_grain(fraction.value().fraction().ok_or(RuleError::Invalid)?, uod.value().grain)
+    );
     Ok(())
 }
 
@@ -310,15 +440,147 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"m[eê]s(?:es)?"#)?,
                       |_| CycleValue::new(Grain::Month)
     );
+    b.rule_1_terminal("trimester (cycle)",
+                      b.reg(r#"trimestres?"#)?,
+                      |_| CycleValue::new(Grain::Quarter)
+    );
     b.rule_1_terminal("year (cycle)",
                       b.reg(r#"anos?"#)?,
                       |_| CycleValue::new(Grain::Year)
     );
-
+    b.rule_2("this <cycle>",
+             b.reg(r#"n?est[ea]|n?ess[ea]|n[oa]"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, 0)
+    );
+    b.rule_3("this <cycle> (current)",
+             b.reg(r#"n?[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"atual|corrente"#)?,
+             |_, cycle, _| helpers::cycle_nth(cycle.value().grain, 0)
+    );
+    b.rule_3("the <cycle> past",
+             b.reg(r#"n?[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"passad[oa]|anterior"#)?,
+             |_, cycle, _| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_2("<cycle> past",
+             cycle_check!(),
+             b.reg(r#"passad[oa]"#)?,
+             |cycle, _| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_2("the past <cycle>",
+             b.reg(r#"(?:n?[oa] )?[uú]ltim[oa]"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_3("the <cycle> next",
+             b.reg(r#"n?[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"que vem|seguinte"#)?,
+             |_, cycle, _| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_2("<cycle> next",
+             cycle_check!(),
+             b.reg(r#"que vem"#)?,
+             |cycle, _| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_2("the next <cycle>",
+             b.reg(r#"(?:n?[oa] )?pr[oó]xim[oa]"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_4("the <cycle> after <time>",
+             b.reg(r#"n?[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"(?:seguinte|depois) a[os]?|depois d[eoa]s?"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, 1, time.value())
+    );
+    b.rule_4("the <cycle> before <time>",
+             b.reg(r#"n?[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"antes d[eoa]s?"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, -1, time.value())
+    );
+    b.rule_3("past n <cycle>",
+             b.reg(r#"(?:n?[oa]s )?[uú]ltim[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, -1 * integer.value().value)
+    );
+    b.rule_3("n past <cycle>",
+             integer_check_by_range!(2, 9999),
+             b.reg(r#"[uú]ltim[oa]s"#)?,
+             cycle_check!(),
+             |integer, _, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, -1 * integer.value().value)
+    );
+    b.rule_4("the n past <cycle>",
+             b.reg(r#"n?[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             b.reg(r#"[uú]ltim[oa]s"#)?,
+             cycle_check!(),
+             |_, integer, _, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, -1 * integer.value().value)
+    );
+    b.rule_4("the n <cycle> past",
+             b.reg(r#"n?[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             b.reg(r#"passad[oa]s|anteriores"#)?,
+             |_, integer, cycle, _| helpers::cycle_n_not_immediate(cycle.value().grain, -1 * integer.value().value)
+    );
+    b.rule_3("next n <cycle>",
+             b.reg(r#"(?:n?[oa]s )?pr[oó]xim[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+    );
+    b.rule_3("n next <cycle>",
+             integer_check_by_range!(2, 9999),
+             b.reg(r#"pr[oó]xim[oa]s"#)?,
+             cycle_check!(),
+             |integer, _, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+    );
+    b.rule_4("the n next <cycle>",
+             b.reg(r#"n?[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             b.reg(r#"pr[oó]xim[oa]s"#)?,
+             cycle_check!(),
+             |_, integer, _, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+    );
+    b.rule_4("the n <cycle> next",
+             b.reg(r#"n?[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             b.reg(r#"seguintes|que v[eê]m"#)?,
+             |_, integer, cycle, _| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+    );
+    b.rule_2("<ordinal> quarter",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             |ordinal, _| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 &helpers::cycle_nth(Grain::Year, 0)?
+             )
+    );
+    b.rule_4("<ordinal> quarter <year>",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             b.reg(r#"d[eo]"#)?,
+             time_check!(),
+             |ordinal, _, _, time| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 time.value()
+             )
+    );
     Ok(())
 }
 
-pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+pub fn rules_time(b: &mut RuleSetBuilder<Dimension>, variant: Option<Variant>) -> RustlingResult<()> {
     b.rule_2("intersect",
              time_check!(|time: &TimeValue| !time.latent),
              time_check!(|time: &TimeValue| !time.latent),
@@ -326,7 +588,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_3("intersect by `de`",
              time_check!(|time: &TimeValue| !time.latent),
-             b.reg(r#"de"#)?,
+             b.reg(r#"d[eoa]"#)?,
              time_check!(|time: &TimeValue| !time.latent),
              |a, _, b| a.value().intersect(b.value())
     );
@@ -337,110 +599,433 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _, b| a.value().intersect(b.value())
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"segunda(?:[- ]feira)?"#)?,
+                      b.reg(r#"segunda(?:[- ]feira)?|seg\.?"#)?,
                       |_| helpers::day_of_week(Weekday::Mon)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"ter[cç]a(?:[- ]feira)?"#)?,
+                      b.reg(r#"ter[cç]a(?:[- ]feira)?|ter\."#)?,
                       |_| helpers::day_of_week(Weekday::Tue)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"quarta(?:[- ]feira)?"#)?,
+                      b.reg(r#"quarta(?:[- ]feira)?|qua\."#)?,
                       |_| helpers::day_of_week(Weekday::Wed)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"quinta(?:[- ]feira)?"#)?,
+                      b.reg(r#"quinta(?:[- ]feira)?|qui\."#)?,
                       |_| helpers::day_of_week(Weekday::Thu)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"sexta(?:[- ]feira)?"#)?,
+                      b.reg(r#"sexta(?:[- ]feira)?|sex\."#)?,
                       |_| helpers::day_of_week(Weekday::Fri)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"s[aá]bado"#)?,
+                      b.reg(r#"s[aá]bado|s[aá]b\.?"#)?,
                       |_| helpers::day_of_week(Weekday::Sat)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"domingo"#)?,
+                      b.reg(r#"domingo|dom\."#)?,
                       |_| helpers::day_of_week(Weekday::Sun)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"janeiro"#)?,
+                      b.reg(r#"janeiro|jan\.?"#)?,
                       |_| helpers::month(1)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"fevereiro"#)?,
+                      b.reg(r#"fevereiro|fev\.?"#)?,
                       |_| helpers::month(2)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"mar[cç]o"#)?,
+                      b.reg(r#"mar[cç]o|mar\."#)?,
                       |_| helpers::month(3)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"abril"#)?,
+                      b.reg(r#"abril|abr\.?"#)?,
                       |_| helpers::month(4)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"maio"#)?,
+                      b.reg(r#"maio|mai\."#)?,
                       |_| helpers::month(5)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"junho"#)?,
+                      b.reg(r#"junho|jun\.?"#)?,
                       |_| helpers::month(6)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"julho"#)?,
+                      b.reg(r#"julho|jul\.?"#)?,
                       |_| helpers::month(7)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"agosto"#)?,
+                      b.reg(r#"agosto|ago\.?"#)?,
                       |_| helpers::month(8)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"setembro"#)?,
+                      b.reg(r#"setembro|set\."#)?,
                       |_| helpers::month(9)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"outubro"#)?,
+                      b.reg(r#"outubro|out\."#)?,
                       |_| helpers::month(10)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"novembro"#)?,
+                      b.reg(r#"novembro|nov\.?"#)?,
                       |_| helpers::month(11)
     );
     b.rule_1_terminal("named-month",
-                      b.reg(r#"dezembro"#)?,
+                      b.reg(r#"dezembro|dez\."#)?,
                       |_| helpers::month(12)
     );
+    b.rule_1_terminal("christmas",
+                      b.reg(r#"(?:o )?(?:dia de )?natal"#)?,
+                      |_| helpers::month_day(12, 25)
+    );
+    b.rule_1_terminal("christmas eve",
+                      b.reg(r#"(?:a )?v[eé]spera de natal|(?:a )?consoada"#)?,
+                      |_| helpers::month_day(12, 24)
+    );
+    b.rule_1_terminal("new year's eve",
+                      b.reg(r#"(?:a )?v[eé]spera de ano novo|(?:o )?r[eé]veillon"#)?,
+                      |_| helpers::month_day(12, 31)
+    );
+    b.rule_1_terminal("new year's day",
+                      b.reg(r#"(?:o )?(?:dia de )?ano novo"#)?,
+                      |_| helpers::month_day(1, 1)
+    );
+    b.rule_1_terminal("labour day",
+                      b.reg(r#"(?:o )?dia do trabalh(?:o|ador)"#)?,
+                      |_| helpers::month_day(5, 1)
+    );
+    b.rule_1_terminal("all saints' day",
+                      b.reg(r#"(?:o )?dia de todos os santos"#)?,
+                      |_| helpers::month_day(11, 1)
+    );
+    b.rule_1_terminal("all souls' day",
+                      b.reg(r#"(?:o )?dia de finados"#)?,
+                      |_| helpers::month_day(11, 2)
+    );
     b.rule_1_terminal("right now",
-                      b.reg(r#"agora mesmo"#)?,
+                      b.reg(r#"agora(?: mesmo)?|j[aá]|imediatamente|neste (?:exato|preciso) (?:momento|instante)"#)?,
                       |_| helpers::cycle_nth(Grain::Second, 0)
     );
-    b.rule_1_terminal("now",
-                      b.reg(r#"agora"#)?,
-                      |_| helpers::cycle_nth(Grain::Day, 0)
-    );
-    b.rule_1_terminal("today",
-                      b.reg(r#"hoje"#)?,
+    b.rule_1_terminal("now / today",
+                      b.reg(r#"hoje|neste momento"#)?,
                       |_| helpers::cycle_nth(Grain::Day, 0)
     );
     b.rule_1_terminal("tomorrow",
-                      b.reg(r#"amanh[aã]"#)?,
+                      b.reg(r#"amanh[aã]|o dia seguinte"#)?,
                       |_| helpers::cycle_nth(Grain::Day, 1)
     );
     b.rule_1_terminal("yesterday",
-                      b.reg(r#"ontem"#)?,
+                      b.reg(r#"ontem|o dia anterior|a v[eé]spera"#)?,
                       |_| helpers::cycle_nth(Grain::Day, -1)
     );
-//    b.rule_1_terminal("the day after tomorrow",
-//                      b.reg(r#""#)?,
-//                      |_| helpers::cycle_nth(Grain::Day, 2)
-//    );
-//    b.rule_1_terminal("the day before yesterday",
-//                      b.reg(r#""#)?,
-//                      |_| helpers::cycle_nth(Grain::Day, -2)
-//    );
-
+    b.rule_1_terminal("the day after tomorrow",
+                      b.reg(r#"depois de amanh[aã]"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 2)
+    );
+    b.rule_1_terminal("the day before yesterday",
+                      b.reg(r#"anteontem|antes de ontem"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, -2)
+    );
+    b.rule_2("this <day-of-week>", //assumed to be in the future
+             b.reg(r#"n?est[ea]"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, time| time.value().the_nth_not_immediate(0)
+    );
+    b.rule_2("this <datetime>",
+             b.reg(r#"n?est[ea]"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth(0)
+    );
+    b.rule_2("in <named-month>",
+             b.reg(r#"(?:durante|em|n?o m[eê]s de)"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, a| Ok(a.value().clone())
+    );
+    b.rule_2("beginning <named-month>(interval)",
+             b.reg(r#"(?:n?o )?(?:in[ií]cio|come[cç]o) d[eo](?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(1)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(5)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_1_terminal("beginning of month",
+                      b.reg(r#"(?:n?o )?(?:in[ií]cio|come[cç]o) do m[eê]s"#)?,
+                      |_| {
+                          let month = helpers::cycle_nth(Grain::Month, 0)?;
+                          Ok(month.intersect(&helpers::day_of_month(1)?)?
+                              .span_to(&month.intersect(&helpers::day_of_month(10)?)?, true)?
+                              .latent()
+                              .form(Form::PartOfMonth))
+                      }
+    );
+    b.rule_1_terminal("end of month",
+                      b.reg(r#"(?:n?o )?(?:fim|final) do m[eê]s"#)?,
+                      |_| {
+                          let month = helpers::cycle_nth(Grain::Month, 1)?;
+                          Ok(helpers::cycle_nth_after(Grain::Day, -10, &month)?
+                              .span_to(&month, false)?
+                              .latent()
+                              .form(Form::PartOfMonth))
+                      }
+    );
+    b.rule_2("end <named-month>(interval)",
+             b.reg(r#"(?:n?o )?(?:fim|final) d[eo](?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(25)?)?;
+                 let end = helpers::cycle(Grain::Day)?.last_of(month.value())?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_2("first 15 days of <named-month>(interval)",
+             b.reg(r#"(?:a )?primeira quinzena d[eo](?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(1)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(14)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_2("second 15 days of <named-month>(interval)",
+             b.reg(r#"(?:a )?segunda quinzena d[eo](?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(15)?)?;
+                 let end = helpers::cycle(Grain::Day)?.last_of(month.value())?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_2("next <named-month|named-day>",
+             b.reg(r#"(?:n?[oa] )?pr[oó]xim[oa]"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth_not_immediate(0)
+    );
+    b.rule_2("last <named-month|named-day>",
+             b.reg(r#"(?:n?[oa] )?[uú]ltim[oa]"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth(-1)
+    );
+    b.rule_2("<named-month|named-day> next",
+             time_check!(),
+             b.reg(r#"que vem|pr[oó]xim[oa]"#)?,
+             |time, _| time.value().the_nth_not_immediate(0)
+    );
+    b.rule_3("the <day-of-week> of next week",
+             b.reg(r#"n?[oa]"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"da (?:semana que vem|pr[oó]xima semana|semana seguinte)"#)?,
+             |_, time, _| time.value().the_nth(1)
+    );
+    b.rule_2("<day-of-week> of next week",
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"da (?:semana que vem|pr[oó]xima semana|semana seguinte)"#)?,
+             |time, _| time.value().the_nth(1)
+    );
+    b.rule_2("<named-month|named-day> past",
+             time_check!(),
+             b.reg(r#"passad[oa]"#)?,
+             |time, _| time.value().the_nth(-1)
+    );
+    b.rule_1("year",
+             integer_check_by_range!(1000, 2100),
+             |integer| {
+                 helpers::year(integer.value().value as i32)
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(-1000, 999),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(2101, 2200),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_2("in <year>",
+             b.reg(r#"(?:em|n?o ano(?: de)?)"#)?,
+             integer_check_by_range!(1000, 2100),
+             |_, integer| helpers::year(integer.value().value as i32)
+    );
+    b.rule_1_terminal("day of month (1st)",
+                      b.reg(r#"(?:o )?primeiro|1[ºo]"#)?,
+                      |_| helpers::day_of_month(1)
+    );
+    b.rule_2("o dia <day-of-month> (non ordinal) (not latent)",
+             b.reg(r#"(?:n?o )?dia"#)?,
+             integer_check_by_range!(1, 31),
+             |_, integer| Ok(helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_2("o <day-of-month> (non ordinal) (latent)",
+             b.reg(r#"n?o"#)?,
+             integer_check_by_range!(1, 31),
+             |_, integer| Ok(helpers::day_of_month(integer.value().value as u32)?.latent())
+    );
+    b.rule_3("the <day-of-month> (ordinal)",
+             b.reg(r#"n?o"#)?,
+             ordinal_check!(),
+             b.reg(r#"dia"#)?,
+             |_, ordinal, _| Ok((*ordinal.value()).prefixed()));
+    b.rule_2("<day-of-month> <named-month>",
+             integer_check_by_range!(1, 31),
+             time_check!(form!(Form::Month(_))),
+             |integer, month| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_3("<day-of-month> de <named-month>",
+             integer_check_by_range!(1, 31),
+             b.reg(r#"de(?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |integer, _, month| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_4("o <day-of-month> de <named-month>",
+             b.reg(r#"(?:n?o )?(?:dia)?"#)?,
+             integer_check_by_range!(1, 31),
+             b.reg(r#"de(?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, integer, _, month| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_3("<ordinal> de <named-month>",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 31),
+             b.reg(r#"de"#)?,
+             time_check!(form!(Form::Month(_))),
+             |ordinal, _, month| month.value().intersect(&helpers::day_of_month(ordinal.value().value as u32)?)
+    );
+    b.rule_4("<day-of-week> <day-of-month> de <named-month>",
+             time_check!(form!(Form::DayOfWeek{..})),
+             integer_check_by_range!(1, 31),
+             b.reg(r#"de(?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, integer, _, month| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_4("último <day-of-week> de <time>",
+             b.reg(r#"(?:o )?[uú]ltimo"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"d[eoa]|em"#)?,
+             time_check!(),
+             |_, dow, _, time| dow.value().last_of(time.value())
+    );
+    b.rule_4("the <cycle> of <time>",
+             b.reg(r#"[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"d[eoa]"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after_not_immediate(cycle.value().grain, 0, time.value())
+    );
+    b.rule_4("nth <time> de <time>",
+             ordinal_check!(),
+             time_check!(),
+             b.reg(r#"d[eoa]|em"#)?,
+             time_check!(),
+             |ordinal, a, _, b| b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+    );
+    b.rule_5("the nth <time> de <time>",
+             b.reg(r#"[oa]"#)?,
+             ordinal_check!(),
+             time_check!(),
+             b.reg(r#"d[eoa]|em"#)?,
+             time_check!(),
+             |_, ordinal, a, _, b| b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+    );
+    b.rule_4("último <cycle> de <time>",
+             b.reg(r#"(?:[oa] )?[uú]ltim[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"d[eoa]|em"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after_not_immediate(cycle.value().grain, -1, time.value())
+    );
+    b.rule_4("nth <cycle> de <time>",
+             ordinal_check!(),
+             cycle_check!(),
+             b.reg(r#"d[eoa]|em"#)?,
+             time_check!(),
+             |ordinal, cycle, _, time| helpers::cycle_nth_after_not_immediate(cycle.value().grain, ordinal.value().value - 1, time.value())
+    );
+    b.rule_5("the nth <cycle> de <time>",
+             b.reg(r#"[oa]"#)?,
+             ordinal_check!(),
+             cycle_check!(),
+             b.reg(r#"d[eoa]|em"#)?,
+             time_check!(),
+             |_, ordinal, cycle, _, time| helpers::cycle_nth_after_not_immediate(cycle.value().grain, ordinal.value().value - 1, time.value())
+    );
+    let week_end = VariantWording::new(variant, r#"fi(?:m|nal) de semana"#, r#"fim[- ]de[- ]semana"#)?;
+    b.rule_3("<ordinal> week-end of <named-month>",
+             ordinal_check!(),
+             b.reg(&format!("(?:{}) de", week_end.reg()))?,
+             time_check!(form!(Form::Month(_))),
+             move |ordinal, text_match, time| {
+                 week_end.check(text_match.group(0))?;
+                 let week_day_start = helpers::day_of_week(Weekday::Fri)?.intersect(&helpers::hour(18, false)?)?;
+                 let week_day_end = helpers::day_of_week(Weekday::Mon)?.intersect(&helpers::hour(0, false)?)?;
+                 let week_day = week_day_start.span_to(&week_day_end, false)?;
+                 let week_ends_of_time = time.value().intersect(&week_day)?;
+                 week_ends_of_time.the_nth(ordinal.value().value - 1)
+             }
+    );
+    let week_end = VariantWording::new(variant, r#"fi(?:m|nal) de semana"#, r#"fim[- ]de[- ]semana"#)?;
+    b.rule_3("last week-end of <named-month>",
+             b.reg(r#"(?:o )?[uú]ltimo"#)?,
+             b.reg(&format!("(?:{}) de", week_end.reg()))?,
+             time_check!(form!(Form::Month(_))),
+             move |_, text_match, time| {
+                 week_end.check(text_match.group(0))?;
+                 let week_day_start = helpers::day_of_week(Weekday::Fri)?.intersect(&helpers::hour(18, false)?)?;
+                 let week_day_end = helpers::day_of_week(Weekday::Mon)?.intersect(&helpers::hour(0, false)?)?;
+                 let week_day = week_day_start.span_to(&week_day_end, false)?;
+                 week_day.last_of(time.value())
+             }
+    );
+    b.rule_2("<named-month> <day-of-month>",
+             time_check!(form!(Form::Month(_))),
+             integer_check_by_range!(1, 31),
+             |month, integer| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_2("<day-of-week> <day-of-month>",
+             time_check!(form!(Form::DayOfWeek{..})),
+             integer_check_by_range!(1, 31),
+             |dow, integer| dow.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_3("<day-of-week> dia <day-of-month>",
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"(?:,\s*)?dia"#)?,
+             integer_check_by_range!(1, 31),
+             |dow, _, integer| dow.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_1("time-of-day (latent)",
+             integer_check_by_range!(1, 23),
+             |integer| Ok(helpers::hour(integer.value().value as u32, integer.value().value < 12)?.latent())
+    );
+    b.rule_1("time-of-day (latent)",
+             integer_check_by_range!(0, 0),
+             |_| Ok(helpers::hour(0, false)?.latent())
+    );
+    b.rule_1_terminal("noon",
+                      b.reg(r#"meio[- ]dia"#)?,
+                      |_| helpers::hour(12, false)
+    );
+    b.rule_1_terminal("midnight",
+                      b.reg(r#"meia[- ]noite"#)?,
+                      |_| helpers::hour(0, false)
+    );
+    b.rule_2("<time-of-day> horas",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"h(?:oras?)?"#)?,
+             |time, _| Ok(time.value().clone().not_latent())
+    );
+    b.rule_2("a <time-of-day>",
+             b.reg(r#"[aà]s?|ao|pel[oa]s?|d[aeo]s?"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             |_, tod| Ok(tod.value().clone().not_latent())
+    );
+    b.rule_2("<time-of-day> em ponto",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"em ponto"#)?,
+             |tod, _| Ok(tod.value().clone().not_latent())
+    );
     b.rule_1_terminal("hh(:|h)mm (time-of-day)",
                       b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:h\.]([0-5]\d)"#)?,
                       |text_match| {
@@ -458,6 +1043,75 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           true
                       )
     );
+    b.rule_2("<time-of-day> am|pm",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"([ap])\.?m\.?"#)?,
+             |a, text_match| {
+                 let day_period = if text_match.group(1) == "a" {
+                     helpers::hour(0, false)?.span_to(&helpers::hour(12, false)?, false)?
+                 } else {
+                     helpers::hour(12, false)?.span_to(&helpers::hour(0, false)?, false)?
+                 };
+                 Ok(a.value().intersect(&day_period)?.form(a.value().form.clone()))
+             }
+    );
+    b.rule_1_terminal("quarter (relative minutes)",
+                      b.reg(r#"(?:um )?quarto"#)?,
+                      |_| Ok(RelativeMinuteValue(15))
+    );
+    b.rule_1_terminal("half (relative minutes)",
+                      b.reg(r#"meia"#)?,
+                      |_| Ok(RelativeMinuteValue(30))
+    );
+    b.rule_1_terminal("3 quarter (relative minutes)",
+                      b.reg(r#"tr[eê]s quartos"#)?,
+                      |_| Ok(RelativeMinuteValue(45))
+    );
+    b.rule_1("number (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             |integer| Ok(RelativeMinuteValue(integer.value().value as i32))
+    );
+    b.rule_2("<integer> minutes (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             b.reg(r#"min(?:uto)?s?"#)?,
+             |integer, _| Ok(RelativeMinuteValue(integer.value().value as i32))
+    );
+    b.rule_2("<hour-of-day> <integer> (as relative minutes)",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             relative_minute_check!(),
+             |time, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("<hour-of-day> and <relative minutes>",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"e"#)?,
+             relative_minute_check!(),
+             |time, _, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("<hour-of-day> minus <relative minutes>",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"menos"#)?,
+             relative_minute_check!(),
+             |time, _, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -1 * relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("<relative minutes> to <hour-of-day>",
+             relative_minute_check!(),
+             b.reg(r#"para (?:[oa]s?|o)"#)?,
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             |relative_minute, _, time| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -1 * relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    // Written dates in numeric formats
     b.rule_1_terminal("dd[/-.]mm[/-.]yyyy",
                       b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](0?[1-9]|1[0-2])[-/.](\d{2,4})"#)?,
                       |text_match| helpers::year_month_day(
@@ -481,6 +1135,417 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(1).parse()?
                       )
     );
+    // End of Written dates in numeric formats
+    b.rule_1_terminal("beginning of day",
+                      b.reg(r#"(?:no|ao) in[ií]cio do dia|logo cedo"#)?,
+                      |_| {
+                          Ok(helpers::hour(6, false)?
+                              .span_to(&helpers::hour(10, false)?, false)?
+                              .latent()
+                              .form(Form::PartOfDay(PartOfDayForm::Morning)))
+                      }
+    );
+    b.rule_1_terminal("middle of day",
+                      b.reg(r#"(?:a|no) meio do dia"#)?,
+                      |_| {
+                          Ok(helpers::hour(11, false)?
+                              .span_to(&helpers::hour(16, false)?, false)?
+                              .latent()
+                              .form(Form::PartOfDay(PartOfDayForm::None)))
+                      }
+    );
+    b.rule_1_terminal("end of day",
+                      b.reg(r#"(?:no|ao) (?:fim|final) do dia"#)?,
+                      |_| {
+                          Ok(helpers::hour(17, false)?
+                              .span_to(&helpers::hour(21, false)?, false)?
+                              .latent()
+                              .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      }
+    );
+    b.rule_1_terminal("morning",
+                      b.reg(r#"manh[aã]"#)?,
+                      |_| Ok(helpers::hour(4, false)?.span_to(&helpers::hour(12, false)?, false)?
+                          .form(Form::PartOfDay(PartOfDayForm::Morning))
+                          .latent())
+    );
+    b.rule_1_terminal("early morning",
+                      b.reg(r#"madrugada"#)?,
+                      |_| Ok(helpers::hour(0, false)?.span_to(&helpers::hour(6, false)?, false)?
+                          .form(Form::PartOfDay(PartOfDayForm::Morning))
+                          .latent())
+    );
+    b.rule_1_terminal("beginning of morning",
+                      b.reg(r#"(?:de|pela) manh[aã] cedo|(?:no )?in[ií]cio da manh[aã]"#)?,
+                      |_| Ok(helpers::hour(4, false)?
+                          .span_to(&helpers::hour(9, false)?, false)?
+                          .latent()
+                          .form(Form::PartOfDay(PartOfDayForm::Morning)))
+    );
+    b.rule_1_terminal("end of morning",
+                      b.reg(r#"(?:no )?(?:fim|final) da manh[aã]"#)?,
+                      |_| Ok(helpers::hour(10, false)?
+                          .span_to(&helpers::hour(12, false)?, false)?
+                          .latent()
+                          .form(Form::PartOfDay(PartOfDayForm::Morning)))
+    );
+    let breakfast = VariantWording::new(variant, r#"(?:o )?caf[eé] da manh[aã]"#, r#"(?:o )?pequeno[- ]almo[cç]o"#)?;
+    b.rule_1_terminal("breakfast",
+                      b.reg(&breakfast.reg())?,
+                      move |text_match| {
+                          breakfast.check(text_match.group(0))?;
+                          Ok(helpers::hour(6, false)?
+                              .span_to(&helpers::hour(10, false)?, false)?
+                              .latent()
+                              .form(Form::Meal))
+                      }
+    );
+    b.rule_1_terminal("lunch",
+                      b.reg(r#"(?:o )?almo[cç]o|(?:a )?hora do almo[cç]o"#)?,
+                      |_| Ok(helpers::hour(12, false)?
+                          .span_to(&helpers::hour(14, false)?, false)?
+                          .latent()
+                          .form(Form::Meal))
+    );
+    b.rule_1_terminal("dinner",
+                      b.reg(r#"(?:o )?jantar|(?:a )?hora do jantar"#)?,
+                      |_| Ok(helpers::hour(19, false)?
+                          .span_to(&helpers::hour(22, false)?, false)?
+                          .latent()
+                          .form(Form::Meal))
+    );
+    b.rule_1_terminal("after lunch",
+                      b.reg(r#"depois do almo[cç]o"#)?,
+                      |_| {
+                          let period = helpers::hour(13, false)?
+                              .span_to(&helpers::hour(17, false)?, false)?;
+                          Ok(helpers::cycle_nth(Grain::Day, 0)?.intersect(&period)?.form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      }
+    );
+    b.rule_1_terminal("before lunch",
+                      b.reg(r#"antes do almo[cç]o"#)?,
+                      |_| {
+                          let period = helpers::hour(10, false)?
+                              .span_to(&helpers::hour(12, false)?, false)?;
+                          Ok(helpers::cycle_nth(Grain::Day, 0)?.intersect(&period)?.form(Form::PartOfDay(PartOfDayForm::Morning)))
+                      }
+    );
+    b.rule_1_terminal("after work",
+                      b.reg(r#"depois do (?:trabalho|expediente)|(?:ao|na) sa[ií]da do trabalho"#)?,
+                      |_| {
+                          let period = helpers::hour(17, false)?
+                              .span_to(&helpers::hour(21, false)?, false)?;
+                          Ok(helpers::cycle_nth(Grain::Day, 0)?.intersect(&period)?.form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      }
+    );
+    b.rule_1_terminal("afternoon",
+                      b.reg(r#"tarde"#)?,
+                      |_| Ok(helpers::hour(12, false)?.span_to(&helpers::hour(19, false)?, false)?
+                          .form(Form::PartOfDay(PartOfDayForm::Afternoon))
+                          .latent())
+    );
+    b.rule_1_terminal("middle afternoon",
+                      b.reg(r#"(?:a|no) meio da tarde"#)?,
+                      |_| {
+                          Ok(helpers::hour(15, false)?
+                              .span_to(&helpers::hour(17, false)?, false)?
+                              .latent()
+                              .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      }
+    );
+    b.rule_1_terminal("end of afternoon",
+                      b.reg(r#"(?:no )?(?:fim|final) da tarde"#)?,
+                      |_| {
+                          Ok(helpers::hour(17, false)?
+                              .span_to(&helpers::hour(19, false)?, false)?
+                              .latent()
+                              .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      }
+    );
+    b.rule_1_terminal("evening",
+                      b.reg(r#"noite"#)?,
+                      |_| Ok(helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?
+                          .form(Form::PartOfDay(PartOfDayForm::Evening))
+                          .latent())
+    );
+    b.rule_1_terminal("beginning of evening",
+                      b.reg(r#"(?:no )?in[ií]cio da noite"#)?,
+                      |_| {
+                          Ok(helpers::hour(18, false)?
+                              .span_to(&helpers::hour(21, false)?, false)?
+                              .latent()
+                              .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      }
+    );
+    b.rule_1_terminal("end of evening",
+                      b.reg(r#"(?:no )?(?:fim|final) da noite|tarde da noite"#)?,
+                      |_| {
+                          Ok(helpers::hour(21, false)?
+                              .span_to(&helpers::hour(0, false)?, false)?
+                              .latent()
+                              .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      }
+    );
+    b.rule_1_terminal("last night",
+                      b.reg(r#"ontem [àa] noite|esta noite passada"#)?,
+                      |_| {
+                          let period = helpers::hour(18, false)?
+                              .span_to(&helpers::hour(0, false)?, false)?;
+                          Ok(helpers::cycle_nth(Grain::Day, -1)?.intersect(&period)?.form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      }
+    );
+    b.rule_2("in the <part-of-day>",
+             b.reg(r#"(?:de|[aà]|ao|n[oa]|pel[oa]|durante(?: [oa])?)"#)?,
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             |_, pod| Ok(pod.value().clone().not_latent())
+    );
+    b.rule_2("this <part-of-day>",
+             b.reg(r#"n?est[ea]"#)?,
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             |_, pod| Ok(helpers::cycle_nth(Grain::Day, 0)?
+                 .intersect(pod.value())?
+                 .form(pod.value().form.clone()))
+    );
+    b.rule_2("<time> <part-of-day>",
+             time_check!(excluding_form!(Form::PartOfDay(_))),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             |a, b| a.value().intersect(b.value())
+    );
+    b.rule_3("<time> prep <part-of-day>",
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"(?:de|[aà]|n[oa]|pel[oa])"#)?,
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             |a, _, b| a.value().intersect(b.value())
+    );
+    b.rule_2("<time-of-day> da tarde",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"da tarde"#)?,
+             |time, _| {
+                 let period = helpers::hour(12, false)?
+                     .span_to(&helpers::hour(21, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> da manhã",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"da (?:manh[aã]|madrugada)"#)?,
+             |time, _| {
+                 let period = helpers::hour(0, false)?
+                     .span_to(&helpers::hour(12, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> da noite",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"da noite"#)?,
+             |a, _| {
+                 let period = helpers::hour(16, false)?
+                     .span_to(&helpers::hour(0, false)?, false)?;
+                 a.value().intersect(&period)
+             }
+    );
+    b.rule_3("<part-of-day> of <time>",
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             b.reg(r#"d[eoa]"#)?,
+             time_check!(),
+             |pod, _, time| time.value().intersect(pod.value())
+    );
+    b.rule_3("the <day-of-month> at <datetime>",
+             b.reg(r#"(?:n?o )?dia"#)?,
+             integer_check_by_range!(1, 31),
+             time_check!(),
+             |_, integer, time| {
+                 let day_of_month = helpers::day_of_month(integer.value().value as u32)?;
+                 day_of_month.intersect(&time.value())
+             }
+    );
+    b.rule_1_terminal("beginning of week",
+                      b.reg(r#"(?:no )?(?:in[ií]cio|come[cç]o) d[aeo](?: ?sta)? semana"#)?,
+                      |_| helpers::day_of_week(Weekday::Mon)
+                          ?.span_to(&helpers::day_of_week(Weekday::Tue)?, false)
+    );
+    b.rule_1_terminal("middle of week",
+                      b.reg(r#"(?:a|no) meio da semana"#)?,
+                      |_| helpers::day_of_week(Weekday::Wed)
+                          ?.span_to(&helpers::day_of_week(Weekday::Thu)?, false)
+    );
+    b.rule_1_terminal("end of week (not weekend)",
+                      b.reg(r#"(?:no )?(?:fim|final) da semana"#)?,
+                      |_| helpers::day_of_week(Weekday::Thu)
+                          ?.span_to(&helpers::day_of_week(Weekday::Sun)?, false)
+    );
+    b.rule_1_terminal("during the week",
+                      b.reg(r#"durante a semana|em dias de semana"#)?,
+                      |_| helpers::day_of_week(Weekday::Mon)
+                          ?.span_to(&helpers::day_of_week(Weekday::Fri)?, false)
+    );
+    let week_end = VariantWording::new(variant, r#"fi(?:m|nal) de semana"#, r#"fim[- ]de[- ]semana"#)?;
+    b.rule_1_terminal("week-end",
+                      b.reg(&week_end.reg())?,
+                      move |text_match| {
+                          week_end.check(text_match.group(0))?;
+                          let friday = helpers::day_of_week(Weekday::Fri)?
+                              .intersect(&helpers::hour(18, false)?)?;
+                          let monday = helpers::day_of_week(Weekday::Mon)?
+                              .intersect(&helpers::hour(0, false)?)?;
+                          friday.span_to(&monday, false)
+                      }
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"(?:o |este |neste )?ver[ãa]o"#)?,
+                      |_| helpers::season(Season::Summer)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"(?:o |este |neste )?outono"#)?,
+                      |_| helpers::season(Season::Autumn)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"(?:o |este |neste )?inverno"#)?,
+                      |_| helpers::season(Season::Winter)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"(?:a |esta |nesta )?primavera"#)?,
+                      |_| helpers::season(Season::Spring)
+    );
+    b.rule_2("o <time>",
+             b.reg(r#"n?[oa]"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |_, time| Ok(time.value().clone())
+    );
+    b.rule_5("dd-dd <month>(interval)",
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             b.reg(r#"\-|a|at[eé]"#)?,
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             b.reg(r#"de(?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |a, _, b, _, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(a.group(1).parse()?)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(b.group(1).parse()?)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_4("dd-dd <month>(interval)",
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             b.reg(r#"\-"#)?,
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             time_check!(form!(Form::Month(_))),
+             |a, _, b, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(a.group(1).parse()?)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(b.group(1).parse()?)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_6("dd-dd <month>(interval)",
+             b.reg(r#"entre(?: o dia| os dias)?"#)?,
+             b.reg(r#"(0?[1-9]|[12]\d|3[01])"#)?,
+             b.reg(r#"e"#)?,
+             b.reg(r#"(0?[1-9]|[12]\d|3[01])"#)?,
+             b.reg(r#"de(?: m[eê]s de)?"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, a, _, b, _, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(a.group(1).parse()?)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(b.group(1).parse()?)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_4("<datetime> - <datetime> (interval)",
+             b.reg(r#"d[eoa]s?"#)?,
+             time_check!(),
+             b.reg(r#"\-|a[os]?|[àa]s|at[eé](?: [oàa]s?)?"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_4("between <datetime> and <datetime> (interval)",
+             b.reg(r#"entre"#)?,
+             time_check!(),
+             b.reg(r#"e"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_2("before <datetime>",
+             b.reg(r#"antes d[eoa]s?|at[eé](?: [aoà]s?)?"#)?,
+             time_check!(),
+             |_, time| Ok(time.value().clone().mark_before_end())
+    );
+    b.rule_2("approx <time-of-day>",
+             b.reg(r#"(?:por volta|cerca) d[ea]s?|l[aá] pel[oa]s?"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             |_, a| Ok(a.value().clone().not_latent())
+    );
+    b.rule_2("<time-of-day> approx",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"mais ou menos|aproximadamente"#)?,
+             |a, _| Ok(a.value().clone().not_latent())
+    );
+    b.rule_2("from <time-of-day>",
+             b.reg(r#"(?:a partir|depois) d[eoa]s?|desde [aoà]s?"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             |_, time| Ok(time.value().clone().mark_after_start())
+    );
+    b.rule_3("from <time-of-day> on",
+             b.reg(r#"(?:a partir|depois) d[eoa]s?|desde [aoà]s?"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"em diante"#)?,
+             |_, time, _| Ok(time.value().clone().mark_after_start())
+    );
+    b.rule_2("(from) <time-of-day> on",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"em diante"#)?,
+             |time, _| Ok(time.value().clone().mark_after_start())
+    );
+    b.rule_2("from <datetime>",
+             b.reg(r#"(?:a partir|depois) d[eoa]s?|desde"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |_, time| Ok(time.value().clone().mark_after_start())
+    );
+    b.rule_3("from <datetime> on",
+             b.reg(r#"(?:a partir|depois) d[eoa]s?|desde"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"em diante"#)?,
+             |_, time, _| Ok(time.value().clone().mark_after_start())
+    );
+    b.rule_2("(from) <datetime> on",
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"em diante"#)?,
+             |time, _| Ok(time.value().clone().mark_after_start())
+    );
+    b.rule_3("em <integer> dias úteis",
+             b.reg(r#"em|dentro de|daqui a"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"dias? [uú]te(?:is|l)"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value)
+    );
+    b.rule_3("nos próximos <integer> dias úteis",
+             b.reg(r#"n?os pr[oó]ximos|em menos de|num prazo de"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"dias? [uú]te(?:is|l)"#)?,
+             |_, integer, _| helpers::cycle_nth(Grain::Second, 0)?
+                    .span_to(&helpers::business_day_nth(integer.value().value)?, true)
+    );
+    b.rule_1_terminal("próximo dia útil",
+                      b.reg(r#"(?:o )?(?:pr[oó]ximo dia [uú]til|dia [uú]til seguinte)"#)?,
+                      |_| helpers::business_day_nth(1)
+    );
+    b.rule_1_terminal("dia útil anterior",
+                      b.reg(r#"(?:o )?dia [uú]til anterior"#)?,
+                      |_| helpers::business_day_nth(-1)
+    );
+    b.rule_1_terminal("último dia útil do mês",
+                      b.reg(r#"(?:o )?[uú]ltimo dia [uú]til do m[eê]s"#)?,
+                      |_| helpers::business_day()?.last_of(&helpers::cycle_nth(Grain::Month, 0)?)
+    );
+    b.rule_2("último dia útil de <time>",
+             b.reg(r#"(?:o )?[uú]ltimo dia [uú]til d[eoa]"#)?,
+             time_check!(),
+             |_, time| helpers::business_day()?.last_of(time.value())
+    );
+    b.rule_4("<ordinal> dia útil de <time>",
+             b.reg(r#"o"#)?,
+             ordinal_check!(),
+             b.reg(r#"dia [uú]til d[eoa]"#)?,
+             time_check!(),
+             |_, ordinal, _, time| helpers::business_day()?.the_nth_after(ordinal.value().value - 1, time.value())
+    );
     Ok(())
 }
 
@@ -488,6 +1553,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     b.rule_1("number as temp", number_check!(), |a| {
         Ok(TemperatureValue {
             value: a.value().value(),
+            precision: a.value().precision(),
             unit: None,
             latent: true,
         })
@@ -498,6 +1564,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("degree"),
                      latent: false,
                  })
@@ -508,6 +1575,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -518,6 +1586,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("kelvin"),
                      latent: false,
                  })
@@ -528,16 +1597,18 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
              });
     b.rule_2("<latent temp> below zero",
              temperature_check!(),
-             b.reg(r#"abaixo de cero"#)?,
+             b.reg(r#"abaixo de zero"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
+                     precision: a.value().precision,
                      latent: false,
                      ..*a.value()
                  })
@@ -545,63 +1616,274 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
-pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> kilometer",
+             number_check!(),
+             b.reg(r#"(?:quil[oôó]metros?|kms?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Kilometer).precision(a.value().precision()))
+    );
+    b.rule_2("<number> meter",
+             number_check!(),
+             b.reg(r#"(?:metros?|m)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Meter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> centimeter",
+             number_check!(),
+             b.reg(r#"(?:cent[ií]metros?|cm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Centimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> millimeter",
+             number_check!(),
+             b.reg(r#"(?:mil[ií]metros?|mm)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Millimeter).precision(a.value().precision()))
+    );
+    b.rule_2("<number> mile",
+             number_check!(),
+             b.reg(r#"(?:milhas?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Mile).precision(a.value().precision()))
+    );
+    b.rule_2("<number> yard",
+             number_check!(),
+             b.reg(r#"(?:jardas?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Yard).precision(a.value().precision()))
+    );
+    b.rule_2("<number> foot",
+             number_check!(),
+             b.reg(r#"(?:p[eé]s?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Foot).precision(a.value().precision()))
+    );
+    b.rule_2("<number> inch",
+             number_check!(),
+             b.reg(r#"(?:polegadas?)"#)?,
+             |a, _| Ok(DistanceValue::new(a.value().value(), DistanceUnit::Inch).precision(a.value().precision()))
+    );
+    b.rule_2("aproximadamente <distance>",
+             b.reg(r#"aproximadamente|cerca de|quase|mais ou menos"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Approximate))
+    );
+    b.rule_2("exatamente <distance>",
+             b.reg(r#"exa(?:c)?tamente"#)?,
+             distance_check!(),
+             |_, distance| Ok(distance.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_ratio(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("ratio (a:b)",
+        b.reg(r#"(\d{1,3}) ?: ?(\d{1,3})"#)?,
+        |text_match| helpers::colon_ratio(text_match.group(1), text_match.group(2))
+    );
+    b.rule_3("<integer> em cada <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"(?:em|de) cada|sobre"#)?,
+             integer_check_by_range!(1),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value))
+    );
+    b.rule_3("<integer> a <integer>",
+             integer_check_by_range!(0),
+             b.reg(r#"a|x"#)?,
+             integer_check_by_range!(0),
+             |numerator, _, denominator| Ok(RatioValue::new(numerator.value().value, denominator.value().value).latent(true))
+    );
+    b.rule_2("resultado de <ratio>",
+             b.reg(r#"(?:o |a )?(?:resultado|placar|marcador|propor[cç][aã]o|rela[cç][aã]o)(?: de| [eé]| foi)?"#)?,
+             ratio_check!(),
+             |_, ratio| Ok(ratio.value().latent(false))
+    );
+    Ok(())
+}
+
+/// Roman numerals which are also Portuguese words or unit symbols
+pub const AMBIGUOUS_ROMAN_NUMERALS: &'static [&'static str] = &["mi", "di", "vi", "li", "xi", "cv", "xl", "cd", "dc", "mm", "cm", "ml", "cl", "dl", "mix"];
+
+pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>, variant: Option<Variant>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
              number_check!(),
              |a, b| helpers::compose_numbers(&a.value(), &b.value())
     );
-    b.rule_1_terminal("number (0..10)",
-                      b.reg(r#"(cero|uma?|dois|duas|tr[eéê]s|quatro|cinco|s[eé]is|sete|oito|nove|dez)"#)?,
+    b.rule_3("intersect (with e)",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             b.reg(r#"e"#)?,
+             number_check!(),
+             |a, _, b| helpers::compose_numbers(&a.value(), &b.value())
+    );
+    b.rule_1_terminal("number (0..15)",
+                      b.reg(r#"(zero|uma?|dois|duas|tr[eê]s|quatro|cinco|seis|sete|oito|nove|dez|onze|doze|treze|quinze)"#)?,
                       |text_match| {
                           let value = match text_match.group(1).as_ref() {
-                              "cero" => 0,
+                              "zero" => 0,
                               "um" => 1,
                               "uma" => 1,
                               "dois" => 2,
                               "duas" => 2,
                               "tres" => 3,
-                              "trés" => 3,
                               "três" => 3,
                               "quatro" => 4,
                               "cinco" => 5,
                               "seis" => 6,
-                              "séis" => 6,
                               "sete" => 7,
                               "oito" => 8,
                               "nove" => 9,
                               "dez" => 10,
+                              "onze" => 11,
+                              "doze" => 12,
+                              "treze" => 13,
+                              "quinze" => 15,
                               _ => return Err(RuleError::Invalid.into()),
                           };
                           IntegerValue::new(value)
                       }
     );
-
-
-    b.rule_1_terminal("integer (numeric)",
-                      b.reg(r#"(\d{1,18})"#)?,
-                      |text_match| IntegerValue::new(text_match.group(0).parse()?));
-    b.rule_1_terminal("integer with thousands separator .",
-                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
+    let fourteen = VariantWording::new(variant, r#"quatorze|catorze"#, r#"catorze"#)?;
+    b.rule_1_terminal("number (14)",
+                      b.reg(&fourteen.reg())?,
+                      move |text_match| {
+                          fourteen.check(text_match.group(0))?;
+                          IntegerValue::new(14)
+                      }
+    );
+    let teens = VariantWording::new(variant, r#"deze(?:sseis|ssete|nove)"#, r#"deza(?:sseis|ssete|nove)"#)?;
+    b.rule_1_terminal("number (16..19)",
+                      b.reg(&teens.reg())?,
+                      move |text_match| {
+                          teens.check(text_match.group(0))?;
+                          let value = match &text_match.group(0)[4..] {
+                              "sseis" => 16,
+                              "ssete" => 17,
+                              "nove" => 19,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          IntegerValue::new(value)
+                      }
+    );
+    b.rule_1_terminal("number (18)",
+                      b.reg(r#"dezoito"#)?,
+                      |_| IntegerValue::new(18)
+    );
+    b.rule_1_terminal("number (20..90)",
+                      b.reg(r#"(vinte|trinta|quarenta|cinq[uü]enta|sessenta|setenta|oitenta|noventa)"#)?,
                       |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "");
-                          let value: i64 = reformatted_string.parse()?;
+                          let value = match text_match.group(1).as_ref() {
+                              "vinte" => 20,
+                              "trinta" => 30,
+                              "quarenta" => 40,
+                              "cinquenta" => 50,
+                              "cinqüenta" => 50,
+                              "sessenta" => 60,
+                              "setenta" => 70,
+                              "oitenta" => 80,
+                              "noventa" => 90,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
                           IntegerValue::new(value)
                       });
-    b.rule_1_terminal("decimal number",
-                      b.reg(r#"(\d*,\d+)"#)?,
+    b.rule_3("number (21..29 31..39 41..49 51..59 61..69 71..79 81..89 91..99)",
+             integer_check_by_range!(20, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             b.reg(r#"e"#)?,
+             integer_check_by_range!(1, 9),
+             |a, _, b| IntegerValue::new(a.value().value + b.value().value));
+    b.rule_1_terminal("hundred",
+                      b.reg(r#"cem|cento"#)?,
+                      |_| IntegerValue::new_with_grain(100, 2)
+    );
+    b.rule_1_terminal("number 200..900",
+                      b.reg(r#"(duz|trez|quatroc|quinh|seisc|setec|oitoc|novec)ent[oa]s"#)?,
                       |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", ".");
-                          let value: f32 = reformatted_string.parse()?;
-                          FloatValue::new(value)
+                          let value = match text_match.group(1).as_ref() {
+                              "duz" => 200,
+                              "trez" => 300,
+                              "quatroc" => 400,
+                              "quinh" => 500,
+                              "seisc" => 600,
+                              "setec" => 700,
+                              "oitoc" => 800,
+                              "novec" => 900,
+                              _ => return Err(RuleError::Invalid.into())
+                          };
+                          IntegerValue::new_with_grain(value, 2)
                       });
+    b.rule_1_terminal("thousand",
+                      b.reg(r#"mil"#)?,
+                      |_| IntegerValue::new_with_grain(1000, 3)
+    );
+    b.rule_1_terminal("million",
+                      b.reg(r#"(?:um )?milh(?:[aã]o|[oõ]es)"#)?,
+                      |_| IntegerValue::new_with_grain(1000000, 6)
+    );
+    // Short scale in Brazil, long scale in Portugal where a "bilião" is a million millions
+    let billion = VariantWording::new(variant, r#"(?:um )?bilh(?:[aã]o|[oõ]es)"#, r#"mil milh(?:[aã]o|[oõ]es)"#)?;
+    b.rule_1_terminal("billion",
+                      b.reg(&billion.reg())?,
+                      move |text_match| {
+                          billion.check(text_match.group(0))?;
+                          IntegerValue::new_with_grain(1000000000, 9)
+                      }
+    );
+    let trillion = VariantWording::new(variant, r#"(?:um )?trilh(?:[aã]o|[oõ]es)"#, r#"(?:um )?bili(?:[aã]o|[oõ]es)"#)?;
+    b.rule_1_terminal("trillion",
+                      b.reg(&trillion.reg())?,
+                      move |text_match| {
+                          trillion.check(text_match.group(0))?;
+                          IntegerValue::new_with_grain(1000000000000, 12)
+                      }
+    );
+    b.rule_2("number thousands",
+             integer_check_by_range!(2, 999),
+             b.reg(r#"mil"#)?,
+             |a, _| {
+                 Ok(IntegerValue {
+                     value: a.value().value * 1000,
+                     grain: Some(3),
+                     ..IntegerValue::default()
+                 })
+             });
+    b.rule_2("number millions",
+             integer_check_by_range!(2, 999),
+             b.reg(r#"milh(?:[aã]o|[oõ]es)"#)?,
+             |a, _| {
+                 Ok(IntegerValue {
+                     value: a.value().value * 1000000,
+                     grain: Some(6),
+                     ..IntegerValue::default()
+                 })
+             });
+    let billions = VariantWording::new(variant, r#"bilh(?:[aã]o|[oõ]es)"#, r#"mil milh(?:[aã]o|[oõ]es)"#)?;
+    b.rule_2("number billions",
+             integer_check_by_range!(2, 999),
+             b.reg(&billions.reg())?,
+             move |a, text_match| {
+                 billions.check(text_match.group(0))?;
+                 Ok(IntegerValue {
+                     value: a.value().value * 1000000000,
+                     grain: Some(9),
+                     ..IntegerValue::default()
+                 })
+             });
+    let trillions = VariantWording::new(variant, r#"trilh(?:[aã]o|[oõ]es)"#, r#"bili(?:[aã]o|[oõ]es)"#)?;
+    b.rule_2("number trillions",
+             integer_check_by_range!(2, 999),
+             b.reg(&trillions.reg())?,
+             move |a, text_match| {
+                 trillions.check(text_match.group(0))?;
+                 Ok(IntegerValue {
+                     value: a.value().value * 1000000000000,
+                     grain: Some(12),
+                     ..IntegerValue::default()
+                 })
+             });
+    b.rule_1_terminal("integer (numeric)",
+                      b.reg(r#"(\d{1,18})"#)?,
+                      |text_match| IntegerValue::new(text_match.group(0).parse()?));
     b.rule_3("number dot number",
              number_check!(|number: &NumberValue| !number.prefixed()),
-             b.reg(r#"v[íi]rgula"#)?,
+             b.reg(r#"v[íi]rgula|ponto"#)?,
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| {
                  let power = b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
@@ -609,25 +1891,18 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              });
     b.rule_4("number dot zero ... number",
              number_check!(|number: &NumberValue| !number.prefixed()),
-             b.reg(r#"v[íi]rgula"#)?,
+             b.reg(r#"v[íi]rgula|ponto"#)?,
              b.reg(r#"(?:(?:zero )*(?:zero))"#)?,
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, zeros, b| {
                  let power = zeros.group(0).split_whitespace().count() + b.value().value().to_string().chars().count();
-                 let coeff = 10.0_f32.powf(-1.0 * power as f32);
+                 let coeff = 10.0_f64.powf(-1.0 * power as f64);
                  Ok(FloatValue {
                      value: b.value().value() * coeff + a.value().value(),
                      ..FloatValue::default()
                  })
              });
 
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                          let value: f32 = reformatted_string.parse()?;
-                          FloatValue::new(value)
-                      });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|menos"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -652,29 +1927,97 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      }
                  })
              });
-    b.rule_1_terminal("ordinals (primero..10)",
-                      b.reg(r#"(primer|segund|terceir|quart|quint|sext|s[eéè]tim|oitav|non|d[eéè]cim)(?:[oa]s?)?"#)?,
+    b.rule_2("numbers suffixes (K, M, G)",
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             b.reg_neg_lh(r#"([kmg])"#, r#"^[\W\$€]"#)?,
+             |a, text_match| -> RuleResult<NumberValue> {
+                 let multiplier = match text_match.group(0).as_ref() {
+                     "k" => 1000,
+                     "m" => 1000000,
+                     "g" => 1000000000,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 Ok(match a.value().clone() {
+                     // checked
+                     NumberValue::Integer(integer) => {
+                         IntegerValue {
+                             value: integer.value * multiplier,
+                             suffixed: true,
+                             ..integer
+                         }
+                             .into()
+                     }
+                     NumberValue::Float(float) => {
+                         let product = float.value * (multiplier as f64);
+                         if product.floor() == product {
+                             IntegerValue {
+                                 value: product as i64,
+                                 suffixed: true,
+                                 ..IntegerValue::default()
+                             }
+                                 .into()
+                         } else {
+                             FloatValue {
+                                 value: product,
+                                 suffixed: true,
+                                 ..float
+                             }
+                                 .into()
+                         }
+                     }
+                 })
+             });
+    b.rule_1_terminal("ordinals (primeiro..10)",
+                      b.reg(r#"(primeir|segund|terceir|quart|quint|sext|s[eé]tim|oitav|non|d[eé]cim)[oa]s?"#)?,
                       |text_match| {
                           let value = match text_match.group(1).as_ref() {
-                              "primer" => 1,
+                              "primeir" => 1,
                               "segund" => 2,
                               "terceir" => 3,
                               "quart" => 4,
                               "quint" => 5,
                               "sext" => 6,
-                              "séptim" => 7,
-                              "sèptim" => 7,
-                              "septim" => 7,
+                              "sétim" => 7,
+                              "setim" => 7,
                               "oitav" => 8,
-                              "noven" => 9,
+                              "non" => 9,
                               "décim" => 10,
-                              "dècim" => 10,
                               "decim" => 10,
                               _ => return Err(RuleError::Invalid.into())
                           };
                           Ok(OrdinalValue::new(value))
                       }
     );
+    b.rule_1_terminal("ordinals 11-19",
+                      b.reg(r#"d[eé]cim[oa] (primeir|segund|terceir|quart|quint|sext|s[eé]tim|oitav|non)[oa]"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "primeir" => 11,
+                              "segund" => 12,
+                              "terceir" => 13,
+                              "quart" => 14,
+                              "quint" => 15,
+                              "sext" => 16,
+                              "sétim" => 17,
+                              "setim" => 17,
+                              "oitav" => 18,
+                              "non" => 19,
+                              _ => return Err(RuleError::Invalid.into())
+                          };
+                          Ok(OrdinalValue::new(value))
+                      }
+    );
+    b.rule_1_terminal("ordinals 20 and 30",
+                      b.reg(r#"(vi|tri)g[eé]sim[oa]"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "vi" => 20,
+                              "tri" => 30,
+                              _ => return Err(RuleError::Invalid.into())
+                          };
+                          Ok(OrdinalValue::new(value))
+                      }
+    );
     b.rule_1_terminal("ordinal (digits)",
                       b.reg(r#"0*(\d+)[ºªoa]"#)?,
                       |text_match| {
@@ -682,5 +2025,48 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           Ok(OrdinalValue::new(value))
                       }
     );
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"((?:mei|ter[çc]|quart|quint|sext|s[ée]tim|oitav|non|d[ée]cim|cent[ée]sim)[oa]s?)"#)?,
+        |integer, text_match| FloatValue::fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    helpers::rules_roman_numerals(b, &helpers::RomanNumeralWords {
+        ordinal_prefix: r#"cap[ií]tulo|parte|volume|tomo|livro|ato|cena|se[cç][ãa]o|artigo|s[ée]culo"#,
+        ordinal_suffix: None,
+        ambiguous: AMBIGUOUS_ROMAN_NUMERALS,
+    })?;
+    b.rule_2("aproximadamente <number>",
+             b.reg(r#"(?:aproximadamente|cerca de|por volta de|mais ou menos|uns|umas)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Approximate)
+    );
+    b.rule_2("<number> aproximadamente",
+             number_check!(),
+             b.reg(r#"aproximadamente|mais ou menos"#)?,
+             |number, _| number.value().clone().with_precision(Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(zero|um|dois|três|tres|quatro|cinco|seis|sete|oito|nove)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "zero" => "0",
+                "um" => "1",
+                "dois" => "2",
+                "três" | "tres" => "3",
+                "quatro" => "4",
+                "cinco" => "5",
+                "seis" => "6",
+                "sete" => "7",
+                "oito" => "8",
+                "nove" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
     Ok(())
 }
@@ -3,41 +3,369 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    // Days
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "agora", "agora mesmo", "neste exato momento", "neste preciso instante");
+    example!(v, check_moment!(c, [2013, 2, 12]), "hoje", "neste momento");
+    example!(v, check_moment!(c, [2013, 2, 11]), "ontem", "o dia anterior", "a véspera");
+    example!(v, check_moment!(c, [2013, 2, 10]), "anteontem", "antes de ontem");
+    example!(v, check_moment!(c, [2013, 2, 13]), "amanhã", "amanha", "o dia seguinte");
+    example!(v, check_moment!(c, [2013, 2, 14]), "depois de amanhã", "depois de amanha");
+    example!(v, check_moment!(c, [2013, 2, 18]), "segunda-feira", "segunda feira", "a segunda", "esta segunda", "seg.", "seg");
+    example!(v, check_moment!(c, [2013, 2, 18]), "segunda 18 de fevereiro", "segunda-feira, 18 de fevereiro", "segunda-feira dezoito de fevereiro");
+    example!(v, check_moment!(c, [2013, 2, 19]), "terça-feira", "terça", "terca-feira", "a terça");
+    example!(v, check_moment!(c, [2013, 2, 13]), "quarta-feira 13 de fevereiro", "quarta-feira, 13 de fevereiro", "quarta treze de fevereiro");
+    example!(v, check_moment!(c, [2013, 2, 14]), "quinta-feira", "quinta", "dois dias depois", "dois dias mais tarde");
+    example!(v, check_moment!(c, [2013, 2, 15]), "sexta-feira", "a sexta");
+    example!(v, check_moment!(c, [2013, 2, 16]), "sábado", "sabado", "o sábado");
+    example!(v, check_moment!(c, [2013, 2, 17]), "domingo", "o domingo");
+    example!(v, check_moment!(c, [2013, 3, 1]), "primeiro de março", "o primeiro de março", "1 de março", "o dia 1 de março", "1º de março", "sexta-feira 1 de março");
+    example!(v, check_moment!(c, [2013, 3, 1]), "1 de março de 2013", "o dia 1 de março de 2013", "1/3/2013", "01/03/2013", "01/03/13", "01-03-2013");
+    example!(v, check_moment!(c, [2013, 3, 2]), "2 de março", "dois de março", "o dia 2 de março", "2/3", "02/03");
+    example!(v, check_moment!(c, [2013, 3, 2]), "o dia 2", "dia 2", "o dia dois");
+    example!(v, check_moment!(c, [2013, 3, 3]), "3 de março", "3 março", "o dia 3 de março", "3/3");
+    example!(v, check_moment!(c, [2013, 4, 5]), "5 de abril", "5 abril", "o dia 5 de abril");
+    example!(v, check_moment!(c, [2015, 3, 3]), "3 de março de 2015", "3 março 2015", "3/3/2015", "03/03/2015", "03/03/15", "3-3-2015");
+    example!(v, check_moment!(c, [2013, 2, 15]), "15 de fevereiro", "15 fevereiro", "o dia 15 de fevereiro");
+    example!(v, check_moment!(c, [2013, 2, 15]), "15/02/2013", "15/02/13", "15 fev. 2013", "15 fev 2013");
+    example!(v, check_moment!(c, [2013, 2, 16]), "o dia 16", "dia dezesseis", "dia dezasseis");
+    example!(v, check_moment!(c, [2013, 2, 17]), "17 de fevereiro", "17/2", "17/02");
+    example!(v, check_moment!(c, [2013, 2, 13]), "quarta-feira 13", "quarta 13", "quarta-feira dia 13");
+    example!(v, check_moment!(c, [2014, 2, 20]), "20/02/2014", "20/2/2014", "20/02/14", "20.02.2014", "20.02.14", "20-02-2014", "20-2-2014", "2014-02-20");
+    example!(v, check_moment!(c, [2013, 10, 31]), "31 de outubro", "31 outubro", "o dia 31 de outubro");
+    example!(v, check_moment!(c, [2014, 12, 24]), "24/12/2014", "24/12/14", "24.12.2014", "24-12-2014");
+    //  smart two-digit year resolution
+    example!(v, check_moment!(c, [1974, 10, 31]), "31/10/1974", "31/10/74");
+    //  when today is Tuesday, "segunda que vem" is next week's Monday
+    example!(v, check_moment!(c, [2013, 2, 18]), "segunda-feira que vem", "a próxima segunda-feira", "na próxima segunda", "a segunda-feira da semana que vem", "segunda da próxima semana");
+    example!(v, check_moment!(c, [2013, 2, 19]), "terça-feira que vem", "a próxima terça", "a terça-feira da semana que vem", "terça da próxima semana");
+    example!(v, check_moment!(c, [2013, 2, 13]), "quarta-feira que vem", "a próxima quarta-feira");
+    example!(v, check_moment!(c, [2013, 2, 20]), "a quarta-feira da semana que vem", "quarta da próxima semana", "a quarta da semana seguinte");
+    example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "esta semana", "nesta semana", "a semana atual");
+    example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "a semana passada", "na semana passada", "a última semana", "a semana anterior");
+    example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "a próxima semana", "na semana que vem", "a semana que vem", "a semana seguinte");
+    example!(v, check_moment!(c, [2013, 1]), "o mês passado", "no mês passado", "o último mês");
+    example!(v, check_moment!(c, [2013, 3]), "o mês que vem", "o próximo mês", "no mês que vem");
+    example!(v, check_moment!(c, [2012]), "o ano passado", "no ano passado", "o ano anterior");
+    example!(v, check_moment!(c, [2013]), "este ano", "neste ano", "o ano corrente");
+    example!(v, check_moment!(c, [2014]), "o ano que vem", "o próximo ano", "no ano que vem");
+    example!(v, check_moment!(c, [2013, 2, 10]), "o domingo passado", "domingo passado", "o último domingo");
+    example!(v, check_moment!(c, [2013, 10, 3]), "o terceiro dia de outubro", "terceiro dia de outubro");
+    example!(v, check_moment!(c, [2014, 10, 6], Grain::Week), "primeira semana de outubro de 2014", "a primeira semana de outubro de 2014");
+
+    // Day times
+    example!(v, check_moment!(c, [2013, 2, 12, 15]), "às quinze horas", "às três da tarde", "as 3 da tarde");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 0]), "15:00", "15h00", "15.00");
+    example!(v, check_moment!(c, [2013, 2, 13, 00]), "meia-noite", "meia noite", "à meia-noite");
+    example!(v, check_moment!(c, [2013, 2, 12, 12]), "meio-dia", "meio dia", "ao meio-dia", "hoje ao meio-dia");
+    example!(v, check_moment!(c, [2013, 2, 12, 12, 15]), "meio-dia e quinze", "meio-dia e um quarto", "doze e quinze");
+    example!(v, check_moment!(c, [2013, 2, 12, 11, 55]), "cinco para o meio-dia", "meio-dia menos cinco", "onze e cinquenta e cinco", "onze e cinquenta e cinco minutos");
+    example!(v, check_moment!(c, [2013, 2, 12, 12, 30]), "meio-dia e meia", "doze e meia", "doze e trinta");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 15]), "às quinze e quinze", "às três e quinze da tarde", "às três e um quarto da tarde", "15:15", "15h15");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 30]), "às quinze e trinta", "às três e meia da tarde", "15:30", "15h30");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 45]), "quinze para as três da tarde", "às três menos um quarto da tarde", "14:45");
+
+    // Day + day time
+    example!(v, check_moment!(c, [2013, 3, 2, 5, 0]), "2 de março às 5:00");
+    example!(v, check_moment!(c, [2013, 3, 2, 5]), "2 de março às 5h", "o dia 2 de março às 5h", "02/03 às 5 da manhã", "02/03 às 5 da madrugada");
+    example!(v, check_moment!(c, [2013, 3, 2, 5]), "2 de março por volta das 5h", "2 de março às 5h mais ou menos");
+    example!(v, check_moment!(c, [2013, 3, 2, 5]), "o dia 2 às 5", "dia dois às cinco", "o dia 2 às 5 da manhã");
+    example!(v, check_moment!(c, [2013, 2, 16, 6]), "o dia 16 às 6 da manhã", "dia 16 às 6 da madrugada");
+    example!(v, check_moment!(c, [2013, 2, 16, 18]), "o dia 16 às 18 horas", "sábado 16 às 6 da tarde");
+    example!(v, check_moment!(c, [2013, 2, 13, 11]), "amanhã às 11", "amanhã às onze horas");
+    example!(v, check_moment!(c, [2013, 2, 14, 11]), "quinta-feira às 11h", "depois de amanhã às 11h");
+    example!(v, check_moment!(c, [2013, 2, 14, 11, 0]), "depois de amanhã às 11:00");
+    example!(v, check_moment!(c, [2013, 2, 15, 12]), "sexta-feira ao meio-dia", "sexta ao meio-dia");
+    example!(v, check_moment!(c, [2013, 2, 15, 16]), "sexta-feira 15 às dezesseis horas", "sexta-feira 15 às 16h", "sexta quinze às quatro da tarde");
+    example!(v, check_moment!(c, [2013, 2, 15, 16, 0]), "sexta-feira 15 às 16:00");
+
+    // In + duration / duration + ago
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 1]), "daqui a um segundo", "em um segundo", "dentro de um segundo");
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 31, 0]), "daqui a um minuto", "em um minuto");
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 32, 0]), "daqui a 2 minutos", "em 2 minutos", "dentro de dois minutos");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 30, 0]), "daqui a 60 minutos", "em 60 minutos");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "daqui a uma hora", "em uma hora", "dentro de uma hora");
+    example!(v, check_moment!(c, [2013, 2, 13, 4, 30]), "daqui a 24 horas", "dentro de vinte e quatro horas");
+    example!(v, check_moment!(c, [2013, 2, 13]), "daqui a um dia", "em um dia");
+    example!(v, check_moment!(c, [2013, 2, 19]), "daqui a sete dias", "em sete dias");
+    example!(v, check_moment!(c, [2013, 2, 19]), "daqui a uma semana", "dentro de uma semana");
+    example!(v, check_moment!(c, [2013, 2, 26]), "daqui a duas semanas", "em duas semanas", "dentro de 2 semanas");
+    example!(v, check_moment!(c, [2013, 2, 27]), "em 15 dias", "daqui a quinze dias", "dentro de 15 dias");
+    example!(v, check_moment!(c, [2013, 4, 12]), "daqui a dois meses", "em dois meses");
+    example!(v, check_moment!(c, [2013, 5, 12]), "daqui a 3 meses", "em três meses");
+    example!(v, check_moment!(c, [2014, 2]), "daqui a um ano", "em um ano");
+    example!(v, check_moment!(c, [2013, 2, 12, 2, 30]), "há duas horas", "duas horas atrás", "faz duas horas");
+    example!(v, check_moment!(c, [2013, 1, 22]), "há três semanas", "três semanas atrás");
+    example!(v, check_moment!(c, [2012, 11, 12]), "há três meses", "faz três meses");
+    example!(v, check_moment!(c, [2011, 2]), "há dois anos", "dois anos atrás");
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 45, 0]), "daqui a um quarto de hora", "daqui a 1/4 de hora");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "daqui a meia hora", "dentro de 1/2 hora");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 15, 0]), "daqui a três quartos de hora", "daqui a 3/4 de hora");
+
+    // Business days
+    example!(v, check_moment!(c, [2013, 2, 15]), "em 3 dias úteis", "daqui a 3 dias úteis");
+    example!(v, check_moment!(c, [2013, 2, 13]), "o próximo dia útil", "o dia útil seguinte");
+    example!(v, check_moment!(c, [2013, 2, 11]), "o dia útil anterior");
+    example!(v, check_moment!(c, [2013, 3, 29]), "o último dia útil de março", "último dia útil de março");
+    example!(v, check_moment!(c, [2013, 2, 28]), "o último dia útil do mês");
+    example!(v, check_moment!(c, [2013, 3, 4]), "o segundo dia útil de março");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 20]), "nos próximos 5 dias úteis", "num prazo de 5 dias úteis");
+
+    // Seasons
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "verão", "este verão");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "inverno", "neste inverno");
+    let southern = c.clone().with_hemisphere(Hemisphere::Southern);
+    example!(v, check_moment_span!(southern, [2012, 12, 21], [2013, 3, 20]), "verão", "este verão");
+
+    // Holidays
+    example!(v, check_moment!(c, [2013, 12, 25]), "natal", "o natal", "o dia de natal");
+    example!(v, check_moment!(c, [2013, 12, 24]), "véspera de natal", "a consoada");
+    example!(v, check_moment!(c, [2013, 12, 31]), "réveillon", "véspera de ano novo");
+    example!(v, check_moment!(c, [2014, 1, 1]), "ano novo", "o dia de ano novo", "primeiro de janeiro");
+    example!(v, check_moment!(c, [2013, 5, 1]), "dia do trabalhador", "o dia do trabalho", "primeiro de maio");
+    example!(v, check_moment!(c, [2013, 11, 2]), "dia de finados");
+
+    // Part of day (morning, afternoon...)
+    example!(v, check_moment_span!(c, [2013, 2, 12, 12], [2013, 2, 12, 19]), "esta tarde", "à tarde", "hoje à tarde", "de tarde");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 15], [2013, 2, 12, 17]), "no meio da tarde", "a meio da tarde");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4], [2013, 2, 12, 9]), "de manhã cedo", "no início da manhã", "pela manhã cedo");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 10], [2013, 2, 12, 12]), "no fim da manhã", "no final da manhã");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 13], [2013, 2, 12, 17]), "depois do almoço");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 10], [2013, 2, 12, 12]), "antes do almoço");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 12], [2013, 2, 12, 14]), "durante o almoço", "na hora do almoço");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 6], [2013, 2, 12, 10]), "no café da manhã", "ao pequeno-almoço", "no início do dia", "logo cedo");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 19], [2013, 2, 12, 22]), "no jantar", "na hora do jantar");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 17], [2013, 2, 12, 21]), "depois do trabalho", "na saída do trabalho", "no fim do dia", "ao final do dia");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 11], [2013, 2, 12, 16]), "no meio do dia", "a meio do dia");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 00]), "esta noite", "à noite", "hoje à noite", "de noite");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 12, 21]), "no início da noite");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 21], [2013, 2, 13, 00]), "no fim da noite", "tarde da noite");
+    example!(v, check_moment_span!(c, [2013, 2, 18, 4], [2013, 2, 18, 12]), "segunda-feira de manhã", "na segunda de manhã", "segunda pela manhã");
+    example!(v, check_moment_span!(c, [2013, 2, 18, 12], [2013, 2, 18, 19]), "segunda-feira à tarde", "na segunda à tarde");
+    example!(v, check_moment_span!(c, [2013, 2, 15, 4], [2013, 2, 15, 12]), "15 de fevereiro de manhã", "o dia quinze de fevereiro pela manhã");
+    example!(v, check_moment_span!(c, [2013, 2, 14, 12], [2013, 2, 14, 19]), "depois de amanhã à tarde", "depois de amanhã de tarde");
+    example!(v, check_moment!(c, [2013, 2, 12, 20]), "hoje às 8 da noite", "8 da noite", "às oito da noite");
+    example!(v, check_moment!(c, [2013, 2, 13, 3]), "3 da manhã", "às 3 da manhã", "às três da madrugada");
+
+    // Part of the week/month
+    example!(v, check_moment_span!(c, [2013, 2, 13, 18], [2013, 2, 14, 00]), "amanhã à noite", "quarta-feira à noite", "na quarta à noite");
+    example!(v, check_moment_span!(c, [2013, 2, 11, 18], [2013, 2, 12, 00]), "ontem à noite", "ontem de noite");
+    example!(v, check_moment_span!(c, [2013, 2, 15, 18], [2013, 2, 18, 00]), "este fim de semana", "neste final de semana", "o fim de semana", "neste fim-de-semana");
+    example!(v, check_moment_span!(c, [2013, 2, 11], [2013, 2, 13]), "no início da semana", "no começo da semana", "no início desta semana");
+    example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 15]), "no meio da semana", "a meio da semana");
+    example!(v, check_moment_span!(c, [2013, 2, 14], [2013, 2, 18]), "no fim da semana", "no final da semana");
+    example!(v, check_moment_span!(c, [2013, 2, 11], [2013, 2, 16]), "durante a semana", "em dias de semana");
+    example!(v, check_moment_span!(c, [2013, 2, 19], [2013, 03, 01]), "no fim do mês", "no final do mês", "fim do mês");
+    example!(v, check_moment_span!(c, [2013, 9, 6, 18], [2013, 9, 9, 00]), "o primeiro fim de semana de setembro", "o primeiro fim-de-semana de setembro", "primeiro final de semana de setembro");
+    example!(v, check_moment_span!(c, [2013, 9, 13, 18], [2013, 9, 16, 00]), "o segundo fim de semana de setembro");
+    example!(v, check_moment_span!(c, [2013, 9, 27, 18], [2013, 9, 30, 00]), "o último fim de semana de setembro");
+
+    // Intervals involving cycles
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 29, 58], [2013, 2, 12, 4, 30, 00]), "os últimos 2 segundos", "os dois últimos segundos");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 01], [2013, 2, 12, 4, 30, 04]), "os próximos 3 segundos", "os 3 próximos segundos");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 28], [2013, 2, 12, 4, 30]), "os 2 últimos minutos", "últimos dois minutos");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 5], [2013, 2, 12, 8]), "as próximas 3 horas", "as 3 horas seguintes");
+    example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 16]), "próximos 3 dias", "os próximos 3 dias", "os três dias seguintes");
+    example!(v, check_moment_span!(c, [2013, 1, 28], [2013, 2, 11]), "as últimas 2 semanas", "últimas duas semanas", "as 2 semanas passadas");
+    example!(v, check_moment_span!(c, [2013, 2, 18], [2013, 3, 11]), "as próximas três semanas", "as três semanas que vêm");
+    example!(v, check_moment_span!(c, [2012, 12], [2013, 02]), "os últimos dois meses", "os dois meses anteriores");
+    example!(v, check_moment_span!(c, [2013, 3], [2013, 6]), "os três próximos meses", "os próximos três meses");
+    example!(v, check_moment_span!(c, [2011], [2013]), "os últimos 2 anos", "os dois anos passados");
+    example!(v, check_moment_span!(c, [2014], [2017]), "os próximos 3 anos");
+
+    // Explicit intervals
+    example!(v, check_moment_span!(c, [2013, 7, 13], [2013, 7, 16]), "13-15 julho", "13 a 15 de julho", "entre 13 e 15 de julho", "entre os dias 13 e 15 de julho");
+    example!(v, check_moment_span!(c, [2013, 6, 10], [2013, 7, 2]), "de 10 de junho a 1 de julho", "entre 10 de junho e 1 de julho", "de dez de junho até primeiro de julho");
+    example!(v, check_moment_span!(c, [2017, 4, 6], [2017, 6, 9]), "de seis de abril a oito de junho de dois mil e dezessete", "de 6 de abril a 8 de junho de 2017");
+    example!(v, check_moment_with_direction!(c, [2013, 2, 14, 9, 30], Direction::After), "a partir das 09:30 de quinta-feira", "quinta-feira a partir das 9 e meia");
+    example!(v, check_moment_with_direction!(c, [2013, 11, 1, 16], Direction::After), "1 de novembro depois das 16 horas", "1 de novembro a partir das 16h");
+    example!(v, check_moment_with_direction!(c, [2013, 11, 1], Direction::After), "depois do dia 1 de novembro", "a partir de 1 de novembro");
+    example!(v, check_moment_with_direction!(c, [2013, 2, 15, 12], Direction::After), "sexta-feira a partir do meio-dia", "sexta a partir do meio-dia");
+    example!(v, check_moment_span!(c, [2014, 9, 14], [2014, 9, 21]), "14-20 set. 2014", "14-20 setembro 2014");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 5], [2013, 2, 12, 7]), "das 5 às 7", "entre as 5 e as 7", "de 5 a sete");
+    example!(v, check_moment_span!(c, [2013, 2, 14, 9], [2013, 2, 14, 11]), "quinta-feira das 9 às 11", "quinta entre as 9 e as 11");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 11, 30], [2013, 2, 12, 13, 30]), "das 11:30 às 13:30", "entre as 11:30 e as 13:30");
+    example!(v, check_moment!(c, [2013, 9, 21, 13, 30]), "às 13:30 de sábado 21 de setembro", "sábado 21 de setembro às 13h30");
+    example!(v, check_moment_span!(c, [2013, 3, 25], [2013, 4, 1]), "no fim de março", "no final do mês de março");
+    example!(v, check_moment_span!(c, [2013, 4, 1], [2013, 4, 6]), "no início de abril", "no começo do mês de abril");
+    example!(v, check_moment_span!(c, [2013, 4, 1], [2013, 4, 15]), "a primeira quinzena de abril", "a primeira quinzena do mês de abril");
+    example!(v, check_moment_span!(c, [2013, 4, 15], [2013, 5, 01]), "a segunda quinzena de abril", "a segunda quinzena do mês de abril");
+    example!(v, check_moment!(c, [2013, 3]), "março", "em março", "durante o mês de março", "o mês de março", "no mês de março");
+    example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16", "15/12/2016");
+}
+
+pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration!([0, 0, 0, 0, 2]), "durante duas horas", "por duas horas", "duas horas");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "mais ou menos 3 horas");
+    example!(v, check_duration!([0, 0, 0, 1]), "durante um dia", "por um dia");
+    example!(v, check_duration!([0, 1, 0]), "durante um mês", "por um mês");
+    example!(v, check_duration!([1]), "durante um ano", "por um ano");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 3]), "durante um minuto e três segundos", "um minuto e três segundos");
+    example!(v, check_duration!([0, 0, 0, 0, 1, 30], Precision::Approximate), "cerca de uma hora e meia", "uma hora e meia mais ou menos", "uma hora e meia aproximadamente", "por volta de 1h30");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante um quarto de hora mais ou menos", "aproximadamente um quarto de hora");
+    example!(v, check_duration!([0, 0, 0, 0, 1]), "durante uma hora", "por uma hora");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 15]), "duas horas e um quarto", "duas horas e quinze minutos");
+    example!(v, check_duration!([0, 0, 2]), "durante 2 semanas", "por duas semanas");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 30], Precision::Exact), "exatamente meia hora", "meia hora exata", "30 minutos exatamente");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "três quartos de hora");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "dois terços de hora");
+}
+
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(1), "1", "um", "uma");
+    example!(v, check_integer(2), "dois", "duas");
+    example!(v, check_integer(11), "onze");
+    example!(v, check_integer(14), "catorze", "quatorze");
+    example!(v, check_integer(16), "dezesseis", "dezasseis");
+    example!(v, check_integer(17), "dezessete", "dezassete");
+    example!(v, check_integer(18), "dezoito");
+    example!(v, check_integer(19), "dezenove", "dezanove");
+    example!(v, check_integer(21), "vinte e um", "vinte e uma");
+    example!(v, check_integer(23), "vinte e três", "vinte e tres");
+    example!(v, check_integer(50), "cinquenta", "cinqüenta");
+    example!(v, check_integer(70), "setenta");
+    example!(v, check_integer(78), "setenta e oito");
+    example!(v, check_integer(80), "oitenta");
+    example!(v, check_integer(91), "noventa e um");
+    example!(v, check_integer(99), "noventa e nove");
+    example!(v, check_integer(100), "cem");
+    example!(v, check_integer(125), "cento e vinte e cinco");
+    example!(v, check_integer(300), "trezentos", "trezentas");
+    example!(v, check_integer(5000), "cinco mil");
+    example!(v, check_integer(2500), "dois mil e quinhentos", "2500");
+    example!(v, check_integer(1980), "mil novecentos e oitenta");
+    example!(v, check_integer(200000), "duzentos mil");
+    example!(v, check_integer(21011), "vinte e um mil e onze");
+    example!(v, check_integer(721012), "setecentos e vinte e um mil e doze");
+    example!(v, check_integer(31256721), "trinta e um milhões duzentos e cinquenta e seis mil setecentos e vinte e um");
+    example!(v, check_integer(33), "33", "trinta e três");
+    example!(v, check_integer(100000), "100.000", "100000", "100K", "100k", "cem mil");
+    example!(v, check_integer(3000000), "3M", "3000K", "3000000", "3.000.000", "três milhões");
+    example!(v, check_integer(1200000), "1.200.000", "1200000", "1,2M", "1200K", ",0012G", "um milhão duzentos mil");
+    example!(v, check_integer(-1200000), "- 1.200.000", "-1200000", "menos 1200000", "-1,2M", "-1200K", "-,0012G", "menos um milhão duzentos mil");
+    // "bilhão" follows the short scale in Brazil, "bilião" the long scale in Portugal
+    example!(v, check_integer(2000000000), "dois bilhões", "dois mil milhões");
+    example!(v, check_integer(2000000000000), "dois trilhões", "dois biliões");
+    example!(v, check_float(1.1), "1,1", "1,10", "um vírgula um", "um virgula um", "um ponto um", "um vírgula dez");
+    example!(v, check_float(0.5), "0,5", "0,50", "zero vírgula cinco", "zero ponto cinco", "zero vírgula cinquenta");
+    example!(v, check_float(32.75), "32,75", "trinta e dois vírgula setenta e cinco");
+    example!(v, check_float(10.08), "10,08", "dez vírgula zero oito");
+    example!(v, check_ordinal(1), "1o", "1a", "1º", "1ª", "primeiro", "primeira");
+    example!(v, check_ordinal(3), "3o", "3a", "3º", "3ª", "terceiro", "terceira");
+    example!(v, check_ordinal(2), "segundo", "segunda");
+    example!(v, check_ordinal(5), "quintos");
+    example!(v, check_ordinal(11), "décimo primeiro", "decima primeira");
+    example!(v, check_ordinal(20), "vigésimo", "XX");
+    example!(v, check_fraction(2, 3), "dois terços");
+    example!(v, check_fraction(3, 4), "três quartos");
+    example!(v, check_fraction(1, 5), "um quinto");
+    example!(v, check_ordinal(20), "século XX", "capítulo XX");
+    example!(v, check_integer(2019), "MMXIX");
+    example!(v, check_ordinal(15), "XV");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "aproximadamente 20", "cerca de vinte", "uns vinte", "vinte mais ou menos");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "zero seis um dois", "0612");
+    example!(v, check_digit_sequence("555"), "cinco cinco cinco");
 }
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_percentage(0.3), "0,3%", "cero vírgula tres porcento", "cero virgula tres porcento");
-    example!(v, check_percentage(5.0), "5%", "cinco porcento");
-    example!(v, check_percentage(355.0), "355 %", "355 por cento", "355 porcento");
+    example!(v, check_percentage(0.3), "0,3%", "zero vírgula três por cento", "zero virgula tres porcento");
+    example!(v, check_percentage(5.0), "5%", "cinco por cento", "cinco porcento");
+    example!(v, check_percentage(15.0), "15%", "quinze por cento");
+    example!(v, check_percentage(355.0), "355 %", "355 por cento", "355 porcento", "trezentos e cinquenta e cinco por cento");
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(3.0, Some("degree")), "tres graus", "3 graus", "3°", "3 °");
-    example!(v, check_temperature(32.0, Some("celsius")), "32°C", "32° C", "32° c", "32°c", "32 °c");
-    example!(v, check_temperature(-27.0, Some("degree")), "-27 graus");
+    example!(v, check_temperature(3.0, Some("degree")), "três graus", "3 graus", "3°", "3 °");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "mais ou menos 20 graus");
+    example!(v, check_temperature(32.0, Some("celsius")), "trinta e dois graus celsius", "trinta e dois graus centígrados", "32°C", "32° C", "32° c", "32°c", "32 °c");
+    example!(v, check_temperature(-27.0, Some("degree")), "-27 graus", "menos 27 graus", "27 graus abaixo de zero", "menos vinte e sete graus");
     example!(v, check_temperature(-27.0, Some("celsius")), "-27 graus celsius", "menos 27 graus centigrados", "-27C", "-27°C", "-27° C", "-27°c", "-27° c", "- 27 c");
-    example!(v, check_temperature(-5.0, Some("fahrenheit")), "menos cinco graus fahrenheit", "cinco graus fahrenheit abaixo de cero", "-5 °F", "-5°F", "-5°f", "-5° f", "- 5°f");
-    example!(v, check_temperature(168.0, Some("fahrenheit")), "168 graus fahrenheit", "168° F", "168 F", "168f");
+    example!(v, check_temperature(-5.0, Some("fahrenheit")), "menos cinco graus fahrenheit", "cinco graus fahrenheit abaixo de zero", "-5 °F", "-5°F", "-5°f", "-5° f", "- 5°f");
+    example!(v, check_temperature(168.0, Some("fahrenheit")), "cento e sessenta e oito graus fahrenheit", "168 graus fahrenheit", "168° F", "168 F", "168f");
     example!(v, check_temperature(10.0, Some("kelvin")), "dez kelvin", "10°K", "10° K", "10 °K", "10°k", "dez graus kelvin");
 }
 
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(3.0, DistanceUnit::Kilometer, Precision::Exact), "3 km", "três quilômetros", "3 quilómetros");
+    example!(v, check_distance(10.0, DistanceUnit::Meter, Precision::Exact), "dez metros", "10m", "10 metros");
+    example!(v, check_distance(25.0, DistanceUnit::Centimeter, Precision::Exact), "vinte e cinco centímetros", "25cm");
+    example!(v, check_distance(4.0, DistanceUnit::Millimeter, Precision::Exact), "quatro milímetros", "4 mm");
+    example!(v, check_distance(2.0, DistanceUnit::Mile, Precision::Exact), "duas milhas", "2 milhas");
+    example!(v, check_distance(6.0, DistanceUnit::Foot, Precision::Exact), "seis pés", "6 pés");
+    example!(v, check_distance(12.0, DistanceUnit::Inch, Precision::Exact), "doze polegadas", "12 polegadas");
+    example!(v, check_distance(5.0, DistanceUnit::Kilometer, Precision::Approximate), "aproximadamente 5 km", "cerca de cinco quilômetros", "mais ou menos 5 km");
+    example!(v, check_distance(20.0, DistanceUnit::Meter, Precision::Exact), "exatamente vinte metros", "exactamente 20 m");
+}
+
+pub fn examples_ratio(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_ratio(3, 2), "3:2", "o resultado foi 3:2");
+    example!(v, check_ratio(3, 5), "3 em cada 5", "três de cada cinco");
+    example!(v, check_ratio(4, 1), "4 a 1", "quatro a um", "o placar foi 4 x 1");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800 $", "800$");
-    example!(v, check_finance(45.0, Some("EUR"), Precision::Exact), "45€", "45 €", "45 euros");
-    example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "2,05 €", "2,05€", "dois euros e cinco centavos");
+    example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800 $", "800$", "oitocentos dólares");
+    example!(v, check_finance(10.0, Some("$"), Precision::Approximate), "uns dez dólares", "dez dólares mais ou menos", "cerca de 10$");
+    example!(v, check_finance(10.0, Some("USD"), Precision::Approximate), "uns dez dólares americanos", "10 USD mais ou menos", "quase 10US$");
+    example!(v, check_finance(3.0, Some("AUD"), Precision::Exact), "três dólares australianos");
+    example!(v, check_finance(3.5, Some("AUD"), Precision::Exact), "3,5 dólares australianos", "três dólares australianos e cinquenta centavos");
+    example!(v, check_finance(0.0, Some("HKD"), Precision::Exact), "zero dólares de hong kong");
+    example!(v, check_finance(125.0, Some("CAD"), Precision::Exact), "125 CAD", "cento e vinte e cinco dólares canadenses");
+    example!(v, check_finance(50.0, Some("BRL"), Precision::Exact), "R$ 50", "R$50", "50 reais", "cinquenta reais", "50 BRL");
+    example!(v, check_finance(2.5, Some("BRL"), Precision::Exact), "R$ 2,50", "dois reais e cinquenta centavos", "dois reais e cinquenta");
+    example!(v, check_finance(1000.0, Some("BRL"), Precision::Approximate), "uns mil reais", "cerca de R$ 1.000");
+    example!(v, check_finance(45.0, Some("EUR"), Precision::Exact), "45€", "45 €", "45 euros", "quarenta e cinco euros");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Approximate), "mais ou menos 20 euros");
+    example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "2,05 €", "2,05€", "dois euros e cinco cêntimos", "dois euros e cinco centavos");
     example!(v, check_finance(2.0, Some("£"), Precision::Exact), "2 £", "2£", "duas libras");
     example!(v, check_finance(10.0, Some("GBP"), Precision::Exact), "dez libras esterlinas");
+    example!(v, check_finance(38.0, Some("CHF"), Precision::Exact), "38 francos suíços", "trinta e oito francos suíços");
+    example!(v, check_finance(447.0, Some("kr"), Precision::Exact), "447 coroas", "quatrocentas e quarenta e sete coroas");
+    example!(v, check_finance(10000.0, Some("DKK"), Precision::Exact), "dez mil DKK", "10.000 coroas dinamarquesas");
+    example!(v, check_finance(100.0, Some("NOK"), Precision::Exact), "100 coroas norueguesas", "cem coroas norueguesas");
+    example!(v, check_finance(2005.0, Some("SEK"), Precision::Exact), "2005 coroas suecas", "dois mil e cinco coroas suecas");
+    example!(v, check_finance(96.0, Some("INR"), Precision::Approximate), "aproximadamente 96 rúpias", "umas noventa e seis rúpias");
+    example!(v, check_finance(89.0, Some("JPY"), Precision::Exact), "exatamente 89 JPY", "89 ienes exatos", "exactamente oitenta e nove ienes japoneses");
     example!(v, check_finance(8.0, Some("¥"), Precision::Exact), "8¥");
-    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿", "tres bitcoins");
+    example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "cem yuans exatos", "exatamente 100 yuans chineses");
+    example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "7 wons", "7₩", "sete wons sul-coreanos");
+    example!(v, check_finance(3.0, Some("XBT"), Precision::Exact), "três bitcoins");
+    example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿");
+    example!(v, check_finance(3.0, Some("THB"), Precision::Exact), "três bahts", "3 THB");
+    example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "três milhões de euros");
 }
 
-pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
-    example!(v, check_moment!(c, [2013, 2, 12]), "hoje");
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "entre 20 e 30", "de vinte a trinta");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3 - 4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "menos de 10", "menos de dez");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "pelo menos 5", "pelo menos cinco");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("EUR")), "entre 20 e 30 euros", "entre 20 euros e 30 euros", "de 20 a 30 euros", "20 - 30 euros");
+    example!(v, check_finance_interval(Some(IntervalBound::exclusive(100.0)), None, Some("$")), "mais de 100 $", "mais de 100$");
+    example!(v, check_finance_interval(None, Some(IntervalBound::inclusive(50.0)), Some("EUR")), "no máximo 50 euros", "até 50 euros", "até cinquenta euros");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "entre 20 e 25 graus", "de 20° a 25°");
+    example!(v, check_temperature_interval(None, Some(IntervalBound::exclusive(5.0)), Some("celsius")), "abaixo de 5 graus celsius", "menos de 5°C");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "entre 2 e 3 horas", "de 2 a 3 horas", "de duas a três horas");
+    example!(v, check_duration_interval(None, Some(IntervalBound::exclusive(period!(0, 0, 0, 0, 0, 30)))), "menos de 30 minutos", "menos de trinta minutos");
 }
 
-pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_duration!([0, 0, 0, 0, 1]), "por uma hora");
+/// Wordings of European Portuguese, read by the rules of the European variant as well as by the default ones
+pub fn examples_european(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(16), "dezasseis");
+    example!(v, check_integer(17), "dezassete");
+    example!(v, check_integer(19), "dezanove");
+    example!(v, check_integer(2000000000), "dois mil milhões");
+    example!(v, check_integer(2000000000000), "dois biliões");
+    example!(v, check_moment!(c, [2013, 2, 17]), "dia dezassete");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 6], [2013, 2, 12, 10]), "ao pequeno-almoço", "ao pequeno almoço", "no pequeno-almoço");
+    example!(v, check_moment_span!(c, [2013, 2, 15, 18], [2013, 2, 18, 00]), "este fim-de-semana", "neste fim-de-semana");
+    example!(v, check_finance(20.0, Some("cent"), Precision::Exact), "vinte cêntimos");
+    example!(v, check_finance(10.2, Some("EUR"), Precision::Exact), "dez euros e vinte cêntimos", "10 euros e 20 cêntimos");
+    example!(v, check_finance(10.0, Some("EUR"), Precision::Exact), "exactamente dez euros", "dez euros exactos");
+    example!(v, check_distance(20.0, DistanceUnit::Meter, Precision::Exact), "exactamente 20 m");
 }
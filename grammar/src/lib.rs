@@ -12,6 +12,10 @@ extern crate rustling_ontology_it as it;
 
 use std::result;
 
+use rustling_ontology_values::helpers::SeparatorPolicy;
+
+pub use pt::Variant as PortugueseVariant;
+
 macro_rules! lang_enum {
     ([$($lang:ident),*]) => {
         /// Enumerates all language supported for the general purpose ontology.
@@ -51,7 +55,8 @@ macro_rules! lang_enum {
 
 lang_enum!([DE, EN, ES, FR, PT, JA, KO, ZH, IT]);
 
-/// Obtain rules for a given language.
+/// Obtain the plain text rules for a given language. Use `rules_with_options` to add the
+/// spoken forms of speech recognition output.
 pub fn rules(lang: Lang) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     match lang {
         Lang::DE => de::rule_set(),
@@ -66,6 +71,53 @@ pub fn rules(lang: Lang) -> ::rustling::RustlingResult<::rustling::RuleSet<rustl
     }
 }
 
+/// Obtain rules for a given language, reading digits with the given separator policies.
+pub fn rules_with_separators(lang: Lang, separators: &[SeparatorPolicy]) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    match lang {
+        Lang::DE => de::rule_set_with_separators(separators),
+        Lang::EN => en::rule_set_with_separators(separators),
+        Lang::ES => es::rule_set_with_separators(separators),
+        Lang::FR => fr::rule_set_with_separators(separators),
+        Lang::PT => pt::rule_set_with_separators(separators),
+        Lang::JA => ja::rule_set_with_separators(separators),
+        Lang::KO => ko::rule_set_with_separators(separators),
+        Lang::ZH => zh::rule_set_with_separators(separators),
+        Lang::IT => it::rule_set_with_separators(separators),
+    }
+}
+
+/// Obtain rules for a given language, reading digits with the given separator policies and,
+/// when `spoken_forms` is set, the spoken forms of speech recognition output. Only English and
+/// French have spoken form rules.
+pub fn rules_with_options(lang: Lang, separators: &[SeparatorPolicy], spoken_forms: bool) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    match lang {
+        Lang::EN => en::rule_set_with_options(separators, spoken_forms),
+        Lang::FR => fr::rule_set_with_options(separators, spoken_forms),
+        _ => rules_with_separators(lang, separators),
+    }
+}
+
+/// Obtain Portuguese rules restricted to the Brazilian or European wordings, reading digits
+/// with the given separator policies.
+pub fn rules_pt_variant(separators: &[SeparatorPolicy], variant: PortugueseVariant) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    pt::rule_set_with_variant(separators, Some(variant))
+}
+
+/// Obtain the separator policies read by default for a given language.
+pub fn separators(lang: Lang) -> Vec<SeparatorPolicy> {
+    match lang {
+        Lang::DE => de::separators(),
+        Lang::EN => en::separators(),
+        Lang::ES => es::separators(),
+        Lang::FR => fr::separators(),
+        Lang::PT => pt::separators(),
+        Lang::JA => ja::separators(),
+        Lang::KO => ko::separators(),
+        Lang::ZH => zh::separators(),
+        Lang::IT => it::separators(),
+    }
+}
+
 /// Obtain dimensions for a given language.
 pub fn dims(lang: Lang) -> Vec<rustling_ontology_values::DimensionKind> {
     match lang {
@@ -1,7 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Season};
 
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -43,7 +43,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()).precision(integer.value().precision))
     );
 
     Ok(())
@@ -671,9 +671,202 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _| Ok(a.value().clone())
     );
 
+    b.rule_1_terminal("season",
+                      b.reg(r#"夏(?:天|季)"#)?,
+                      |_| helpers::season(Season::Summer)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"秋(?:天|季)"#)?,
+                      |_| helpers::season(Season::Autumn)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"冬(?:天|季)"#)?,
+                      |_| helpers::season(Season::Winter)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"春(?:天|季)"#)?,
+                      |_| helpers::season(Season::Spring)
+    );
+    Ok(())
+}
+
+
+pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> per cent",
+        number_check!(),
+        b.reg(r#"%|％"#)?,
+        |number, _| Ok(PercentageValue(number.value().value()))
+    );
+    b.rule_2("百分之 <number>",
+        b.reg(r#"百分之"#)?,
+        number_check!(),
+        |_, number| Ok(PercentageValue(number.value().value()))
+    );
+    b.rule_2("<integer> 成 (tens of per cent)",
+        integer_check_by_range!(1, 10),
+        b.reg(r#"成"#)?,
+        |integer, _| Ok(PercentageValue(integer.value().value as f64 * 10.0))
+    );
+    b.rule_3("<integer> 成 <integer> (tens and units of per cent)",
+        integer_check_by_range!(1, 9),
+        b.reg(r#"成"#)?,
+        integer_check_by_range!(1, 9),
+        |tens, _, units| Ok(PercentageValue((tens.value().value * 10 + units.value().value) as f64))
+    );
     Ok(())
 }
 
+pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect <money> (X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some(MoneyUnit::Cent)),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent)),
+             |a, b| helpers::compose_money(a.value(), b.value()));
+    b.rule_2("intersect <money> (X 毛 Y 分)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent) && money.value % 10.0 == 0.0),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Cent) && money.value < 10.0),
+             |a, b| Ok(AmountOfMoneyValue {
+                 value: a.value().value + b.value().value,
+                 ..a.value().clone()
+             }));
+    b.rule_2("<amount> 块 <digit> (X yuan Y jiao)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some(MoneyUnit::Currency(Currency::CNY)) && money.value.fract() == 0.0),
+             integer_check_by_range!(1, 9),
+             |a, b| Ok(AmountOfMoneyValue {
+                 value: a.value().value + b.value().value as f64 / 10.0,
+                 ..a.value().clone()
+             }));
+    b.rule_1_terminal("CNY",
+        b.reg(r#"块钱?|塊錢?|元|圆|圓|人民币|人民幣|rmb|cny"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CNY)) })
+    );
+    b.rule_1_terminal("$",
+        b.reg(r#"\$|＄|美刀"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Dollar)) })
+    );
+    b.rule_1_terminal("USD",
+        b.reg(r#"美元|美金|usd"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::USD)) })
+    );
+    b.rule_1_terminal("EUR",
+        b.reg(r#"欧元|歐元|€|eur"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::EUR)) })
+    );
+    b.rule_1_terminal("£",
+        b.reg(r#"£"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Pound)) })
+    );
+    b.rule_1_terminal("GBP",
+        b.reg(r#"英镑|英鎊|gbp"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::GBP)) })
+    );
+    b.rule_1_terminal("JPY",
+        b.reg(r#"日元|日圆|日圓|日币|日幣|jpy"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::JPY)) })
+    );
+    b.rule_1_terminal("¥",
+        b.reg(r#"¥|￥"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Symbol(CurrencySymbol::Yen)) })
+    );
+    b.rule_1_terminal("HKD",
+        b.reg(r#"港元|港币|港幣|hkd"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::HKD)) })
+    );
+    b.rule_1_terminal("KRW",
+        b.reg(r#"韩元|韓元|韩币|韓幣|₩|krw"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::KRW)) })
+    );
+    b.rule_1_terminal("AUD",
+        b.reg(r#"澳元|澳币|澳幣|aud"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::AUD)) })
+    );
+    b.rule_1_terminal("CAD",
+        b.reg(r#"加元|加币|加幣|cad"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CAD)) })
+    );
+    b.rule_1_terminal("CHF",
+        b.reg(r#"瑞士法郎|chf"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::CHF)) })
+    );
+    b.rule_1_terminal("RUB",
+        b.reg(r#"卢布|盧布|rub"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::RUB)) })
+    );
+    b.rule_1_terminal("INR",
+        b.reg(r#"印度卢比|印度盧比|卢比|盧比|inr"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::INR)) })
+    );
+    b.rule_1_terminal("SGD",
+        b.reg(r#"新加坡元|新币|新幣|sgd"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::SGD)) })
+    );
+    b.rule_1_terminal("Bitcoin",
+        b.reg(r#"比特币|比特幣|฿"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Currency(Currency::XBT)) })
+    );
+    b.rule_2("<integer> 毛|角 (tenth of yuan)",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"毛钱?|毛錢?|角"#)?,
+             |integer, _| Ok(AmountOfMoneyValue {
+                 value: integer.value().value as f64 * 10.0,
+                 precision: integer.value().precision,
+                 unit: Some(MoneyUnit::Cent),
+                 ..AmountOfMoneyValue::default()
+             }));
+    b.rule_1_terminal("cent",
+        b.reg(r#"分钱|分錢|美分|分|cents?"#)?,
+        |_| Ok(MoneyUnitValue { unit: Some(MoneyUnit::Cent) })
+    );
+    b.rule_2("<amount> <unit>",
+             number_check!(),
+             money_unit!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: a.value().value(),
+                     precision: a.value().precision(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_2("<unit> <amount>",
+             money_unit!(),
+             number_check!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: b.value().value(),
+                     precision: b.value().precision(),
+                     unit: a.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_2("about <amount-of-money>",
+             b.reg(r#"大约|大約|大概|约|約"#)?,
+             amount_of_money_check!(),
+             |_, a| {
+                 Ok(AmountOfMoneyValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("about <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"左右|上下"#)?,
+             |a, _| {
+                 Ok(AmountOfMoneyValue {
+                     precision: Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <amount-of-money>",
+             b.reg(r#"刚好|剛好|正好|整整"#)?,
+             amount_of_money_check!(),
+             |_, a| {
+                 Ok(AmountOfMoneyValue {
+                     precision: Exact,
+                     ..a.value().clone()
+                 })
+             });
+    Ok(())
+}
 
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("number as temp",
@@ -681,6 +874,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
+                     precision: a.value().precision(),
                      unit: None,
                      latent: true,
                  })
@@ -692,6 +886,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("degree"),
                      latent: false,
                  })
@@ -703,6 +898,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -715,6 +911,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |_, b, _| {
                  Ok(TemperatureValue {
                      value: b.value().value,
+                     precision: b.value().precision,
                      unit: Some("celsius"),
                      latent: false,
                  })
@@ -727,6 +924,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
+                     precision: a.value().precision,
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -739,6 +937,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |_, b, _| {
                  Ok(TemperatureValue {
                      value: b.value().value,
+                     precision: b.value().precision,
                      unit: Some("fahrenheit"),
                      latent: false,
                  })
@@ -748,6 +947,31 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+pub fn rules_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    helpers::rules_intervals(b, &helpers::IntervalWords {
+        between: None,
+        from_to: None,
+        range: r#"到|至|~|-"#,
+        open: vec![
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::After(r#"以上"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::After(r#"以下"#)),
+            (OpenInterval::AtLeast, helpers::OpenIntervalMarker::Before(r#"至少"#)),
+            (OpenInterval::AtMost, helpers::OpenIntervalMarker::Before(r#"最多|至多"#)),
+            (OpenInterval::Under, helpers::OpenIntervalMarker::Before(r#"不到|少于"#)),
+            (OpenInterval::Over, helpers::OpenIntervalMarker::Before(r#"超过|多于"#)),
+        ],
+    })
+}
+
+
+fn zh_counter_class(counter: &str) -> Option<CounterClass> {
+    match counter {
+        "个" | "個" => Some(CounterClass::Object),
+        "位" | "名" => Some(CounterClass::Person),
+        "只" | "隻" => Some(CounterClass::Animal),
+        _ => None,
+    }
+}
 
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("integer (0..10)",
@@ -770,7 +994,8 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                               "十" => 10,
                               _ => return Err(RuleError::Invalid.into())
                           };
-                          IntegerValue::new_with_grain(value, 1)
+                          let counter = &text_match.group(0)[text_match.group(1).len()..];
+                          IntegerValue::new_with_grain(value, 1)?.with_counter(zh_counter_class(counter))
                       });
 
     b.rule_1_terminal(
@@ -778,13 +1003,6 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         b.reg(r#"(\d{1,18})"#)?,
         |text_match| IntegerValue::new(text_match.group(0).parse()?));
 
-    b.rule_1("decimal number", b.reg(r#"(\d*\.\d+)"#)?, |text_match| {
-        let value: f32 = text_match.group(0).parse()?;
-        Ok(FloatValue {
-            value: value,
-            ..FloatValue::default()
-        })
-    });
 
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|负\s?|負\s?"#)?,
@@ -811,20 +1029,15 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  })
              });
 
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: f32 = reformatted_string.parse()?;
-                          Ok(FloatValue {
-                              value: value,
-                              ..FloatValue::default()
-                          })
-                      });
+
+    b.rule_2("<integer> <counter>",
+             integer_check!(|integer: &IntegerValue| integer.counter.is_none()),
+             b.reg(r#"个|個|位|名|只|隻"#)?,
+             |integer, counter| integer.value().clone().with_counter(zh_counter_class(counter.group(0))));
 
     b.rule_2("<number>个",
-             number_check!(),
-             b.reg(r#"个"#)?,
+             number_check!(|number: &NumberValue| match *number { NumberValue::Float(_) => true, _ => false }),
+             b.reg(r#"个|個"#)?,
              |number, _| Ok(number.value().clone()));
 
     b.rule_2("integer (20..90)",
@@ -858,7 +1071,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                              .into()
                      }
                      NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f32);
+                         let product = float.value * (multiplier as f64);
                          if product.floor() == product {
                              IntegerValue {
                                  value: product as i64,
@@ -888,17 +1101,77 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(1, 9),
              |_, b| IntegerValue::new(10 + b.value().value));
 
-    b.rule_1("integer with thousands separator, ",
-             b.reg(r#"(\d{1,3}(,\d\d\d){1,5})"#)?,
-             |text_match| {
-                 let reformatted_string = text_match.group(1).replace(",", "");
-                 let value: i64 = reformatted_string.parse()?;
-                 Ok(IntegerValue {
-                     value: value,
-                     ..IntegerValue::default()
-                 })
+    b.rule_1_terminal("100, 1_000, 10_000, 100_000_000",
+        b.reg(r#"(百|千|万|萬|亿|億)"#)?,
+        |text_match| {
+            let (value, grain) = match text_match.group(1).as_ref() {
+                "百" => (100, 2),
+                "千" => (1_000, 3),
+                "万" | "萬" => (10_000, 4),
+                "亿" | "億" => (100_000_000, 8),
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            IntegerValue::new_with_grain(value, grain)
+        });
+
+    b.rule_2("200..900, 2_000..9_000",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"(百|千)"#)?,
+             |a, text_match| {
+                 let (value, grain) = match text_match.group(1).as_ref() {
+                     "百" => (100, 2),
+                     "千" => (1_000, 3),
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 IntegerValue::new_with_grain(a.value().value * value, grain)
+             });
+
+    b.rule_2("1_0000..9999_0000, 1_0000_0000..9999_0000_0000",
+             integer_check_by_range!(1, 9999),
+             b.reg(r#"(万|萬|亿|億)"#)?,
+             |a, text_match| {
+                 let (value, grain) = match text_match.group(1).as_ref() {
+                     "万" | "萬" => (10_000, 4),
+                     "亿" | "億" => (100_000_000, 8),
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 IntegerValue::new_with_grain(a.value().value * value, grain)
              });
 
+    b.rule_2("intersect",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             number_check!(),
+             |a, b| helpers::compose_numbers_from_left(&a.value(), &b.value()));
+
+    b.rule_3("intersect (with 零)",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             b.reg(r#"零|〇"#)?,
+             integer_check_by_range!(1, 99),
+             |a, _, b| helpers::compose_numbers_from_left(&a.value(), &NumberValue::Integer(b.value().clone())));
+
+    b.rule_3("number dot number (chinese digits)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             b.reg(r#"点|點"#)?,
+             b.reg(r#"[零〇一二三四五六七八九]+"#)?,
+             |a, _, decimals| {
+                 let decimals = decimals.group(0).chars()
+                     .map(|c| match c {
+                         '零' | '〇' => '0',
+                         '一' => '1',
+                         '二' => '2',
+                         '三' => '3',
+                         '四' => '4',
+                         '五' => '5',
+                         '六' => '6',
+                         '七' => '7',
+                         '八' => '8',
+                         _ => '9',
+                     })
+                     .collect::<String>();
+                 FloatValue::new(format!("{}.{}", a.value().value, decimals).parse()?)
+             });
+
+
     b.rule_2("ordinal (digits)",
              b.reg(r#"第"#)?,
              integer_check!(),
@@ -907,5 +1180,44 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              }
     );
 
+    b.rule_3("<integer> 分之 <integer> (fraction)",
+        integer_check_by_range!(1),
+        b.reg(r#"分之"#)?,
+        integer_check_by_range!(0),
+        |denominator, _, numerator| FloatValue::fraction(numerator.value().value, denominator.value().value)
+    );
+    b.rule_2("大约 <number>",
+             b.reg(r#"(?:大约|大概|约)"#)?,
+             number_check!(),
+             |_, number| number.value().clone().with_precision(Precision::Approximate)
+    );
+    b.rule_2("<number> 左右",
+             number_check!(),
+             b.reg(r#"(?:左右|上下)"#)?,
+             |number, _| number.value().clone().with_precision(Precision::Approximate)
+    );
+    Ok(())
+}
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("spoken digit",
+        b.reg(r#"(零|〇|一|幺|二|两|三|四|五|六|七|八|九)"#)?,
+        |text_match| {
+            let digit = match text_match.group(1) {
+                "零" | "〇" => "0",
+                "一" | "幺" => "1",
+                "二" | "两" => "2",
+                "三" => "3",
+                "四" => "4",
+                "五" => "5",
+                "六" => "6",
+                "七" => "7",
+                "八" => "8",
+                "九" => "9",
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            Ok(DigitSequenceValue::new(digit))
+        }
+    );
     Ok(())
 }
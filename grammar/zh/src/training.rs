@@ -7,6 +7,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 0, 1]), "1 秒钟", "一 秒鐘", "一 秒");
     example!(v, check_duration!([0, 0, 0, 0, 0, 1]), "1 分鐘", "一 分鐘");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "1 小時", "一 小時");
+    example!(v, check_duration!([0, 0, 0, 0, 3], Precision::Approximate), "大约3小时", "大概三小时");
     example!(v, check_duration!([0, 0, 0, 5]), "5 天", "五 天", "五 日");
     example!(v, check_duration!([0, 10]), "10 月", "十 月");
 }
@@ -33,6 +34,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 14, 15]), "1415");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 45]), "545");
     example!(v, check_moment_span!(c, [2013, 2, 15, 18], [2013, 2, 18, 00]), "周末", "週末", "这周末", "這週末");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 23]), "夏天", "夏季");
+    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 20]), "冬天", "冬季");
     example!(v, check_moment!(c, [2012]), "去年", "上年");
     example!(v, check_moment!(c, [2013]) ,"今年");
     example!(v, check_moment!(c, [2014]), "明年", "下年");
@@ -103,6 +106,7 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(45.0, Some("degree")), "45°", "45度");
     example!(v, check_temperature(45.0, Some("degree")), "45°", "45度");
+    example!(v, check_temperature_with_precision(20.0, Some("degree"), Precision::Approximate), "大约20度", "大概二十度");
     example!(v,
              check_temperature(50.0, Some("fahrenheit")),
              "50°F",
@@ -150,6 +154,33 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
 }
 
 
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance(50.0, Some("CNY"), Precision::Exact), "50块钱", "五十块", "50元", "五十塊錢", "人民币50元");
+    example!(v, check_finance(300.0, Some("CNY"), Precision::Exact), "三百元", "300块", "300人民币", "rmb300");
+    example!(v, check_finance(3.5, Some("CNY"), Precision::Exact), "三块五", "三块五毛", "3块5毛钱", "三元五角");
+    example!(v, check_finance(2.55, Some("CNY"), Precision::Exact), "两块五毛五分", "2元5角5分");
+    example!(v, check_finance(50.0, Some("cent"), Precision::Exact), "五毛", "五角", "50分");
+    example!(v, check_finance(20.0, Some("$"), Precision::Exact), "20$", "$20", "二十美刀");
+    example!(v, check_finance(100.0, Some("USD"), Precision::Exact), "100美元", "一百美金");
+    example!(v, check_finance(30.0, Some("EUR"), Precision::Exact), "30欧元", "三十歐元", "30€");
+    example!(v, check_finance(8.0, Some("GBP"), Precision::Exact), "八英镑");
+    example!(v, check_finance(1000.0, Some("JPY"), Precision::Exact), "一千日元", "1000日币");
+    example!(v, check_finance(10.0, Some("¥"), Precision::Exact), "10¥", "¥10");
+    example!(v, check_finance(200.0, Some("HKD"), Precision::Exact), "两百港币", "200港元");
+    example!(v, check_finance(5000.0, Some("KRW"), Precision::Exact), "五千韩元");
+    example!(v, check_finance(2.0, Some("XBT"), Precision::Exact), "两个比特币", "2比特币");
+    example!(v, check_finance(100.0, Some("CNY"), Precision::Approximate), "大约100块", "100块左右", "大概一百元");
+    example!(v, check_finance(20.0, Some("USD"), Precision::Exact), "正好20美元", "刚好二十美元");
+}
+
+pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_percentage(20.0), "百分之二十", "20%", "20％", "百分之20", "两成");
+    example!(v, check_percentage(50.0), "五成", "百分之五十", "50%");
+    example!(v, check_percentage(75.0), "七成五", "百分之七十五");
+    example!(v, check_percentage(2.5), "百分之二点五", "2.5%");
+    example!(v, check_percentage(100.0), "百分之百", "十成");
+}
+
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(0), "0", "〇", "零", "零个", "0个");
     example!(v, check_integer(1), "1", "一", "一个", "1个");
@@ -175,6 +206,17 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(96), "96", "九十六");
     example!(v, check_integer(14), "14", "十四");
     example!(v, check_integer(11), "11", "十一");
+    example!(v, check_integer(100), "100", "百", "一百");
+    example!(v, check_integer(300), "300", "三百");
+    example!(v, check_integer(250), "250", "两百五十", "二百五十");
+    example!(v, check_integer(305), "305", "三百零五");
+    example!(v, check_integer(2000), "2000", "两千", "二千");
+    example!(v, check_integer(1234), "1234", "一千二百三十四");
+    example!(v, check_integer(30000), "30000", "三万", "三萬");
+    example!(v, check_integer(35000), "35000", "三万五千");
+    example!(v, check_integer(200000000), "两亿", "二億");
+    example!(v, check_float(2.5), "2.5", "二点五", "二點五");
+    example!(v, check_float(1.5), "1.5个", "一点五个");
     example!(v,
              check_integer(-1200000),
              "- 1,200,000",
@@ -190,4 +232,28 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(7), "第七");
     example!(v, check_ordinal(11), "第十一");
     example!(v, check_ordinal(91), "第九十一");
+    example!(v, check_fraction(2, 3), "三分之二");
+    example!(v, check_fraction(1, 4), "四分之一");
+    example!(v, check_integer_with_precision(20, Precision::Approximate), "大约20", "20左右");
+    example!(v, check_integer_with_counter(3, CounterClass::Person), "三位", "3位");
+    example!(v, check_integer_with_counter(2, CounterClass::Animal), "两只", "兩隻", "2只");
+    example!(v, check_integer_with_counter(5, CounterClass::Object), "五个", "五個", "5个");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("0612"), "零六幺二", "〇六一二", "0612");
+}
+
+pub fn examples_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0))), "20到30", "20至30");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(3.0)), Some(IntervalBound::inclusive(4.0))), "3-4", "3~4");
+    example!(v, check_number_interval(None, Some(IntervalBound::exclusive(10.0))), "不到10", "少于10");
+    example!(v, check_number_interval(Some(IntervalBound::inclusive(5.0)), None), "至少5", "5以上");
+    example!(v, check_temperature_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(25.0)), Some("degree")), "20度到25度", "20到25度");
+    example!(v, check_temperature_interval(Some(IntervalBound::exclusive(30.0)), None, Some("degree")), "超过30度");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(20.0)), Some(IntervalBound::inclusive(30.0)), Some("CNY")), "20到30块", "20块到30块", "二十元至三十元");
+    example!(v, check_finance_interval(Some(IntervalBound::inclusive(100.0)), None, Some("USD")), "100美元以上", "至少100美元");
+    example!(v, check_finance_interval(None, Some(IntervalBound::exclusive(50.0)), Some("CNY")), "不到50块", "少于五十元");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "2到3 小時", "2 小時 到 3 小時");
+    example!(v, check_duration_interval(Some(IntervalBound::inclusive(period!(0, 0, 0, 0, 2))), None), "至少 2 小時");
 }
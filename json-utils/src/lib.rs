@@ -6,8 +6,7 @@ extern crate serde_json;
 extern crate serde_derive;
 
 use rustling_ontology::{Output, dimension, output::TimeIntervalOutput};
-use moment::{Moment, Local};
-use ::std::f64;
+use moment::{Moment, Local, Period};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -98,13 +97,22 @@ pub enum SlotValue {
     AmountOfMoney(AmountOfMoneyValue),
     Temperature(TemperatureValue),
     Duration(DurationValue),
+    Distance(DistanceValue),
+    Quantity(QuantityValue),
+    Rate(RateValue),
+    Ratio(RatioValue),
+    DigitSequence(DigitSequenceValue),
+    NumberInterval(NumberIntervalValue),
+    AmountOfMoneyInterval(AmountOfMoneyIntervalValue),
+    TemperatureInterval(TemperatureIntervalValue),
+    DurationInterval(DurationIntervalValue),
 }
 
 impl From<Output> for SlotValue {
     fn from(o: Output) -> SlotValue {
         match o {
-            Output::Integer(int) => SlotValue::Number(NumberValue { value: (int.0 as f64).into() }),
-            Output::Float(float) => SlotValue::Number(NumberValue { value: float.0.into() }),
+            Output::Integer(int) => SlotValue::Number(NumberValue { value: (int.value as f64).into(), precision: int.precision.into() }),
+            Output::Float(float) => SlotValue::Number(NumberValue { value: float.value.into(), precision: float.precision.into() }),
             Output::Ordinal(ordinal) => SlotValue::Ordinal(OrdinalValue { value: ordinal.0 as i64 }),
             Output::Percentage(percentage) => SlotValue::Percentage(PercentageValue { value: percentage.0.into() }),
             Output::Time(time) => SlotValue::InstantTime( InstantTimeValue {
@@ -127,51 +135,97 @@ impl From<Output> for SlotValue {
             Output::AmountOfMoney(amount) => SlotValue::AmountOfMoney( AmountOfMoneyValue {
                 value: amount.value,
                 precision: amount.precision.into(),
-                unit: amount.unit.map(|it| it.to_string()),
+                unit: money_unit(amount.unit, amount.currency),
             }),
             Output::Temperature(temperature) => SlotValue::Temperature( TemperatureValue {
                 value: temperature.value,
+                precision: temperature.precision.into(),
                 unit: temperature.unit.map(|it| it.to_string()),
             }),
-            Output::Duration(duration) => SlotValue::Duration( DurationValue {
-                years: *duration.period.0.get(Grain::Year as usize).unwrap_or(&0),
-                quarters: *duration.period.0.get(Grain::Quarter as usize).unwrap_or(&0),
-                months: *duration.period.0.get(Grain::Month as usize).unwrap_or(&0),
-                weeks: *duration.period.0.get(Grain::Week as usize).unwrap_or(&0),
-                days: *duration.period.0.get(Grain::Day as usize).unwrap_or(&0),
-                hours: *duration.period.0.get(Grain::Hour as usize).unwrap_or(&0),
-                minutes: *duration.period.0.get(Grain::Minute as usize).unwrap_or(&0),
-                seconds: *duration.period.0.get(Grain::Second as usize).unwrap_or(&0),
-                precision: duration.precision.into(),
+            Output::Distance(distance) => SlotValue::Distance( DistanceValue {
+                value: distance.value,
+                precision: distance.precision.into(),
+                unit: distance.unit.name().to_string(),
             }),
+            Output::Quantity(quantity) => SlotValue::Quantity( QuantityValue {
+                value: quantity.value,
+                unit: quantity.unit.name().to_string(),
+                product: quantity.product.map(|range| ByteRange { start: range.0, end: range.1 }),
+            }),
+            Output::Rate(rate) => SlotValue::Rate( RateValue {
+                quantity: Box::new(Output::from(rate.quantity).into()),
+                per: rate.per.into(),
+            }),
+            Output::Ratio(ratio) => SlotValue::Ratio( RatioValue {
+                numerator: ratio.numerator,
+                denominator: ratio.denominator,
+            }),
+            Output::DigitSequence(sequence) => SlotValue::DigitSequence( DigitSequenceValue {
+                value: sequence.digits,
+            }),
+            Output::Duration(duration) => SlotValue::Duration(duration_value(&duration.period, duration.precision)),
+            Output::NumberInterval(interval) => SlotValue::NumberInterval( NumberIntervalValue {
+                from: interval.from.map(|b| IntervalBoundValue { value: b.value.into(), inclusive: b.inclusive }),
+                to: interval.to.map(|b| IntervalBoundValue { value: b.value.into(), inclusive: b.inclusive }),
+            }),
+            Output::AmountOfMoneyInterval(interval) => SlotValue::AmountOfMoneyInterval( AmountOfMoneyIntervalValue {
+                from: interval.from.map(|b| IntervalBoundValue { value: b.value, inclusive: b.inclusive }),
+                to: interval.to.map(|b| IntervalBoundValue { value: b.value, inclusive: b.inclusive }),
+                precision: interval.precision.into(),
+                unit: money_unit(interval.unit, interval.currency),
+            }),
+            Output::TemperatureInterval(interval) => SlotValue::TemperatureInterval( TemperatureIntervalValue {
+                from: interval.from.map(|b| IntervalBoundValue { value: b.value, inclusive: b.inclusive }),
+                to: interval.to.map(|b| IntervalBoundValue { value: b.value, inclusive: b.inclusive }),
+                unit: interval.unit.map(|it| it.to_string()),
+            }),
+            Output::DurationInterval(interval) => {
+                let precision = interval.precision;
+                SlotValue::DurationInterval( DurationIntervalValue {
+                    from: interval.from.map(|b| IntervalBoundValue { value: duration_value(&b.value, precision), inclusive: b.inclusive }),
+                    to: interval.to.map(|b| IntervalBoundValue { value: duration_value(&b.value, precision), inclusive: b.inclusive }),
+                })
+            }
         }
     }
 }
 
-fn nearly_equal_f64(a: f64, b: f64) -> bool {
-    let abs_a = a.abs();
-    let abs_b = b.abs();
-    let diff = (a - b).abs();
-
-    if a == b { // Handle infinities.
-        true
-    } else if a == 0.0 || b == 0.0 || diff < f64::MIN_POSITIVE {
-        // One of a or b is zero (or both are extremely close to it,) use absolute error.
-        diff < (f64::EPSILON * f64::MIN_POSITIVE)
-    } else { // Use relative error.
-        (diff / f64::min(abs_a + abs_b, f64::MAX)) < 0.00001
+/// The ISO code of the resolved currency, or the unit as read when its currency is ambiguous
+fn money_unit(unit: Option<dimension::MoneyUnit>, currency: Option<dimension::Currency>) -> Option<String> {
+    currency.map(|c| c.code()).or(unit.map(|u| u.name())).map(|it| it.to_string())
+}
+
+fn duration_value(period: &Period, precision: dimension::Precision) -> DurationValue {
+    DurationValue {
+        years: *period.0.get(Grain::Year as usize).unwrap_or(&0),
+        quarters: *period.0.get(Grain::Quarter as usize).unwrap_or(&0),
+        months: *period.0.get(Grain::Month as usize).unwrap_or(&0),
+        weeks: *period.0.get(Grain::Week as usize).unwrap_or(&0),
+        days: *period.0.get(Grain::Day as usize).unwrap_or(&0),
+        hours: *period.0.get(Grain::Hour as usize).unwrap_or(&0),
+        minutes: *period.0.get(Grain::Minute as usize).unwrap_or(&0),
+        seconds: *period.0.get(Grain::Second as usize).unwrap_or(&0),
+        precision: precision.into(),
     }
 }
 
+/// Values computed by the rules (such as "1.5" read as 1 + 5/10) can be off by a few ulps
+/// from the parsed expectation, but amounts differing by a cent must not compare equal
+fn nearly_equal_f64(a: f64, b: f64) -> bool {
+    a == b || (a - b).abs() < 1e-9
+}
+
 
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
 pub struct NumberValue {
     pub value: f64,
+    #[serde(default)]
+    pub precision: Precision,
 }
 
 impl PartialEq for NumberValue {
     fn eq(&self, other: &NumberValue) -> bool {
-        nearly_equal_f64(self.value, other.value)
+        nearly_equal_f64(self.value, other.value) && self.precision == other.precision
     }
 }
 
@@ -210,17 +264,55 @@ pub struct TimeIntervalValue {
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct AmountOfMoneyValue {
-    pub value: f32,
+    pub value: f64,
     pub precision: Precision,
     pub unit: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct TemperatureValue {
-    pub value: f32,
+    pub value: f64,
+    pub precision: Precision,
     pub unit: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DistanceValue {
+    pub value: f64,
+    pub precision: Precision,
+    pub unit: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct QuantityValue {
+    pub value: f64,
+    pub unit: String,
+    pub product: Option<ByteRange>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct ByteRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RateValue {
+    pub quantity: Box<SlotValue>,
+    pub per: Grain,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Copy, Debug)]
+pub struct RatioValue {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DigitSequenceValue {
+    pub value: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct DurationValue {
     pub years: i64,
@@ -234,6 +326,39 @@ pub struct DurationValue {
     pub precision: Precision,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct IntervalBoundValue<T> {
+    pub value: T,
+    pub inclusive: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct NumberIntervalValue {
+    pub from: Option<IntervalBoundValue<f64>>,
+    pub to: Option<IntervalBoundValue<f64>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AmountOfMoneyIntervalValue {
+    pub from: Option<IntervalBoundValue<f64>>,
+    pub to: Option<IntervalBoundValue<f64>>,
+    pub precision: Precision,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct TemperatureIntervalValue {
+    pub from: Option<IntervalBoundValue<f64>>,
+    pub to: Option<IntervalBoundValue<f64>>,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DurationIntervalValue {
+    pub from: Option<IntervalBoundValue<DurationValue>>,
+    pub to: Option<IntervalBoundValue<DurationValue>>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Hash)]
 pub enum Grain {
     Year = 0,
@@ -267,6 +392,12 @@ pub enum Precision {
     Exact,
 }
 
+impl Default for Precision {
+    fn default() -> Precision {
+        Precision::Exact
+    }
+}
+
 impl From<dimension::Precision> for Precision {
     fn from(o: dimension::Precision) -> Precision {
         match o {
@@ -313,3 +444,19 @@ mod optional_moment_json {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_equality() {
+        let a: NumberValue = serde_json::from_str(r#"{"value": 1234567.89}"#).unwrap();
+        let b: NumberValue = serde_json::from_str(r#"{"value": 1234567.88}"#).unwrap();
+        assert_ne!(a, b);
+        let computed = NumberValue { value: 1234567.0 + 0.89, precision: Precision::Exact };
+        assert_eq!(a, computed);
+        let a: PercentageValue = serde_json::from_str(r#"{"value": 0.3}"#).unwrap();
+        assert_eq!(a, PercentageValue { value: 0.1 + 0.2 });
+    }
+}
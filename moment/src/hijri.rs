@@ -0,0 +1,92 @@
+//! Arithmetic (tabular) Islamic calendar.
+//!
+//! Uses the 30-year cycle with leap years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 and
+//! the civil epoch (July 16, 622 Julian). Dates may differ by a day or two from the ones
+//! announced from moon sighting.
+use chrono::{Datelike, NaiveDate};
+
+/// Julian day number of 1 Muharram 1 AH.
+const HIJRI_EPOCH: i64 = 1_948_440;
+/// Julian day number of January 1, 1 CE minus one, i.e. chrono's day 0.
+const CE_EPOCH: i64 = 1_721_425;
+
+pub fn is_hijri_leap_year(year: i32) -> bool {
+    modulo(14 + 11 * year as i64, 30) < 11
+}
+
+pub fn hijri_month_length(year: i32, month: u32) -> u32 {
+    if month % 2 == 1 || (month == 12 && is_hijri_leap_year(year)) { 30 } else { 29 }
+}
+
+pub fn is_valid_hijri_month_day(month: u32, day: u32) -> bool {
+    month >= 1 && month <= 12 && day >= 1 && day <= 30
+}
+
+fn hijri_to_julian_day(year: i32, month: u32, day: u32) -> i64 {
+    let year = year as i64;
+    let month = month as i64;
+    day as i64 + (59 * (month - 1) + 1) / 2 + (year - 1) * 354 + div_floor(3 + 11 * year, 30) + HIJRI_EPOCH - 1
+}
+
+/// Gregorian date of the given Hijri date, `None` if the day does not exist in that month.
+pub fn hijri_to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if month < 1 || month > 12 || day < 1 || day > hijri_month_length(year, month) {
+        return None;
+    }
+    NaiveDate::from_num_days_from_ce_opt((hijri_to_julian_day(year, month, day) - CE_EPOCH) as i32)
+}
+
+/// Hijri (year, month, day) of the given Gregorian date.
+pub fn gregorian_to_hijri(date: NaiveDate) -> (i32, u32, u32) {
+    let jd = date.num_days_from_ce() as i64 + CE_EPOCH;
+    let year = div_floor(30 * (jd - HIJRI_EPOCH) + 10646, 10631) as i32;
+    let month = (1..13).rev()
+        .find(|&m| hijri_to_julian_day(year, m, 1) <= jd)
+        .unwrap_or(1);
+    let day = (jd - hijri_to_julian_day(year, month, 1) + 1) as u32;
+    (year, month, day)
+}
+
+fn modulo(a: i64, b: i64) -> i64 {
+    ((a % b) + b) % b
+}
+
+fn div_floor(a: i64, b: i64) -> i64 {
+    (a - modulo(a, b)) / b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leap_years() {
+        let leap_years: Vec<i32> = (1..31).filter(|&y| is_hijri_leap_year(y)).collect();
+        assert_eq!(vec![2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29], leap_years);
+    }
+
+    #[test]
+    fn test_hijri_to_gregorian() {
+        assert_eq!(Some(NaiveDate::from_ymd(622, 7, 19)), hijri_to_gregorian(1, 1, 1));
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 7, 9)), hijri_to_gregorian(1434, 9, 1));
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 8, 8)), hijri_to_gregorian(1434, 10, 1));
+        assert_eq!(None, hijri_to_gregorian(1434, 2, 30));
+    }
+
+    #[test]
+    fn test_gregorian_to_hijri() {
+        assert_eq!((1434, 9, 1), gregorian_to_hijri(NaiveDate::from_ymd(2013, 7, 9)));
+        assert_eq!((1434, 8, 29), gregorian_to_hijri(NaiveDate::from_ymd(2013, 7, 8)));
+        assert_eq!((1445, 1, 1), gregorian_to_hijri(NaiveDate::from_ymd(2023, 7, 19)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let start = NaiveDate::from_ymd(2000, 1, 1);
+        for offset in 0..20_000 {
+            let date = start + ::chrono::Duration::days(offset);
+            let (y, m, d) = gregorian_to_hijri(date);
+            assert_eq!(Some(date), hijri_to_gregorian(y, m, d));
+        }
+    }
+}
@@ -3,24 +3,123 @@ use std::rc::Rc;
 
 use bidirectional_walker::*;
 use walker::*;
-use {Moment, Interval, last_day_in_month};
+use {Moment, Interval, last_day_in_month, local_moment};
+use hijri::*;
 use period::*;
 use std::ops;
 use std::fmt;
-use chrono::{Datelike, Local, TimeZone, Timelike, Weekday};
+use std::collections::BTreeSet;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike, Weekday};
+
+/// Non-working days used to resolve business-day expressions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BusinessCalendar {
+    pub weekend: Vec<Weekday>,
+    pub holidays: BTreeSet<NaiveDate>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> BusinessCalendar {
+        BusinessCalendar {
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            holidays: BTreeSet::new(),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    pub fn new(weekend: Vec<Weekday>, holidays: Vec<NaiveDate>) -> BusinessCalendar {
+        BusinessCalendar {
+            weekend,
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+
+    pub fn with_holidays(self, holidays: Vec<NaiveDate>) -> BusinessCalendar {
+        BusinessCalendar {
+            holidays: self.holidays.into_iter().chain(holidays).collect(),
+            .. self
+        }
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// First day, as (month, day), of each season. A season lasts until the first day of the next one, excluded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeasonCalendar {
+    pub spring: (u32, u32),
+    pub summer: (u32, u32),
+    pub autumn: (u32, u32),
+    pub winter: (u32, u32),
+}
+
+impl Default for SeasonCalendar {
+    fn default() -> SeasonCalendar {
+        SeasonCalendar::for_hemisphere(Hemisphere::Northern)
+    }
+}
+
+impl SeasonCalendar {
+    pub fn new(spring: (u32, u32), summer: (u32, u32), autumn: (u32, u32), winter: (u32, u32)) -> MomentResult<SeasonCalendar> {
+        let calendar = SeasonCalendar { spring, summer, autumn, winter };
+        if [spring, summer, autumn, winter].iter().all(|&(m, d)| is_valid_month_day(m, d)) {
+            Ok(calendar)
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs { context: format!("{:?}", calendar) })
+        }
+    }
+
+    pub fn for_hemisphere(hemisphere: Hemisphere) -> SeasonCalendar {
+        let (march, june, september, december) = ((3, 20), (6, 21), (9, 23), (12, 21));
+        match hemisphere {
+            Hemisphere::Northern => SeasonCalendar { spring: march, summer: june, autumn: september, winter: december },
+            Hemisphere::Southern => SeasonCalendar { spring: september, summer: december, autumn: march, winter: june },
+        }
+    }
+
+    /// First day of the season and first day of the following one.
+    pub fn bounds(&self, season: Season) -> ((u32, u32), (u32, u32)) {
+        match season {
+            Season::Spring => (self.spring, self.summer),
+            Season::Summer => (self.summer, self.autumn),
+            Season::Autumn => (self.autumn, self.winter),
+            Season::Winter => (self.winter, self.spring),
+        }
+    }
+}
 
 #[derive(Clone, PartialEq, new)]
 pub struct Context<T: TimeZone> {
     pub reference: Interval<T>,
     pub min: Interval<T>,
     pub max: Interval<T>,
+    #[new(default)]
+    pub calendar: Rc<BusinessCalendar>,
+    #[new(default)]
+    pub seasons: Rc<SeasonCalendar>,
 }
 
-impl<T: TimeZone> Copy for Context<T> where <T as TimeZone>::Offset: Copy {}
-
 impl<T: TimeZone> fmt::Debug for Context<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Context {{ reference: {:?}, min: {:?}, max: {:?} }}", self.reference, self.min, self.max)
+        write!(f, "Context {{ reference: {:?}, min: {:?}, max: {:?}, calendar: {:?}, seasons: {:?} }}",
+               self.reference, self.min, self.max, self.calendar, self.seasons)
     }
 }
 
@@ -43,10 +142,24 @@ impl<T: TimeZone> Context<T> where <T as TimeZone>::Offset: Copy {
         let now_end = now.end_moment();
         let max_year = if 2038 > now_end.year() + 70 { now_end.year() + 70 } else { 2038 };
         let min_year = if 1970 < now.start.year() - 70 { now.start.year() - 70  } else { 1970 };
-        let min_interval = Interval::starting_at(Moment(now.timezone().ymd(min_year, 1, 1).and_hms(0, 0, 0)), Grain::Second);
-        let max_interval = Interval::starting_at(Moment(now.timezone().ymd(max_year, 1, 1).and_hms(0, 0, 0)), Grain::Second);
+        let min_interval = Interval::starting_at(Moment::start_of_day(&now.timezone(), min_year, 1, 1), Grain::Second);
+        let max_interval = Interval::starting_at(Moment::start_of_day(&now.timezone(), max_year, 1, 1), Grain::Second);
         Context::new(now, min_interval, max_interval)
     }
+
+    pub fn with_calendar(self, calendar: BusinessCalendar) -> Context<T> {
+        Context {
+            calendar: Rc::new(calendar),
+            .. self
+        }
+    }
+
+    pub fn with_seasons(self, seasons: SeasonCalendar) -> Context<T> {
+        Context {
+            seasons: Rc::new(seasons),
+            .. self
+        }
+    }
 }
 
 pub type IntervalWalker<T> = BidirectionalWalker<Interval<T>>;
@@ -175,11 +288,11 @@ impl<T: TimeZone> IntervalConstraint<T> for Year where <T as TimeZone>::Offset:
         if year > context.max.start.year() || year < context.min.start.year() {
             BidirectionalWalker::new()
         } else if origin.start.year() <= year {
-            let moment_year = Moment(origin.timezone().ymd(year, 1, 1).and_hms(0, 0, 0));
+            let moment_year = Moment::start_of_day(&origin.timezone(), year, 1, 1);
             let interval = Interval::starting_at(moment_year, Grain::Year);
             BidirectionalWalker::new().forward_values(vec![interval])
         } else {
-            let moment_year = Moment(origin.timezone().ymd(year, 1, 1).and_hms(0, 0, 0));
+            let moment_year = Moment::start_of_day(&origin.timezone(), year, 1, 1);
             let interval = Interval::starting_at(moment_year, Grain::Year);
             BidirectionalWalker::new().backward_values(vec![interval])
         }
@@ -224,11 +337,11 @@ impl<T: TimeZone> IntervalConstraint<T> for YearMonthDay where <T as TimeZone>::
         } else if self.day > last_day_in_month(year, self.month, origin.timezone()) {
             BidirectionalWalker::new() 
         } else if origin.start.year() <= year {
-            let moment_year = Moment(origin.timezone().ymd(year, self.month, self.day).and_hms(0, 0, 0));
+            let moment_year = Moment::start_of_day(&origin.timezone(), year, self.month, self.day);
             let interval = Interval::starting_at(moment_year, Grain::Day);
             BidirectionalWalker::new().forward_values(vec![interval])
         } else {
-            let moment_year = Moment(origin.timezone().ymd(year, self.month, self.day).and_hms(0, 0, 0));
+            let moment_year = Moment::start_of_day(&origin.timezone(), year, self.month, self.day);
             let interval = Interval::starting_at(moment_year, Grain::Day);
             BidirectionalWalker::new().backward_values(vec![interval])
         }
@@ -265,9 +378,7 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for MonthDay where <T as TimeZ
 
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         if !is_valid_month_day(self.0, self.1) { return BidirectionalWalker::new(); }
-        let rounded_moment = Moment(origin.timezone()
-                                        .ymd(origin.start.year(), self.0, 1)
-                                        .and_hms(0, 0, 0));
+        let rounded_moment = Moment::start_of_day(&origin.timezone(), origin.start.year(), self.0, 1);
         let rounded_interval = Interval::starting_at(rounded_moment, Grain::Month);
         let offset_year = !(origin.start <= rounded_interval.end_moment()) as i64;
         let anchor = rounded_interval + PeriodComp::years(offset_year);
@@ -302,6 +413,180 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for MonthDay where <T as TimeZ
 }
 
 
+/// First day of the given Hijri month, walked year after year in the Hijri calendar.
+fn hijri_month_start_walker<T: TimeZone + 'static>(month: u32, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T>
+    where <T as TimeZone>::Offset: Copy
+{
+    fn month_start<T: TimeZone>(tz: &T, year: i32, month: u32) -> Option<Interval<T>> where <T as TimeZone>::Offset: Copy {
+        hijri_to_gregorian(year, month, 1)
+            .map(|date| Interval::starting_at(Moment::start_of_day(tz, date.year(), date.month(), date.day()), Grain::Day))
+    }
+    fn hijri_year<T: TimeZone>(interval: &Interval<T>) -> i32 {
+        gregorian_to_hijri(interval.start.naive_local().date()).0
+    }
+
+    let tz = origin.timezone();
+    let origin_year = hijri_year(origin);
+    let anchor = match month_start(&tz, origin_year, month) {
+        Some(anchor) => anchor,
+        None => return BidirectionalWalker::new(),
+    };
+    let anchor_end = anchor + PeriodComp::days(hijri_month_length(origin_year, month) as i64);
+    let anchor = if origin.start < anchor_end.start {
+        anchor
+    } else {
+        match month_start(&tz, origin_year + 1, month) {
+            Some(anchor) => anchor,
+            None => return BidirectionalWalker::new(),
+        }
+    };
+    // Boundaries for iteration
+    let max_context_moment = context.max.end_moment();
+    let min_context_moment = context.min.start;
+
+    let forward_tz = tz.clone();
+    let forward_walker =
+        Walker::generator(anchor, move |prev| month_start(&forward_tz, hijri_year(&prev) + 1, month).unwrap_or(prev))
+            .take_while(move |i| i.start <= max_context_moment);
+    let backward_tz = tz.clone();
+    let backward_walker = match month_start(&tz, hijri_year(&anchor) - 1, month) {
+        Some(backward_anchor) => Walker::generator(backward_anchor,
+                                                   move |prev| month_start(&backward_tz, hijri_year(&prev) - 1, month).unwrap_or(prev))
+            .take_while(move |i| i.start >= min_context_moment),
+        None => Walker::vec(vec![]),
+    };
+
+    BidirectionalWalker::new()
+        .forward(forward_walker)
+        .backward(backward_walker)
+}
+
+/// Day of a month in the arithmetic Hijri calendar.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HijriMonthDay(pub u32, pub u32);
+
+impl HijriMonthDay {
+    pub fn new<T: TimeZone + 'static>(m: u32, d: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
+        let args = HijriMonthDay(m, d);
+        if is_valid_hijri_month_day(m, d) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {  context: format!("{:?}", args)})
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for HijriMonthDay where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        if !is_valid_hijri_month_day(self.0, self.1) { return BidirectionalWalker::new(); }
+        let month = self.0;
+        let day = self.1;
+        let months = hijri_month_start_walker(month, origin, context);
+        let to_day = move |interval: Interval<T>| interval + PeriodComp::days(day as i64 - 1);
+        let is_valid_day = move |interval: &Interval<T>| {
+            let (year, _, _) = gregorian_to_hijri(interval.start.naive_local().date());
+            day <= hijri_month_length(year, month)
+        };
+        let origin = *origin;
+        // The anchor month may contain the origin while the day itself is already past
+        let forward_walker = months.forward
+            .filter(is_valid_day)
+            .map(to_day)
+            .skip_while(move |i| i.end_moment() <= origin.start);
+        let backward_walker = months.backward
+            .filter(is_valid_day)
+            .map(to_day);
+        let backward_walker = Walker::vec(months.forward.clone()
+                                              .filter(is_valid_day)
+                                              .map(to_day)
+                                              .take_while(move |i| i.end_moment() <= origin.start)
+                                              .into_iter()
+                                              .collect())
+            .chain(&backward_walker);
+
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
+/// Whole month in the arithmetic Hijri calendar, e.g. Ramadan.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HijriMonth(pub u32);
+
+impl HijriMonth {
+    pub fn new<T: TimeZone + 'static>(m: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
+        let args = HijriMonth(m);
+        if is_valid_month(m) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {  context: format!("{:?}", args)})
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for HijriMonth where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        if !is_valid_month(self.0) { return BidirectionalWalker::new(); }
+        let month = self.0;
+        let months = hijri_month_start_walker(month, origin, context);
+        let to_month = move |interval: Interval<T>| {
+            let (year, _, _) = gregorian_to_hijri(interval.start.naive_local().date());
+            let end = interval + PeriodComp::days(hijri_month_length(year, month) as i64);
+            Interval { start: interval.start, end: Some(end.start), grain: Grain::Day }
+        };
+
+        BidirectionalWalker::new()
+            .forward(months.forward.map(to_month))
+            .backward(months.backward.map(to_month))
+    }
+}
+
+
+/// Season whose boundaries are read from the context's season calendar.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SeasonOfYear(pub Season);
+
+impl SeasonOfYear {
+    pub fn new<T: TimeZone + 'static>(season: Season) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(SeasonOfYear(season))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for SeasonOfYear where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let ((start_month, start_day), (end_month, end_day)) = context.seasons.bounds(self.0);
+        MonthDay::new_unchecked(start_month, start_day)
+            .span_to(&MonthDay::new_unchecked(end_month, end_day))
+            .to_walker(origin, context)
+    }
+}
+
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Month(pub u32);
 
@@ -331,9 +616,7 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for Month where <T as TimeZone
 
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
         if !is_valid_month(self.0) { return BidirectionalWalker::new(); }
-        let rounded_moment = Moment(origin.timezone()
-                                        .ymd(origin.start.year(), self.0, 1)
-                                        .and_hms(0, 0, 0));
+        let rounded_moment = Moment::start_of_day(&origin.timezone(), origin.start.year(), self.0, 1);
         let rounded_interval = Interval::starting_at(rounded_moment, Grain::Month);
         let offset_year = !(origin.start <= rounded_interval.end_moment()) as i64;
         let anchor = rounded_interval + PeriodComp::years(offset_year);
@@ -435,6 +718,51 @@ impl<T: TimeZone> IntervalConstraint<T> for DayOfWeek where <T as TimeZone>::Off
 }
 
 
+/// Walks the days that are business days according to the context's calendar.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BusinessDay;
+
+impl BusinessDay {
+    pub fn new<T: TimeZone + 'static>() -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(BusinessDay)
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for BusinessDay where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let anchor = origin.start_round_to(Grain::Day);
+        // Boundaries for iteration, a calendar made only of holidays must not loop forever
+        let max_context_moment = context.max.end_moment();
+        let min_context_moment = context.min.start;
+
+        let forward_calendar = context.calendar.clone();
+        let forward_walker =
+            Walker::generator(anchor, |prev| prev + PeriodComp::days(1))
+                .take_while(move |i| i.start <= max_context_moment)
+                .filter(move |i| forward_calendar.is_business_day(i.start.naive_local().date()));
+
+        let backward_calendar = context.calendar.clone();
+        let backward_walker =
+            Walker::generator(anchor - PeriodComp::days(1),
+                              |prev| prev - PeriodComp::days(1))
+                .take_while(move |i| i.end_moment() >= min_context_moment)
+                .filter(move |i| backward_calendar.is_business_day(i.start.naive_local().date()));
+
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct HourMinute {
     pub hour: u32,
@@ -463,17 +791,42 @@ impl HourMinute {
         }
     }
 
-    pub fn clock_12<T: TimeZone>(hour: u32, minute: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
+    pub fn clock_12<T: TimeZone + 'static>(hour: u32, minute: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
         Ok(rc!(HourMinute::new(hour, minute)?.with_is_12_clock(true)))
     }
 
-    pub fn clock_24<T: TimeZone>(hour: u32, minute: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
+    pub fn clock_24<T: TimeZone + 'static>(hour: u32, minute: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
         Ok(rc!(HourMinute::new(hour, minute)?.with_is_12_clock(false)))
     }
 }
 
 
-impl<T: TimeZone> IntervalConstraint<T> for HourMinute where <T as TimeZone>::Offset: Copy {
+/// Walks a local time of day every `step_hours` of wall clock time, so that a daylight saving
+/// transition does not shift the following occurrences by the length of the transition.
+fn wall_clock_walker<T: TimeZone + 'static>(tz: T, anchor: NaiveDateTime, step_hours: i64, grain: Grain) -> IntervalWalker<T>
+    where <T as TimeZone>::Offset: Copy
+{
+    fn nth<T: TimeZone>(tz: &T, anchor: NaiveDateTime, step_hours: i64, grain: Grain, n: i64) -> Interval<T>
+        where <T as TimeZone>::Offset: Copy
+    {
+        Interval::starting_at(local_moment(tz, anchor + Duration::hours(step_hours * n)), grain)
+    }
+    // Index of an occurrence, a time moved forward by a daylight saving gap is less than half a step away
+    fn index<T: TimeZone>(interval: &Interval<T>, anchor: NaiveDateTime, step_hours: i64) -> i64 {
+        let step = step_hours * 60;
+        let shifted = interval.start.naive_local().signed_duration_since(anchor).num_minutes() + step / 2;
+        (shifted - ((shifted % step) + step) % step) / step
+    }
+
+    let backward_tz = tz.clone();
+    BidirectionalWalker::new()
+        .forward_with(nth(&tz, anchor, step_hours, grain, 0),
+                      move |prev| nth(&tz, anchor, step_hours, grain, index(&prev, anchor, step_hours) + 1))
+        .backward_with(nth(&backward_tz, anchor, step_hours, grain, -1),
+                       move |prev| nth(&backward_tz, anchor, step_hours, grain, index(&prev, anchor, step_hours) - 1))
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for HourMinute where <T as TimeZone>::Offset: Copy {
     fn grain(&self) -> Grain {
         Grain::Minute
     }
@@ -490,11 +843,10 @@ impl<T: TimeZone> IntervalConstraint<T> for HourMinute where <T as TimeZone>::Of
         };
         let offset_hour = (self.hour as i64 - origin.start.hour() as i64 + clock_step) % clock_step;
         let offset_minute = self.minute as i64 % 60;
-        let anchor = origin.start_round_to(Grain::Hour) + PeriodComp::hours(offset_hour)+ PeriodComp::minutes(offset_minute);
-        BidirectionalWalker::new()
-            .forward_with(anchor, move |prev| prev + PeriodComp::hours(clock_step))
-            .backward_with(anchor - PeriodComp::hours(clock_step),
-                           move |prev| prev - PeriodComp::hours(clock_step))
+        let anchor = origin.start.round_to(Grain::Hour).naive_local()
+            + Duration::hours(offset_hour)
+            + Duration::minutes(offset_minute);
+        wall_clock_walker(origin.timezone(), anchor, clock_step, Grain::Minute)
     }
 }
 
@@ -525,16 +877,16 @@ impl Hour {
         }
     }
 
-    pub fn clock_12<T: TimeZone>(hour: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
+    pub fn clock_12<T: TimeZone + 'static>(hour: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
         Ok(rc!(Hour::new(hour)?.with_is_12_clock(true)))
     }
 
-    pub fn clock_24<T: TimeZone>(hour: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
+    pub fn clock_24<T: TimeZone + 'static>(hour: u32) -> MomentResult<RcConstraint<T>> where <T as TimeZone>::Offset: Copy {
         Ok(rc!(Hour::new(hour)?.with_is_12_clock(false)))
     }
 }
 
-impl<T: TimeZone> IntervalConstraint<T> for Hour where <T as TimeZone>::Offset: Copy {
+impl<T: TimeZone + 'static> IntervalConstraint<T> for Hour where <T as TimeZone>::Offset: Copy {
     fn grain(&self) -> Grain {
         Grain::Hour
     }
@@ -550,12 +902,9 @@ impl<T: TimeZone> IntervalConstraint<T> for Hour where <T as TimeZone>::Offset:
             24
         };
         let offset = (self.hour as i64 - origin.start.hour() as i64 + clock_step) % clock_step;
-        let anchor = origin.start_round_to(Grain::Hour) + PeriodComp::hours(offset);
+        let anchor = origin.start.round_to(Grain::Hour).naive_local() + Duration::hours(offset);
 
-        BidirectionalWalker::new()
-            .forward_with(anchor, move |prev| prev + PeriodComp::hours(clock_step))
-            .backward_with(anchor - PeriodComp::hours(clock_step),
-                           move |prev| prev - PeriodComp::hours(clock_step))
+        wall_clock_walker(origin.timezone(), anchor, clock_step, Grain::Hour)
     }
 }
 
@@ -934,7 +1283,7 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Intersection<T>  where <T as
                      constraint: RcConstraint<U>)
                      -> Walker<Interval<U>> where <U as TimeZone>::Offset: Copy 
         {
-            let context = Context::new(context.reference, *origin, *origin);
+            let context = Context { min: *origin, max: *origin, .. context };
             let max_moment = origin.end_moment();
             let origin_copied = origin.clone();
             constraint
@@ -953,24 +1302,26 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Intersection<T>  where <T as
         {
             let coarse_walker = coarse.to_walker(origin, &context);
             let max_moment = context.max.end_moment();
+            let min_moment = context.min.start;
             let fine_for_walker = fine.clone();
+            let context_for_walker = context.clone();
             let fore = coarse_walker
                 .forward
                 .take_while(move |i| i.start <= max_moment)
                 .take(183)
-                .flat_map(move |i| walk_from(&i, context, fine_for_walker.clone()));
+                .flat_map(move |i| walk_from(&i, context_for_walker.clone(), fine_for_walker.clone()));
             let back = coarse_walker
                 .backward
-                .take_while(move |i| i.end_moment() >= context.min.start)
+                .take_while(move |i| i.end_moment() >= min_moment)
                 .take(183)
-                .flat_map(move |i| walk_from(&i, context, fine.clone()));
+                .flat_map(move |i| walk_from(&i, context.clone(), fine.clone()));
             IntervalWalker::new().forward(fore).backward(back)
         }
 
         if self.lhs.coarse_grain_step() <= self.rhs.coarse_grain_step() {
-            combine(origin, *context, self.rhs.clone(), self.lhs.clone())
+            combine(origin, context.clone(), self.rhs.clone(), self.lhs.clone())
         } else {
-            combine(origin, *context, self.lhs.clone(), self.rhs.clone())
+            combine(origin, context.clone(), self.lhs.clone(), self.rhs.clone())
         }
     }
 }
@@ -1005,42 +1356,47 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Translate<T>  where <T as Ti
 
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let generator_walker = self.generator.to_walker(origin, context);
-        let context = *context;
+        let min_moment = context.min.start;
+        let max_moment = context.max.end_moment();
 
         let offset = self.offset.clone();
         let origin = *origin;
+        let offset_context = context.clone();
         let prepend_to_fore = generator_walker
             .backward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .take_while(move |i| origin.start <= i.end_moment());
         let mut prepend_to_fore: Vec<Interval<T>> = prepend_to_fore.into_iter().collect();
         prepend_to_fore.reverse();
 
         let offset = self.offset.clone();
+        let offset_context = context.clone();
         let still_fore = generator_walker
             .forward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .skip_while(move |i| origin.start > i.end_moment())
-            .take_while(move |i| i.start <= context.max.end_moment());
+            .take_while(move |i| i.start <= max_moment);
 
         let offset = self.offset.clone();
+        let offset_context = context.clone();
         let prepend_to_back = generator_walker
             .forward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .take_while(move |i| origin.start > i.end_moment());
         let mut prepend_to_back: Vec<Interval<T>> = prepend_to_back.into_iter().collect();
         prepend_to_back.reverse();
 
         let offset = self.offset.clone();
+        let offset_context = context.clone();
         let still_back = generator_walker
             .backward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &offset_context))
             .skip_while(move |i| origin.start <= i.end_moment())
-            .take_while(move |i| context.min.start <= i.end_moment());
+            .take_while(move |i| min_moment <= i.end_moment());
 
         IntervalWalker::new()
             .forward(Walker::vec(prepend_to_fore).chain(&still_fore))
@@ -1573,6 +1929,146 @@ mod tests {
                    walker.backward.clone().skip(1).next());
     }
 
+    #[test]
+    fn test_hijri_month_day() {
+        // 1 Shawwal 1434 => 2013-08-08, 1 Shawwal 1435 => 2014-07-29
+        let context = build_context(Moment(Paris.ymd(2013, 08, 09).and_hms(9, 10, 11)));
+        let walker = HijriMonthDay(10, 1).to_walker(&context.reference, &context);
+
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2014, 07, 29).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2013, 08, 08).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.backward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2012, 08, 19).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.backward.clone().skip(1).next());
+    }
+
+    #[test]
+    fn test_hijri_month() {
+        // Ramadan 1434 => 2013-07-09 to 2013-08-08
+        let context = build_context(Moment(Paris.ymd(2013, 02, 12).and_hms(9, 10, 11)));
+        let walker = HijriMonth(9).to_walker(&context.reference, &context);
+
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2013, 07, 09).and_hms(0, 0, 0)),
+                                      Some(Moment(Paris.ymd(2013, 08, 08).and_hms(0, 0, 0))),
+                                      Grain::Day)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2012, 07, 20).and_hms(0, 0, 0)),
+                                      Some(Moment(Paris.ymd(2012, 08, 19).and_hms(0, 0, 0))),
+                                      Grain::Day)),
+                   walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_season_of_year_by_hemisphere() {
+        let context = build_context(Moment(Paris.ymd(2013, 02, 12).and_hms(9, 10, 11)));
+        let walker = SeasonOfYear(Season::Summer).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2013, 06, 21).and_hms(0, 0, 0)),
+                                      Some(Moment(Paris.ymd(2013, 09, 23).and_hms(0, 0, 0))),
+                                      Grain::Day)),
+                   walker.forward.clone().next());
+
+        let context = context.with_seasons(SeasonCalendar::for_hemisphere(Hemisphere::Southern));
+        let walker = SeasonOfYear(Season::Summer).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2012, 12, 21).and_hms(0, 0, 0)),
+                                      Some(Moment(Paris.ymd(2013, 03, 20).and_hms(0, 0, 0))),
+                                      Grain::Day)),
+                   walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_season_boundary_day() {
+        let context = build_context(Moment(Paris.ymd(2013, 02, 12).and_hms(9, 10, 11)));
+        let summer = SeasonOfYear(Season::Summer).to_walker(&context.reference, &context).forward.next().unwrap();
+        let autumn = SeasonOfYear(Season::Autumn).to_walker(&context.reference, &context).forward.next().unwrap();
+        let boundary_day = Interval::starting_at(Moment(Paris.ymd(2013, 09, 23).and_hms(0, 0, 0)), Grain::Day);
+
+        // The first day of autumn is not the last day of summer
+        assert_eq!(summer.end, Some(autumn.start));
+        assert_eq!(None, summer.intersect(boundary_day));
+        assert_eq!(Some(boundary_day), autumn.intersect(boundary_day));
+    }
+
+    #[test]
+    fn test_hour_across_daylight_saving() {
+        use tests::ParisDst;
+        let now = Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 25).and_hms(10, 0, 0)), Grain::Second);
+        let context = Context::for_reference(now);
+        let walker = Hour::clock_24(9).unwrap().to_walker(&context.reference, &context);
+
+        assert_eq!(Some(Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 26).and_hms(9, 0, 0)), Grain::Hour)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 27).and_hms(9, 0, 0)), Grain::Hour)),
+                   walker.forward.clone().skip(1).next());
+        assert_eq!(Some(Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 25).and_hms(9, 0, 0)), Grain::Hour)),
+                   walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_hour_minute_in_daylight_saving_gap() {
+        use tests::ParisDst;
+        let now = Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 25).and_hms(10, 0, 0)), Grain::Second);
+        let context = Context::for_reference(now);
+        let walker = HourMinute::clock_24(2, 30).unwrap().to_walker(&context.reference, &context);
+
+        // 02:30 does not exist on the 26th and is moved after the gap, the next days are not shifted
+        assert_eq!(Some(Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 26).and_hms(3, 30, 0)), Grain::Minute)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 27).and_hms(2, 30, 0)), Grain::Minute)),
+                   walker.forward.clone().skip(1).next());
+    }
+
+    #[test]
+    fn test_business_day_skips_weekend() {
+        // Day of week => Friday
+        let context = build_context(Moment(Paris.ymd(2017, 04, 28).and_hms(9, 10, 11)));
+        let walker = BusinessDay.to_walker(&context.reference, &context);
+
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 28).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 05, 01).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.forward.clone().skip(1).next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 27).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_business_day_skips_holidays() {
+        let calendar = BusinessCalendar::default()
+            .with_holidays(vec![NaiveDate::from_ymd(2017, 05, 01), NaiveDate::from_ymd(2017, 04, 27)]);
+        let context = build_context(Moment(Paris.ymd(2017, 04, 28).and_hms(9, 10, 11)))
+            .with_calendar(calendar);
+        let walker = BusinessDay.to_walker(&context.reference, &context);
+
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 05, 02).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.forward.clone().skip(1).next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 26).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_take_the_last_business_day_of_month() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let take_last_of = BusinessDay::new().last_of(&rc!(Month(4)));
+
+        let walker = take_last_of.to_walker(&context.reference, &context);
+
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 28).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2018, 04, 30).and_hms(0, 0, 0)),
+                                              Grain::Day)),
+                   walker.forward.clone().skip(1).next());
+    }
+
     #[test]
     fn test_hour_minute_24_clock_under_12() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
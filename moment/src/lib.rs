@@ -11,13 +11,14 @@ mod period;
 pub mod interval_constraints;
 pub mod bidirectional_walker;
 pub mod walker;
+pub mod hijri;
 
 use std::ops;
 use std::cmp::Ordering;
 use std::fmt;
 
-use chrono::{DateTime, Duration, Datelike, Timelike};
-pub use chrono::{Weekday, Local, TimeZone};
+use chrono::{DateTime, Duration, Datelike, Timelike, NaiveDateTime, LocalResult, Offset};
+pub use chrono::{Weekday, Local, NaiveDate, TimeZone};
 pub use interval_constraints::*;
 pub use period::*;
 
@@ -76,6 +77,15 @@ fn last_day_in_month<T: TimeZone>(y: i32, m: u32, tz: T) -> u32 {
     31
 }
 
+/// Moment of a local date and time as given by `Moment::from_local`, falling back to the UTC
+/// offset of the same date and time when the timezone has no offset around the local time.
+fn local_moment<T: TimeZone>(tz: &T, local: NaiveDateTime) -> Moment<T> where <T as TimeZone>::Offset: Copy {
+    Moment::from_local(tz, local).unwrap_or_else(|| {
+        let offset = tz.offset_from_utc_datetime(&local).fix();
+        Moment(tz.from_utc_datetime(&(local - offset)))
+    })
+}
+
 impl<T: TimeZone> ops::Deref for Moment<T> {
     type Target = DateTime<T>;
     fn deref(&self) -> &Self::Target {
@@ -101,12 +111,41 @@ impl Moment<Local> {
     }
 
     pub fn ymd(y: i32, m: u32, d: u32) -> Moment<Local> {
-        Moment(Local.ymd(y, m, d).and_hms(0, 0, 0))
+        Moment::start_of_day(&Local, y, m, d)
     }
 }
 
 impl<T: TimeZone> Moment<T> where <T as TimeZone>::Offset: Copy {
 
+    /// Builds the moment of a local date and time in the given timezone.
+    ///
+    /// A local time skipped by a daylight saving transition is moved forward by the length of
+    /// the gap (02:30 becomes 03:30 when clocks go from 02:00 to 03:00), and an ambiguous local
+    /// time resolves to its first occurrence. Returns `None` when the timezone has no offset for
+    /// the local time nor for any time in the day before it.
+    pub fn from_local(tz: &T, local: NaiveDateTime) -> Option<Moment<T>> {
+        match tz.from_local_datetime(&local) {
+            LocalResult::Single(dt) => Some(Moment(dt)),
+            LocalResult::Ambiguous(earliest, _) => Some(Moment(earliest)),
+            LocalResult::None => {
+                // Read the local time with the offset in effect just before the gap
+                (1..49)
+                    .filter_map(|n| match tz.offset_from_local_datetime(&(local - Duration::minutes(30 * n))) {
+                        LocalResult::Single(offset) => Some(offset),
+                        LocalResult::Ambiguous(_, latest) => Some(latest),
+                        LocalResult::None => None,
+                    })
+                    .next()
+                    .map(|offset| Moment(tz.from_utc_datetime(&(local - offset.fix()))))
+            }
+        }
+    }
+
+    /// Local midnight of the given date, or the first instant of that date when midnight is skipped.
+    pub fn start_of_day(tz: &T, y: i32, m: u32, d: u32) -> Moment<T> {
+        local_moment(tz, NaiveDate::from_ymd(y, m, d).and_hms(0, 0, 0))
+    }
+
     fn add_months(self, n: i32) -> Moment<T> {
         let (year, month0) = if n >= 0 {
             let n = n as u32;
@@ -119,16 +158,22 @@ impl<T: TimeZone> Moment<T> where <T as TimeZone>::Offset: Copy {
         };
         let target_month_days = last_day_in_month(year, month0 + 1, self.timezone());
         let day = ::std::cmp::min(target_month_days, self.day());
-        Moment(self.timezone().ymd(year, month0 + 1, day).and_hms(self.hour(), self.minute(), self.second()))
+        let local = NaiveDate::from_ymd(year, month0 + 1, day).and_time(self.naive_local().time());
+        local_moment(&self.timezone(), local)
+    }
+
+    fn add_local_days(self, n: i64) -> Moment<T> {
+        local_moment(&self.timezone(), self.naive_local() + Duration::days(n))
     }
 
     fn round_to(self, g: Grain) -> Moment<T> {
         match g {
-            Grain::Year => Moment(self.timezone().ymd(self.year(), 1, 1).and_hms(0, 0, 0)),
-            Grain::Month => Moment(self.timezone().ymd(self.year(), self.month(), 1).and_hms(0, 0, 0)),
-            Grain::Day => Moment(self.date().and_hms(0, 0, 0)),
-            Grain::Hour => Moment(self.date().and_hms(self.hour(), 0, 0)),
-            Grain::Minute => Moment(self.date().and_hms(self.hour(), self.minute(), 0)),
+            Grain::Year => Moment::start_of_day(&self.timezone(), self.year(), 1, 1),
+            Grain::Month => Moment::start_of_day(&self.timezone(), self.year(), self.month(), 1),
+            Grain::Day => Moment::start_of_day(&self.timezone(), self.year(), self.month(), self.day()),
+            // Hours and minutes are truncated on the timeline to stay on the right side of an ambiguous hour
+            Grain::Hour => Moment(self.0 - Duration::minutes(self.minute() as i64) - Duration::seconds(self.second() as i64)),
+            Grain::Minute => Moment(self.0 - Duration::seconds(self.second() as i64)),
             Grain::Second => self,
             Grain::Week => {
                 // shift to monday morning
@@ -140,12 +185,6 @@ impl<T: TimeZone> Moment<T> where <T as TimeZone>::Offset: Copy {
             }
         }
     }
-
-    fn adjust_for_daylight_saving(self) -> Moment<T> {
-        Moment(self.timezone()
-                   .ymd(self.year(), self.month(), self.day())
-                   .and_hms(self.hour(), self.minute(), self.second()))
-    }
 }
 
 impl<T: TimeZone> ops::Add<Period> for Moment<T>  where <T as TimeZone>::Offset: Copy {
@@ -185,10 +224,9 @@ impl<'a, T: TimeZone> ops::Add<&'a PeriodComp> for Moment<T>  where <T as TimeZo
             Grain::Year => self.add_months(12 * p.quantity as i32),
             Grain::Quarter => self.add_months(3 * p.quantity as i32),
             Grain::Month => self.add_months(p.quantity as i32),
-            Grain::Week => {
-                Moment(self.0 + Duration::weeks(p.quantity)).adjust_for_daylight_saving()
-            }
-            Grain::Day => Moment(self.0 + Duration::days(p.quantity)).adjust_for_daylight_saving(),
+            // Days and weeks follow the wall clock, hours and below are elapsed time
+            Grain::Week => self.add_local_days(7 * p.quantity),
+            Grain::Day => self.add_local_days(p.quantity),
             Grain::Hour => Moment(self.0 + Duration::hours(p.quantity)),
             Grain::Minute => Moment(self.0 + Duration::minutes(p.quantity)),
             Grain::Second => Moment(self.0 + Duration::seconds(p.quantity)),
@@ -225,7 +263,7 @@ impl<T: TimeZone> Interval<T> {
 
 impl Interval<Local> {
     pub fn ymd(y: i32, m: u32, d: u32) -> Interval<Local> {
-        Interval::starting_at(Moment(Local.ymd(y, m, d).and_hms(0, 0, 0)), Grain::Day)
+        Interval::starting_at(Moment::start_of_day(&Local, y, m, d), Grain::Day)
     }
 }
 
@@ -396,7 +434,7 @@ impl<T: TimeZone> ops::Sub<Period> for Interval<T> where <T as TimeZone>::Offset
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{TimeZone, FixedOffset, NaiveDate, NaiveDateTime, LocalResult};
+    use chrono::{TimeZone, FixedOffset, NaiveDate, NaiveDateTime, LocalResult, Datelike};
 
     #[derive(Copy, Clone, PartialEq, Eq)]
     struct Paris;
@@ -415,6 +453,51 @@ mod tests {
         fn offset_from_utc_date(&self, _utc: &NaiveDate) -> FixedOffset { FixedOffset::east(2*3600) }
         fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> FixedOffset { FixedOffset::east(2*3600) }
     }
+
+    /// Central European time with the EU daylight saving rules: +02:00 from the last Sunday of
+    /// March to the last Sunday of October at 01:00 UTC, +01:00 otherwise.
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub struct ParisDst;
+
+    impl ParisDst {
+        fn last_sunday_at_one_utc(year: i32, month: u32) -> NaiveDateTime {
+            let last_day = NaiveDate::from_ymd(year, month, 31);
+            let offset = last_day.weekday().num_days_from_sunday() as i64;
+            (last_day - Duration::days(offset)).and_hms(1, 0, 0)
+        }
+
+        fn is_summer_time(utc: &NaiveDateTime) -> bool {
+            *utc >= ParisDst::last_sunday_at_one_utc(utc.year(), 3)
+                && *utc < ParisDst::last_sunday_at_one_utc(utc.year(), 10)
+        }
+    }
+
+    impl TimeZone for ParisDst {
+        type Offset = FixedOffset;
+        fn from_offset(_: &FixedOffset) -> ParisDst { ParisDst }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+        }
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let candidates: Vec<FixedOffset> = [FixedOffset::east(2*3600), FixedOffset::east(3600)].iter()
+                .cloned()
+                .filter(|offset| self.offset_from_utc_datetime(&(*local - *offset)) == *offset)
+                .collect();
+            match candidates.len() {
+                0 => LocalResult::None,
+                1 => LocalResult::Single(candidates[0]),
+                _ => LocalResult::Ambiguous(candidates[0], candidates[1]),
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms(12, 0, 0))
+        }
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            if ParisDst::is_summer_time(utc) { FixedOffset::east(2*3600) } else { FixedOffset::east(3600) }
+        }
+    }
     
     #[test]
     fn test_last_day_in_month() {
@@ -505,9 +588,84 @@ mod tests {
 
     #[test]
     fn daylight_saving_aware() {
-        // TODO Take a look at the offset shifting due to a period addition        // 1st March -> +1 and 31 Match -> +2        // 1st March + 30 days -> +1 instead of +2
-        assert_eq!(Moment(Paris.ymd(2017, 03, 31).and_hms(0, 0, 0)),
-                   Moment(Paris.ymd(2017, 03, 20).and_hms(0, 0, 0)) + PeriodComp::days(11))
+        // 20th March -> +1 and 31st March -> +2
+        assert_eq!(Moment(ParisDst.ymd(2017, 03, 31).and_hms(0, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 20).and_hms(0, 0, 0)) + PeriodComp::days(11));
+        assert_eq!(Moment(ParisDst.ymd(2017, 03, 20).and_hms(0, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 31).and_hms(0, 0, 0)) - PeriodComp::days(11));
+        assert_eq!(Moment(ParisDst.ymd(2017, 11, 03).and_hms(9, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 10, 27).and_hms(9, 0, 0)) + PeriodComp::weeks(1));
+        assert_eq!(Moment(ParisDst.ymd(2017, 04, 25).and_hms(12, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 02, 25).and_hms(12, 0, 0)) + PeriodComp::months(2));
+    }
+
+    #[test]
+    fn hours_are_elapsed_time_across_daylight_saving() {
+        // 23 hours in the spring forward day, 25 hours in the fall back day
+        assert_eq!(Moment(ParisDst.ymd(2017, 03, 27).and_hms(1, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 26).and_hms(0, 0, 0)) + PeriodComp::hours(24));
+        assert_eq!(Moment(ParisDst.ymd(2017, 10, 29).and_hms(23, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 10, 29).and_hms(0, 0, 0)) + PeriodComp::hours(24));
+        assert_eq!(Moment(ParisDst.ymd(2017, 03, 26).and_hms(3, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 26).and_hms(1, 30, 0)) + PeriodComp::minutes(30));
+    }
+
+    #[test]
+    fn skipped_and_ambiguous_local_times() {
+        let skipped = NaiveDate::from_ymd(2017, 03, 26).and_hms(2, 30, 0);
+        assert_eq!(Some(Moment(ParisDst.ymd(2017, 03, 26).and_hms(3, 30, 0))),
+                   Moment::from_local(&ParisDst, skipped));
+        assert_eq!(Moment(ParisDst.ymd(2017, 03, 26).and_hms(3, 30, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 25).and_hms(2, 30, 0)) + PeriodComp::days(1));
+
+        let ambiguous = NaiveDate::from_ymd(2017, 10, 29).and_hms(2, 30, 0);
+        let first_occurrence = Moment::from_local(&ParisDst, ambiguous).unwrap();
+        assert_eq!(FixedOffset::east(2*3600), *first_occurrence.offset());
+        assert_eq!(first_occurrence, Moment(ParisDst.ymd(2017, 10, 28).and_hms(2, 30, 0)) + PeriodComp::days(1));
+    }
+
+    #[test]
+    fn local_time_without_offset() {
+        // A broken timezone where every local time falls in a gap
+        #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+        struct Gap;
+
+        impl TimeZone for Gap {
+            type Offset = FixedOffset;
+            fn from_offset(_: &FixedOffset) -> Gap { Gap }
+
+            fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<FixedOffset> { LocalResult::None }
+            fn offset_from_local_datetime(&self, _local: &NaiveDateTime) -> LocalResult<FixedOffset> { LocalResult::None }
+
+            fn offset_from_utc_date(&self, _utc: &NaiveDate) -> FixedOffset { FixedOffset::east(3600) }
+            fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> FixedOffset { FixedOffset::east(3600) }
+        }
+
+        assert_eq!(None, Moment::from_local(&Gap, NaiveDate::from_ymd(2017, 03, 26).and_hms(2, 30, 0)));
+        assert_eq!(Moment(Gap.from_utc_datetime(&NaiveDate::from_ymd(2017, 03, 25).and_hms(23, 0, 0))),
+                   Moment::start_of_day(&Gap, 2017, 03, 26));
+    }
+
+    #[test]
+    fn rounding_across_daylight_saving() {
+        let second_occurrence = Moment(ParisDst.from_utc_datetime(&NaiveDate::from_ymd(2017, 10, 29).and_hms(1, 40, 0)));
+        assert_eq!(Moment(ParisDst.from_utc_datetime(&NaiveDate::from_ymd(2017, 10, 29).and_hms(1, 0, 0))),
+                   second_occurrence.round_to(Grain::Hour));
+        assert_eq!(Moment(ParisDst.ymd(2017, 10, 29).and_hms(0, 0, 0)),
+                   second_occurrence.round_to(Grain::Day));
+    }
+
+    #[test]
+    fn interval_length_across_daylight_saving() {
+        let spring_forward = Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 26).and_hms(0, 0, 0)), Grain::Day);
+        assert_eq!(Moment(ParisDst.ymd(2017, 03, 27).and_hms(0, 0, 0)), spring_forward.end_moment());
+        assert_eq!(23 * 3600, spring_forward.seconds());
+
+        let fall_back = Interval::starting_at(Moment(ParisDst.ymd(2017, 10, 29).and_hms(0, 0, 0)), Grain::Day);
+        assert_eq!(25 * 3600, fall_back.seconds());
+
+        let march = Interval::starting_at(Moment(ParisDst.ymd(2017, 03, 01).and_hms(0, 0, 0)), Grain::Month);
+        assert_eq!(Moment(ParisDst.ymd(2017, 04, 01).and_hms(0, 0, 0)), march.end_moment());
     }
 
     #[test]
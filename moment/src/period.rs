@@ -131,6 +131,83 @@ impl Period {
     pub fn coarse_num_secs(&self) -> i64 {
         self.comps().iter().map(|it| it.coarse_num_secs()).sum()
     }
+
+    /// Years, quarters and months have no fixed length: `coarse_num_secs` counts them as
+    /// 30-day months
+    pub fn has_exact_num_secs(&self) -> bool {
+        self.comps().iter().all(|it| it.quantity == 0 || it.grain > Grain::Month)
+    }
+
+    /// ISO 8601 representation, e.g. "PT1H30M", "P1Y2M3D" or "P2W". Quarters are written as
+    /// 3 months and weeks mixed with other components as 7 days. Components of mixed signs are
+    /// normalized ("1 hour minus 15 minutes" is "PT45M", "1 day minus 2 hours" is "PT22H"), and
+    /// `None` is returned when months and years can not be, as in "1 month minus 1 day".
+    pub fn to_iso8601(&self) -> Option<String> {
+        let get = |grain: Grain| *self.0.get(grain as usize).unwrap_or(&0);
+        let mut calendar = normalized_comps(&[get(Grain::Year), 3 * get(Grain::Quarter) + get(Grain::Month)], &[12, 1]);
+        let mut days = 7 * get(Grain::Week) + get(Grain::Day);
+        let mut clock = normalized_comps(&[get(Grain::Hour), get(Grain::Minute), get(Grain::Second)], &[3600, 60, 1]);
+        let clock_sign = clock.iter().map(|q| q.signum()).find(|s| *s != 0).unwrap_or(0);
+        if days.signum() * clock_sign < 0 {
+            // Days are taken as 24 hours to borrow between days and hours
+            clock = normalized_comps(&[24 * days + clock[0], clock[1], clock[2]], &[3600, 60, 1]);
+            days = 0;
+        }
+        let signs: Vec<i64> = calendar.iter().chain(Some(&days)).chain(clock.iter())
+            .map(|q| q.signum())
+            .filter(|s| *s != 0)
+            .collect();
+        if signs.iter().any(|s| *s != signs[0]) {
+            return None;
+        }
+        let negative = signs.first() == Some(&-1);
+        if negative {
+            calendar = calendar.iter().map(|q| -q).collect();
+            days = -days;
+            clock = clock.iter().map(|q| -q).collect();
+        }
+
+        let mut iso = if negative { "-P".to_string() } else { "P".to_string() };
+        let only_weeks = get(Grain::Week) != 0 && get(Grain::Day) == 0
+            && calendar.iter().chain(clock.iter()).all(|q| *q == 0);
+        if only_weeks {
+            iso.push_str(&format!("{}W", days / 7));
+            return Some(iso);
+        }
+        for &(quantity, designator) in &[(calendar[0], "Y"), (calendar[1], "M"), (days, "D")] {
+            if quantity != 0 {
+                iso.push_str(&format!("{}{}", quantity, designator));
+            }
+        }
+        if clock.iter().any(|q| *q != 0) {
+            iso.push('T');
+            for &(quantity, designator) in &[(clock[0], "H"), (clock[1], "M"), (clock[2], "S")] {
+                if quantity != 0 {
+                    iso.push_str(&format!("{}{}", quantity, designator));
+                }
+            }
+        }
+        if iso.ends_with('P') {
+            iso.push_str("T0S");
+        }
+        Some(iso)
+    }
+}
+
+/// Components of a single sign are kept as they are, mixed signs are summed in the unit of the
+/// last component, whose size relative to the others is given by `factors`, and split again
+fn normalized_comps(quantities: &[i64], factors: &[i64]) -> Vec<i64> {
+    if quantities.iter().all(|q| *q >= 0) || quantities.iter().all(|q| *q <= 0) {
+        return quantities.to_vec();
+    }
+    let mut rest: i64 = quantities.iter().zip(factors.iter()).map(|(q, f)| q * f).sum();
+    factors.iter()
+        .map(|f| {
+            let quantity = rest / f;
+            rest -= quantity * f;
+            quantity
+        })
+        .collect()
 }
 
 impl From<PeriodComp> for Period {
@@ -396,4 +473,36 @@ mod tests {
         a.0.insert(Grain::Hour as usize, 4);
         assert_eq!(a.finer_grain(), Some(Grain::Hour));
     }
+
+    #[test]
+    fn has_exact_num_secs() {
+        let a = Period::default() + PeriodComp::days(2) + PeriodComp::hours(3);
+        assert!(a.has_exact_num_secs());
+        assert!(!(a + PeriodComp::months(1)).has_exact_num_secs());
+    }
+
+    #[test]
+    fn to_iso8601() {
+        let iso = |period: Period| period.to_iso8601();
+        assert_eq!(Some("PT0S".to_string()), iso(Period::default()));
+        assert_eq!(Some("PT1H30M".to_string()), iso(Period::default() + PeriodComp::hours(1) + PeriodComp::minutes(30)));
+        assert_eq!(Some("P1Y5M3D".to_string()), iso(Period::default() + PeriodComp::years(1) + PeriodComp::quarters(1)
+            + PeriodComp::months(2) + PeriodComp::days(3)));
+        assert_eq!(Some("P2W".to_string()), iso(Period::from(PeriodComp::weeks(2))));
+        assert_eq!(Some("P9DT12H".to_string()), iso(Period::default() + PeriodComp::weeks(1) + PeriodComp::days(2)
+            + PeriodComp::hours(12)));
+        assert_eq!(Some("-P1D".to_string()), iso(Period::from(PeriodComp::days(-1))));
+    }
+
+    #[test]
+    fn to_iso8601_mixed_signs() {
+        let iso = |period: Period| period.to_iso8601();
+        assert_eq!(Some("PT45M".to_string()), iso(Period::default() + PeriodComp::hours(1) + PeriodComp::minutes(-15)));
+        assert_eq!(Some("-PT45M".to_string()), iso(Period::default() + PeriodComp::hours(-1) + PeriodComp::minutes(15)));
+        assert_eq!(Some("PT22H".to_string()), iso(Period::default() + PeriodComp::days(1) + PeriodComp::hours(-2)));
+        assert_eq!(Some("P10M".to_string()), iso(Period::default() + PeriodComp::years(1) + PeriodComp::months(-2)));
+        assert_eq!(Some("P1MT1H".to_string()), iso(Period::default() + PeriodComp::months(1) + PeriodComp::hours(2)
+            + PeriodComp::minutes(-60)));
+        assert_eq!(None, iso(Period::default() + PeriodComp::months(1) + PeriodComp::days(-1)));
+    }
 }
@@ -15,7 +15,7 @@
 //!     let result = parser.parse("twenty-one", &ctx).unwrap();
 //!
 //!     let int: output::IntegerOutput= result[0].value.clone().attempt_into().unwrap();
-//!     assert_eq!(21, int.0);
+//!     assert_eq!(21, int.value);
 //! }
 //! ```
 extern crate rmp_serde;
@@ -30,27 +30,52 @@ extern crate rustling_ontology_values;
 
 pub use rustling::{AttemptInto, ParsedNode, ParserMatch, Range, Value, Sym, ParsingAnalysis};
 pub use rustling::RustlingResult;
-pub use grammar::{Lang, dims};
+pub use grammar::{Lang, PortugueseVariant, dims};
 pub use rustling_ontology_values::dimension;
 pub use rustling_ontology_values::output;
 pub use rustling_ontology_values::output::{Output, OutputKind};
 pub use rustling_ontology_values::{ResolverContext, IdentityContext, ParsingContext};
+pub use rustling_ontology_values::helpers::SeparatorPolicy;
 pub use rustling_ontology_moment::{Interval, Moment, Local, TimeZone};
 pub use rustling_ontology_moment::Grain;
+pub use rustling_ontology_moment::{BusinessCalendar, Hemisphere, NaiveDate, Season, SeasonCalendar};
 
+mod normalization;
 mod parser;
 mod tagger;
 
+pub use normalization::NormalizedText;
 pub use tagger::CandidateTagger;
 
 // Rustling raw parser. Don't use directly
 #[doc(hidden)]
 pub type RawParser = rustling::Parser<dimension::Dimension, parser::Feat, parser::FeatureExtractor>;
 
+/// Kind of text given to the parser.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParserMode {
+    /// Written text
+    Text,
+    /// Speech recognition output: numbers and times are spelled out as spoken ("oh five
+    /// hundred", "nine thirty", "twenty twenty") and may contain filler words ("uh", "euh").
+    /// Only English and French have rules for these spoken forms.
+    Asr,
+}
+
 /// Main class to be use at runtime.
-pub struct Parser(RawParser);
+pub struct Parser {
+    raw: RawParser,
+    lang: Lang,
+    mode: ParserMode,
+}
 
 impl Parser {
+    pub fn mode(&self) -> ParserMode {
+        self.mode
+    }
+
+    /// Parses raw text: the input is normalized for the language of the parser and the ranges
+    /// of the matches refer to the input as given.
     pub fn parse_with_kind_order(&self,
                                  input: &str,
                                  context: &ResolverContext,
@@ -61,13 +86,18 @@ impl Parser {
             context: context,
             resolve_all_candidates: false,
         };
-        Ok(self.0.parse(input, &tagger)?
+        let normalized = NormalizedText::new(self.lang, input);
+        Ok(self.raw.parse(&normalized.text, &tagger)?
             .into_iter()
             .filter_map(|m| {
-                if let Some(v) = m.value {
+                if let Some(mut v) = m.value {
+                    if let Output::Quantity(ref mut quantity) = v {
+                        quantity.product = quantity.product.map(|product| normalized.original_byte_range(product));
+                    }
+                    let byte_range = normalized.original_byte_range(m.byte_range);
                     Some(ParserMatch {
-                        byte_range: m.byte_range,
-                        char_range: m.char_range,
+                        byte_range: byte_range,
+                        char_range: normalization::char_range(input, byte_range),
                         parsing_tree_height: m.parsing_tree_height,
                         parsing_tree_num_nodes: m.parsing_tree_num_nodes,
                         value: v,
@@ -98,7 +128,10 @@ impl Parser {
             context: context,
             resolve_all_candidates: false,
         };
-        self.0.analyse(examples, &tagger)
+        let normalized = examples.iter()
+            .map(|example| NormalizedText::new(self.lang, example).text)
+            .collect::<Vec<_>>();
+        self.raw.analyse(normalized.iter().map(|example| example.as_str()).collect(), &tagger)
     }
 
     pub fn analyse(&self, examples: Vec<&str>, context: &ResolverContext) -> RustlingResult<ParsingAnalysis> {
@@ -107,24 +140,63 @@ impl Parser {
     }
 
     pub fn num_rules(&self) -> usize {
-        self.0.num_rules()
+        self.raw.num_rules()
     }
 
     pub fn num_text_patterns(&self) -> usize {
-        self.0.num_text_patterns()
+        self.raw.num_text_patterns()
     }
 }
 
 /// Obtain a parser for a given language.
 pub fn build_parser(lang: Lang) -> RustlingResult<Parser> {
-    build_raw_parser(lang).map(::Parser)
+    build_raw_parser(lang).map(|raw| Parser { raw, lang, mode: ParserMode::Text })
+}
+
+/// Obtain a parser for a given language and kind of text.
+pub fn build_parser_with_mode(lang: Lang, mode: ParserMode) -> RustlingResult<Parser> {
+    build_raw_parser_with_mode(lang, mode).map(|raw| Parser { raw, lang, mode })
 }
 
 
 
+/// Obtain a parser for a given language, reading digits with the given separator policies,
+/// e.g. `SeparatorPolicy::Swiss` for "1'000.50".
+pub fn build_parser_with_separators(lang: Lang, separators: &[SeparatorPolicy]) -> RustlingResult<Parser> {
+    build_raw_parser_with_separators(lang, separators).map(|raw| Parser { raw, lang, mode: ParserMode::Text })
+}
+
+/// Obtain a Portuguese parser reading only the Brazilian or European wordings, e.g. "bilhão"
+/// is 10^9 in Brazil and "bilião" 10^12 in Portugal.
+pub fn build_parser_with_pt_variant(variant: PortugueseVariant) -> RustlingResult<Parser> {
+    build_raw_parser_with_pt_variant(variant).map(|raw| Parser { raw, lang: Lang::PT, mode: ParserMode::Text })
+}
+
 /// Obtain a parser for a given language.
 pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
-    let rules = grammar::rules(lang)?;
+    build_raw_parser_with_separators(lang, &grammar::separators(lang))
+}
+
+/// Obtain a parser for a given language and kind of text.
+pub fn build_raw_parser_with_mode(lang: Lang, mode: ParserMode) -> RustlingResult<RawParser> {
+    let rules = grammar::rules_with_options(lang, &grammar::separators(lang), mode == ParserMode::Asr)?;
+    with_model(lang, rules)
+}
+
+/// Obtain a parser for a given language, reading digits with the given separator policies.
+pub fn build_raw_parser_with_separators(lang: Lang, separators: &[SeparatorPolicy]) -> RustlingResult<RawParser> {
+    let rules = grammar::rules_with_separators(lang, separators)?;
+    with_model(lang, rules)
+}
+
+/// Obtain a Portuguese parser reading only the Brazilian or European wordings.
+pub fn build_raw_parser_with_pt_variant(variant: PortugueseVariant) -> RustlingResult<RawParser> {
+    let rules = grammar::rules_pt_variant(&grammar::separators(Lang::PT), variant)?;
+    with_model(Lang::PT, rules)
+}
+
+/// Parser using the bundled model, trained with the rules of all modes of the language.
+fn with_model(lang: Lang, rules: ::rustling::RuleSet<dimension::Dimension>) -> RustlingResult<RawParser> {
     let model = match lang {
         Lang::DE => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/de.rmp"))[..]) },
         Lang::EN => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp"))[..]) },
@@ -140,11 +212,18 @@ pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
 }
 
 
+/// Parser for plain text with a model trained from the rules of all modes of the language, as
+/// the bundled model is.
 pub fn train_parser(lang: Lang) -> RustlingResult<Parser> {
-    let rules = grammar::rules(lang)?;
+    let rules = grammar::rules_with_options(lang, &grammar::separators(lang), true)?;
     let examples = grammar::examples(lang);
     let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor())?;
-    Ok(Parser(::rustling::Parser::new(rules, model, ::parser::FeatureExtractor())))
+    let rules = grammar::rules_with_separators(lang, &grammar::separators(lang))?;
+    Ok(Parser {
+        raw: ::rustling::Parser::new(rules, model, ::parser::FeatureExtractor()),
+        lang,
+        mode: ParserMode::Text,
+    })
 }
 
 #[cfg(test)]
@@ -158,7 +237,127 @@ mod tests {
         let number = "one million five hundred twenty-one thousand eighty-two";
         let result = parser.parse_with_kind_order(number, &ctx,  &[OutputKind::Number]).unwrap();
         let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
-        assert_eq!(1521082, int.0);
+        assert_eq!(1521082, int.value);
+    }
+
+    #[test]
+    fn test_swiss_separators_fr() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser_with_separators(Lang::FR, &[SeparatorPolicy::Swiss]).unwrap();
+        let result = parser.parse_with_kind_order("1'000.50", &ctx, &[OutputKind::Number]).unwrap();
+        let float: output::FloatOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(1000.5, float.value);
+    }
+
+    fn assert_same_matches(expected: &Parser, parser: &Parser, sentences: &[&str]) {
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        for sentence in sentences {
+            let expected = expected.parse(sentence, &ctx).unwrap();
+            let result = parser.parse(sentence, &ctx).unwrap();
+            assert_eq!(expected.len(), result.len(), "{:?}", sentence);
+            for (expected, result) in expected.iter().zip(result.iter()) {
+                assert_eq!(expected.byte_range, result.byte_range, "{:?}", sentence);
+                assert_eq!(expected.value, result.value, "{:?}", sentence);
+                assert_eq!(expected.probalog, result.probalog, "{:?}", sentence);
+            }
+        }
+    }
+
+    #[test]
+    fn test_separators_keep_the_model_features() {
+        let default = build_parser(Lang::EN).unwrap();
+        let mut separators = grammar::separators(Lang::EN);
+        separators.push(SeparatorPolicy::Swiss);
+        let parser = build_parser_with_separators(Lang::EN, &separators).unwrap();
+        assert_same_matches(&default, &parser, &[
+            "from 3 to 5 pm tomorrow",
+            "book a table for 4 people at 8 on march the 3rd",
+            "it costs 1,500.50 dollars and weighs 2.5 kg",
+        ]);
+    }
+
+    #[test]
+    fn test_number_intervals_give_way_to_times() {
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        for &(lang, sentence) in &[(Lang::EN, "from 3 to 5"), (Lang::FR, "de 3 à 5")] {
+            let parser = build_parser(lang).unwrap();
+            let result = parser.parse(sentence, &ctx).unwrap();
+            assert_eq!(Range(0, sentence.len()), result[0].byte_range, "{:?}", sentence);
+            assert_eq!(OutputKind::Time, result[0].value.kind(), "{:?}", sentence);
+            let intervals = parser.parse_with_kind_order(sentence, &ctx, &[OutputKind::NumberInterval]).unwrap();
+            assert!(intervals.iter().all(|m| m.latent), "{:?}", sentence);
+        }
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse_with_kind_order("between 20 and 30 dollars", &ctx, &[OutputKind::AmountOfMoneyInterval]).unwrap();
+        assert!(!result[0].latent);
+    }
+
+    #[test]
+    fn test_parse_raw_text() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let input = "Ｐａｙ ＴＷＥＮＴＹ-ONE dollars";
+        let result = parser.parse_with_kind_order(input, &ctx, &[OutputKind::AmountOfMoney]).unwrap();
+        let range = result[0].byte_range;
+        assert_eq!("ＴＷＥＮＴＹ-ONE dollars", &input[range.0..range.1]);
+        assert_eq!(Range(4, 22), result[0].char_range);
+    }
+
+    #[test]
+    fn test_quantity_product_range() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let input = "Buy 2 liters of ＭＩＬＫ";
+        let result = parser.parse_with_kind_order(input, &ctx, &[OutputKind::Quantity]).unwrap();
+        let quantity: output::QuantityOutput = result[0].value.clone().attempt_into().unwrap();
+        let product = quantity.product.unwrap();
+        assert_eq!("ＭＩＬＫ", &input[product.0..product.1]);
+    }
+
+    #[test]
+    fn test_asr_mode_en() {
+        let ctx = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+        let parser = build_parser_with_mode(Lang::EN, ParserMode::Asr).unwrap();
+        assert_eq!(ParserMode::Asr, parser.mode());
+        let result = parser.parse_with_kind_order("wake me at oh five hundred", &ctx, &[OutputKind::Time]).unwrap();
+        let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(5, 0, 0)), time.moment);
+        let result = parser.parse_with_kind_order("uh three", &ctx, &[OutputKind::Number]).unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(3, int.value);
+        assert_eq!(Range(0, 8), result[0].byte_range);
+    }
+
+    #[test]
+    fn test_pt_variants() {
+        let ctx = ResolverContext::default();
+        let brazilian = build_parser_with_pt_variant(PortugueseVariant::Brazilian).unwrap();
+        let result = brazilian.parse_with_kind_order("dois bilhões", &ctx, &[OutputKind::Number]).unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(2_000_000_000, int.value);
+        let european = build_parser_with_pt_variant(PortugueseVariant::European).unwrap();
+        let result = european.parse_with_kind_order("dois biliões", &ctx, &[OutputKind::Number]).unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(2_000_000_000_000, int.value);
+        let result = european.parse_with_kind_order("dezasseis", &ctx, &[OutputKind::Number]).unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(16, int.value);
+        assert!(brazilian.parse_with_kind_order("dezasseis", &ctx, &[OutputKind::Number]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pt_variants_keep_the_model_features() {
+        let default = build_parser(Lang::PT).unwrap();
+        let sentences = [
+            "amanhã às 5 da tarde",
+            "de 3 a 5 de março",
+            "na próxima segunda às 10h30",
+            "daqui a três horas por 20 euros",
+        ];
+        for &variant in &[PortugueseVariant::Brazilian, PortugueseVariant::European] {
+            let parser = build_parser_with_pt_variant(variant).unwrap();
+            assert_same_matches(&default, &parser, &sentences);
+        }
     }
 
     #[test]
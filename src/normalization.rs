@@ -0,0 +1,134 @@
+//! Normalization of the raw input before parsing.
+//!
+//! Grammars are written for lowercased text with ASCII digits, quotes and spaces. The
+//! normalized text keeps, for each of its bytes, the byte range of the original character it
+//! comes from so that the ranges of the matches can be mapped back to the raw input.
+
+use grammar::Lang;
+use rustling::Range;
+
+#[derive(Debug, Clone)]
+pub struct NormalizedText {
+    pub text: String,
+    origins: Vec<(usize, usize)>,
+}
+
+impl NormalizedText {
+    /// Lowercases the input and replaces full-width forms, typographic quotes and non-breaking
+    /// spaces by their ASCII counterparts. Latin script languages also compose combining
+    /// accents with the letter they follow.
+    pub fn new(lang: Lang, original: &str) -> NormalizedText {
+        let compose_accents = ![Lang::JA, Lang::KO, Lang::ZH].contains(&lang);
+        let mut text = String::with_capacity(original.len());
+        let mut origins = Vec::with_capacity(original.len());
+        let mut chars = original.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let mut end = start + c.len_utf8();
+            let mut normalized: String = normalize_char(c).to_lowercase().collect();
+            if compose_accents && normalized.chars().count() == 1 {
+                if let Some(&(mark_start, mark)) = chars.peek() {
+                    if let Some(composed) = compose(normalized.chars().next().unwrap(), mark) {
+                        chars.next();
+                        end = mark_start + mark.len_utf8();
+                        normalized = composed.to_string();
+                    }
+                }
+            }
+            for _ in 0..normalized.len() {
+                origins.push((start, end));
+            }
+            text.push_str(&normalized);
+        }
+        NormalizedText { text, origins }
+    }
+
+    /// Byte range in the original input of a byte range of the normalized text
+    pub fn original_byte_range(&self, range: Range) -> Range {
+        let start = self.origins.get(range.0).map(|o| o.0).unwrap_or_else(|| self.original_len());
+        if range.1 <= range.0 {
+            return Range(start, start);
+        }
+        Range(start, self.origins[range.1 - 1].1)
+    }
+
+    fn original_len(&self) -> usize {
+        self.origins.last().map(|o| o.1).unwrap_or(0)
+    }
+}
+
+/// Char range of a byte range of a text
+pub fn char_range(text: &str, byte_range: Range) -> Range {
+    Range(text[..byte_range.0].chars().count(), text[..byte_range.1].chars().count())
+}
+
+fn normalize_char(c: char) -> char {
+    match c {
+        '\u{ff01}'..='\u{ff5e}' => ::std::char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        '\u{a0}' | '\u{2007}' | '\u{202f}' | '\u{3000}' => ' ',
+        '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{2032}' => '\'',
+        '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{2033}' => '"',
+        _ => c,
+    }
+}
+
+fn compose(base: char, mark: char) -> Option<char> {
+    let composed = match (base, mark) {
+        ('a', '\u{300}') => 'à',
+        ('e', '\u{300}') => 'è',
+        ('i', '\u{300}') => 'ì',
+        ('o', '\u{300}') => 'ò',
+        ('u', '\u{300}') => 'ù',
+        ('a', '\u{301}') => 'á',
+        ('e', '\u{301}') => 'é',
+        ('i', '\u{301}') => 'í',
+        ('o', '\u{301}') => 'ó',
+        ('u', '\u{301}') => 'ú',
+        ('y', '\u{301}') => 'ý',
+        ('a', '\u{302}') => 'â',
+        ('e', '\u{302}') => 'ê',
+        ('i', '\u{302}') => 'î',
+        ('o', '\u{302}') => 'ô',
+        ('u', '\u{302}') => 'û',
+        ('a', '\u{303}') => 'ã',
+        ('n', '\u{303}') => 'ñ',
+        ('o', '\u{303}') => 'õ',
+        ('a', '\u{308}') => 'ä',
+        ('e', '\u{308}') => 'ë',
+        ('i', '\u{308}') => 'ï',
+        ('o', '\u{308}') => 'ö',
+        ('u', '\u{308}') => 'ü',
+        ('y', '\u{308}') => 'ÿ',
+        ('a', '\u{30a}') => 'å',
+        ('c', '\u{327}') => 'ç',
+        _ => return None,
+    };
+    Some(composed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let original = "Le 1\u{a0}000 Ｅｕｒｏｓ du Cafe\u{301} d\u{2019}Ici";
+        let normalized = NormalizedText::new(Lang::FR, original);
+        assert_eq!("le 1 000 euros du café d'ici", normalized.text);
+        let start = normalized.text.find("euros").unwrap();
+        let range = normalized.original_byte_range(Range(start, start + "euros".len()));
+        assert_eq!("Ｅｕｒｏｓ", &original[range.0..range.1]);
+        let start = normalized.text.find("café").unwrap();
+        let range = normalized.original_byte_range(Range(start, start + "café".len()));
+        assert_eq!("Cafe\u{301}", &original[range.0..range.1]);
+        assert_eq!(Range(18, 23), char_range(original, range));
+    }
+
+    #[test]
+    fn test_normalize_length_change() {
+        let original = "İ 3";
+        let normalized = NormalizedText::new(Lang::EN, original);
+        let start = normalized.text.find('3').unwrap();
+        let range = normalized.original_byte_range(Range(start, start + 1));
+        assert_eq!("3", &original[range.0..range.1]);
+    }
+}
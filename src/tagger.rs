@@ -1,7 +1,7 @@
 use std::cmp::{PartialOrd, Ordering};
 use rustling::{ParserMatch, ParsedNode, Candidate, MaxElementTagger, Value, Range};
 use rustling_ontology_values::ParsingContext;
-use rustling_ontology_values::dimension::{Dimension};
+use rustling_ontology_values::dimension::{Dimension, DimensionKind, Form};
 use rustling_ontology_values::output::OutputKind;
 
 pub struct CandidateTagger<'a, C: ParsingContext<Dimension> + 'a> {
@@ -30,6 +30,20 @@ impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for Candidate
             })
             .collect::<Vec<_>>();
 
+        // A latent ratio like "2:30" gives way to the time of day read from the same span.
+        let time_of_day_ranges = candidates.iter()
+            .filter(|c| is_time_of_day(&c.1.value))
+            .map(|c| c.1.byte_range)
+            .collect::<Vec<_>>();
+        candidates.retain(|c| !is_latent_ratio(&c.1.value) || !time_of_day_ranges.contains(&c.1.byte_range));
+
+        // The number read from "0612" would lose its leading zero, the digit sequence wins.
+        let zero_led_sequence_ranges = candidates.iter()
+            .filter(|c| is_zero_led_digit_sequence(&c.1.value))
+            .map(|c| c.1.byte_range)
+            .collect::<Vec<_>>();
+        candidates.retain(|c| c.1.value.kind() != DimensionKind::Number || !zero_led_sequence_ranges.contains(&c.1.byte_range));
+
         candidates.sort_by(|a, b|{
             a.1.byte_range.len().cmp(&b.1.byte_range.len())
                 .then_with(|| {
@@ -99,4 +113,28 @@ impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for Candidate
         })
         .collect()
     }
-}
\ No newline at end of file
+}
+
+fn is_time_of_day(value: &Dimension) -> bool {
+    match value {
+        &Dimension::Time(ref tv) => match tv.form {
+            Form::TimeOfDay(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_latent_ratio(value: &Dimension) -> bool {
+    match value {
+        &Dimension::Ratio(ref ratio) => ratio.latent,
+        _ => false,
+    }
+}
+
+fn is_zero_led_digit_sequence(value: &Dimension) -> bool {
+    match value {
+        &Dimension::DigitSequence(ref sequence) => sequence.has_leading_zero(),
+        _ => false,
+    }
+}
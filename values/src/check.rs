@@ -1,4 +1,4 @@
-use rustling::{AttemptFrom, Check, ParsedNode};
+use rustling::{AttemptFrom, Check, ParsedNode, Range};
 use moment::{Grain, Interval, Moment, Local, Period};
 use dimension::*;
 use output::*;
@@ -21,6 +21,42 @@ pub fn check_integer(v: i64) -> CheckInteger {
     CheckInteger { value: v }
 }
 
+#[derive(Debug)]
+pub struct CheckIntegerWithPrecision {
+    pub value: i64,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckIntegerWithPrecision {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        IntegerValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.precision == self.precision)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_integer_with_precision(v: i64, precision: Precision) -> CheckIntegerWithPrecision {
+    CheckIntegerWithPrecision { value: v, precision: precision }
+}
+
+#[derive(Debug)]
+pub struct CheckIntegerWithCounter {
+    pub value: i64,
+    pub counter: CounterClass,
+}
+
+impl Check<Dimension> for CheckIntegerWithCounter {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        IntegerValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.counter == Some(self.counter))
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_integer_with_counter(v: i64, counter: CounterClass) -> CheckIntegerWithCounter {
+    CheckIntegerWithCounter { value: v, counter: counter }
+}
+
 #[derive(Debug)]
 pub struct CheckOrdinal {
     pub value: i64,
@@ -40,7 +76,7 @@ pub fn check_ordinal(v: i64) -> CheckOrdinal {
 
 #[derive(Debug)]
 pub struct CheckFloat {
-    pub value: f32,
+    pub value: f64,
 }
 
 impl Check<Dimension> for CheckFloat {
@@ -51,10 +87,28 @@ impl Check<Dimension> for CheckFloat {
     }
 }
 
-pub fn check_float(v: f32) -> CheckFloat {
+pub fn check_float(v: f64) -> CheckFloat {
     CheckFloat { value: v }
 }
 
+#[derive(Debug)]
+pub struct CheckFraction {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Check<Dimension> for CheckFraction {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        FloatValue::attempt_from(pn.value.clone())
+            .map(|v| v.fraction == Some(Fraction { numerator: self.numerator, denominator: self.denominator }))
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_fraction(numerator: i64, denominator: i64) -> CheckFraction {
+    CheckFraction { numerator, denominator }
+}
+
 #[derive(Debug)]
 pub struct CheckDuration {
     pub period: Period,
@@ -166,7 +220,7 @@ pub fn check_moment_span(context: ResolverContext, precision: Precision, start:
 
 #[derive(Debug)]
 pub struct CheckFinance {
-    pub value: f32,
+    pub value: f64,
     pub unit: Option<&'static str>,
     pub precision: Precision,
 }
@@ -174,12 +228,12 @@ pub struct CheckFinance {
 impl Check<Dimension> for CheckFinance {
     fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
         AmountOfMoneyValue::attempt_from(pn.value.clone())
-            .map(|v| v.value == self.value && v.precision == self.precision && v.unit == self.unit)
+            .map(|v| v.value == self.value && v.precision == self.precision && v.unit.map(|u| u.name()) == self.unit)
             .unwrap_or(false)
     }
 }
 
-pub fn check_finance(value: f32, unit: Option<&'static str>, precision: Precision) -> CheckFinance {
+pub fn check_finance(value: f64, unit: Option<&'static str>, precision: Precision) -> CheckFinance {
     CheckFinance {
         value: value,
         precision: precision,
@@ -189,7 +243,7 @@ pub fn check_finance(value: f32, unit: Option<&'static str>, precision: Precisio
 
 #[derive(Debug)]
 pub struct CheckPercentage {
-    pub value: f32,
+    pub value: f64,
 }
 
 impl Check<Dimension> for CheckPercentage {
@@ -200,7 +254,7 @@ impl Check<Dimension> for CheckPercentage {
     }
 }
 
-pub fn check_percentage(value: f32) -> CheckPercentage {
+pub fn check_percentage(value: f64) -> CheckPercentage {
     CheckPercentage {
         value: value,
     }
@@ -208,22 +262,242 @@ pub fn check_percentage(value: f32) -> CheckPercentage {
 
 #[derive(Debug)]
 pub struct CheckTemperature {
-    pub value: f32,
+    pub value: f64,
     pub unit: Option<&'static str>,
+    pub precision: Precision,
 }
 
 impl Check<Dimension> for CheckTemperature {
     fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
         TemperatureValue::attempt_from(pn.value.clone())
-            .map(|v| v.value == self.value && v.unit == self.unit)
+            .map(|v| v.value == self.value && v.unit == self.unit && v.precision == self.precision)
             .unwrap_or(false)
     }
 }
 
-pub fn check_temperature(value: f32, unit: Option<&'static str>) -> CheckTemperature {
+pub fn check_temperature(value: f64, unit: Option<&'static str>) -> CheckTemperature {
+    check_temperature_with_precision(value, unit, Precision::Exact)
+}
+
+pub fn check_temperature_with_precision(value: f64, unit: Option<&'static str>, precision: Precision) -> CheckTemperature {
     CheckTemperature {
         value: value,
         unit: unit,
+        precision: precision,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckDistance {
+    pub value: f64,
+    pub unit: DistanceUnit,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckDistance {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DistanceValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.unit == self.unit && v.precision == self.precision)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_distance(value: f64, unit: DistanceUnit, precision: Precision) -> CheckDistance {
+    CheckDistance {
+        value: value,
+        unit: unit,
+        precision: precision,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckQuantity {
+    pub value: f64,
+    pub unit: QuantityUnit,
+    pub product: Option<Range>,
+}
+
+impl Check<Dimension> for CheckQuantity {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        QuantityValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.unit == self.unit && v.product == self.product)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_quantity(value: f64, unit: QuantityUnit, product: Option<Range>) -> CheckQuantity {
+    CheckQuantity {
+        value: value,
+        unit: unit,
+        product: product,
     }
 }
 
+#[derive(Debug)]
+pub struct CheckRate {
+    pub quantity: CheckRateQuantity,
+    pub per: Grain,
+}
+
+/// What is counted per unit of time in a checked rate
+#[derive(Debug)]
+pub enum CheckRateQuantity {
+    Number(f64),
+    AmountOfMoney(f64, Option<&'static str>),
+    Distance(f64, DistanceUnit),
+}
+
+impl Check<Dimension> for CheckRate {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        RateValue::attempt_from(pn.value.clone())
+            .map(|v| {
+                let quantity = match (&v.quantity, &self.quantity) {
+                    (&RateQuantity::Number(ref number), &CheckRateQuantity::Number(value)) => number.value() == value,
+                    (&RateQuantity::AmountOfMoney(ref amount), &CheckRateQuantity::AmountOfMoney(value, unit)) => {
+                        amount.value == value && amount.unit.map(|u| u.name()) == unit
+                    }
+                    (&RateQuantity::Distance(ref distance), &CheckRateQuantity::Distance(value, unit)) => {
+                        distance.value == value && distance.unit == unit
+                    }
+                    _ => false,
+                };
+                quantity && v.per == self.per
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_rate(value: f64, per: Grain) -> CheckRate {
+    CheckRate {
+        quantity: CheckRateQuantity::Number(value),
+        per: per,
+    }
+}
+
+pub fn check_finance_rate(value: f64, unit: Option<&'static str>, per: Grain) -> CheckRate {
+    CheckRate {
+        quantity: CheckRateQuantity::AmountOfMoney(value, unit),
+        per: per,
+    }
+}
+
+pub fn check_distance_rate(value: f64, unit: DistanceUnit, per: Grain) -> CheckRate {
+    CheckRate {
+        quantity: CheckRateQuantity::Distance(value, unit),
+        per: per,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckRatio {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Check<Dimension> for CheckRatio {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        RatioValue::attempt_from(pn.value.clone())
+            .map(|v| v.numerator == self.numerator && v.denominator == self.denominator)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_ratio(numerator: i64, denominator: i64) -> CheckRatio {
+    CheckRatio {
+        numerator: numerator,
+        denominator: denominator,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckDigitSequence {
+    pub digits: &'static str,
+}
+
+impl Check<Dimension> for CheckDigitSequence {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DigitSequenceValue::attempt_from(pn.value.clone())
+            .map(|v| v.digits == self.digits)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_digit_sequence(digits: &'static str) -> CheckDigitSequence {
+    CheckDigitSequence {
+        digits: digits,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckNumberInterval {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+}
+
+impl Check<Dimension> for CheckNumberInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        NumberIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.from == self.from && v.to == self.to)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_number_interval(from: Option<IntervalBound<f64>>, to: Option<IntervalBound<f64>>) -> CheckNumberInterval {
+    CheckNumberInterval { from, to }
+}
+
+#[derive(Debug)]
+pub struct CheckFinanceInterval {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+    pub unit: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckFinanceInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        AmountOfMoneyIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.from == self.from && v.to == self.to && v.unit.map(|u| u.name()) == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_finance_interval(from: Option<IntervalBound<f64>>, to: Option<IntervalBound<f64>>, unit: Option<&'static str>) -> CheckFinanceInterval {
+    CheckFinanceInterval { from, to, unit }
+}
+
+#[derive(Debug)]
+pub struct CheckTemperatureInterval {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+    pub unit: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckTemperatureInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        TemperatureIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.from == self.from && v.to == self.to && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_temperature_interval(from: Option<IntervalBound<f64>>, to: Option<IntervalBound<f64>>, unit: Option<&'static str>) -> CheckTemperatureInterval {
+    CheckTemperatureInterval { from, to, unit }
+}
+
+#[derive(Debug)]
+pub struct CheckDurationInterval {
+    pub from: Option<IntervalBound<Period>>,
+    pub to: Option<IntervalBound<Period>>,
+}
+
+impl Check<Dimension> for CheckDurationInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DurationIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.from == self.from && v.to == self.to)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_duration_interval(from: Option<IntervalBound<Period>>, to: Option<IntervalBound<Period>>) -> CheckDurationInterval {
+    CheckDurationInterval { from, to }
+}
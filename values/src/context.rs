@@ -1,5 +1,6 @@
 use output::*;
 use dimension::*;
+use helpers;
 use rustling::Value;
 use moment::*;
 
@@ -27,9 +28,11 @@ impl<V: Value+Clone> ParsingContext<V> for IdentityContext<V> {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Clone)]
 pub struct ResolverContext {
     ctx: Context<Local>,
+    currency: Option<Currency>,
+    temperature_unit: Option<&'static str>,
 }
 
 impl ResolverContext { 
@@ -41,7 +44,69 @@ impl ResolverContext {
 
     pub fn new(now: Interval<Local>) -> ResolverContext {
         ResolverContext {
-           ctx: Context::for_reference(now) 
+           ctx: Context::for_reference(now),
+           currency: None,
+           temperature_unit: None,
+        }
+    }
+
+    pub fn with_business_calendar(self, calendar: BusinessCalendar) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_calendar(calendar),
+            ..self
+        }
+    }
+
+    pub fn with_hemisphere(self, hemisphere: Hemisphere) -> ResolverContext {
+        self.with_seasons(SeasonCalendar::for_hemisphere(hemisphere))
+    }
+
+    pub fn with_seasons(self, seasons: SeasonCalendar) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_seasons(seasons),
+            ..self
+        }
+    }
+
+    /// Takes the currency and temperature unit of the locale region, e.g. "en_CA" or "sv-SE",
+    /// to resolve shared currency symbols such as "$" or "kr" and fill the unit of amounts and
+    /// temperatures that leave it out
+    pub fn with_locale(self, locale: &str) -> ResolverContext {
+        let temperature_unit = helpers::locale_region(locale).map(|region| {
+            match region.as_ref() {
+                "US" | "BS" | "BZ" | "KY" | "LR" | "PW" => "fahrenheit",
+                _ => "celsius",
+            }
+        });
+        self.with_currency(Currency::for_locale(locale))
+            .with_temperature_unit(temperature_unit)
+    }
+
+    pub fn with_currency(self, currency: Option<Currency>) -> ResolverContext {
+        ResolverContext { currency, ..self }
+    }
+
+    /// "celsius" or "fahrenheit"
+    pub fn with_temperature_unit(self, temperature_unit: Option<&'static str>) -> ResolverContext {
+        ResolverContext { temperature_unit, ..self }
+    }
+
+    /// The unit and currency of an amount, and whether they come from the context
+    fn money_unit(&self, unit: Option<MoneyUnit>) -> (Option<MoneyUnit>, Option<Currency>, bool) {
+        match unit {
+            Some(unit) => (Some(unit), unit.currency(self.currency), false),
+            None => match self.currency {
+                Some(currency) => (Some(MoneyUnit::Currency(currency)), Some(currency), true),
+                None => (None, None, false),
+            },
+        }
+    }
+
+    /// The unit of a temperature, and whether it comes from the context
+    fn temperature_unit(&self, unit: Option<&'static str>) -> (Option<&'static str>, bool) {
+        match (unit, self.temperature_unit) {
+            (None, Some(default)) | (Some("degree"), Some(default)) => (Some(default), true),
+            _ => (unit, false),
         }
     }
 }
@@ -104,28 +169,139 @@ impl ParsingContext<Dimension> for ResolverContext {
             }
             &Dimension::Number(ref number) => {
                 match number {
-                    &NumberValue::Integer(ref v) => Some(Output::Integer(IntegerOutput(v.value))),
-                    &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput(v.value))),
+                    &NumberValue::Integer(ref v) => Some(Output::Integer(IntegerOutput {
+                        value: v.value,
+                        precision: v.precision,
+                        counter: v.counter,
+                    })),
+                    &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput {
+                        value: v.value,
+                        precision: v.precision,
+                        fraction: v.fraction,
+                    })),
                 }
             }
             &Dimension::Ordinal(ref ordinal) => Some(Output::Ordinal(OrdinalOutput(ordinal.value))),
-            &Dimension::AmountOfMoney(ref aom) => Some(Output::AmountOfMoney(AmountOfMoneyOutput {
-                value: aom.value,
-                precision: aom.precision,
-                unit: aom.unit,
+            &Dimension::AmountOfMoney(ref aom) => {
+                let (unit, currency, unit_inferred) = self.money_unit(aom.unit);
+                Some(Output::AmountOfMoney(AmountOfMoneyOutput {
+                    value: aom.value,
+                    precision: aom.precision,
+                    unit,
+                    currency,
+                    unit_inferred,
+                }))
+            }
+            &Dimension::Temperature(ref temp) => {
+                let (unit, unit_inferred) = self.temperature_unit(temp.unit);
+                Some(Output::Temperature(TemperatureOutput {
+                    value: temp.value,
+                    unit,
+                    precision: temp.precision,
+                    latent: temp.latent,
+                    unit_inferred,
+                }))
+            }
+            &Dimension::Distance(ref distance) => Some(Output::Distance(DistanceOutput {
+                value: distance.value,
+                unit: distance.unit,
+                precision: distance.precision,
+                latent: distance.latent,
+            })),
+            &Dimension::Quantity(ref quantity) => Some(Output::Quantity(QuantityOutput {
+                value: quantity.value,
+                unit: quantity.unit,
+                product: quantity.product,
             })),
-            &Dimension::Temperature(ref temp) => Some(Output::Temperature(TemperatureOutput {
-                value: temp.value,
-                unit: temp.unit,
-                latent: temp.latent,
+            &Dimension::Rate(ref rate) => {
+                let quantity = match self.resolve(&rate.quantity.clone().into())? {
+                    Output::Integer(v) => RateQuantityOutput::Integer(v),
+                    Output::Float(v) => RateQuantityOutput::Float(v),
+                    Output::AmountOfMoney(v) => RateQuantityOutput::AmountOfMoney(v),
+                    Output::Distance(v) => RateQuantityOutput::Distance(v),
+                    _ => return None,
+                };
+                Some(Output::Rate(RateOutput { quantity, per: rate.per }))
+            }
+            &Dimension::Ratio(ref ratio) => Some(Output::Ratio(RatioOutput {
+                numerator: ratio.numerator,
+                denominator: ratio.denominator,
+                latent: ratio.latent,
+            })),
+            &Dimension::DigitSequence(ref sequence) => Some(Output::DigitSequence(DigitSequenceOutput {
+                digits: sequence.digits.clone(),
+                latent: sequence.latent,
             })),
             &Dimension::Duration(ref duration) => Some(Output::Duration(DurationOutput {
                 period: duration.period.clone(),
                 precision: duration.precision,
             })),
             &Dimension::Percentage(ref percentage) => Some(Output::Percentage(PercentageOutput(percentage.0))),
+            &Dimension::NumberInterval(ref interval) => Some(Output::NumberInterval(NumberIntervalOutput {
+                from: interval.from,
+                to: interval.to,
+            })),
+            &Dimension::AmountOfMoneyInterval(ref interval) => {
+                let (unit, currency, unit_inferred) = self.money_unit(interval.unit);
+                Some(Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput {
+                    from: interval.from,
+                    to: interval.to,
+                    unit,
+                    currency,
+                    unit_inferred,
+                    precision: interval.precision,
+                }))
+            }
+            &Dimension::TemperatureInterval(ref interval) => {
+                let (unit, unit_inferred) = self.temperature_unit(interval.unit);
+                Some(Output::TemperatureInterval(TemperatureIntervalOutput {
+                    from: interval.from,
+                    to: interval.to,
+                    unit,
+                    unit_inferred,
+                }))
+            }
+            &Dimension::DurationInterval(ref interval) => Some(Output::DurationInterval(DurationIntervalOutput {
+                from: interval.from.clone(),
+                to: interval.to.clone(),
+                precision: interval.precision,
+            })),
             _ => None,
         }
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_defaults() {
+        let ctx = ResolverContext::default().with_locale("en_US");
+        let amount = AmountOfMoneyValue { value: 20.0, ..AmountOfMoneyValue::default() };
+        match ctx.resolve(&Dimension::AmountOfMoney(amount)) {
+            Some(Output::AmountOfMoney(output)) => {
+                assert_eq!(Some(Currency::USD), output.currency);
+                assert!(output.unit_inferred);
+            }
+            other => panic!("unexpected output {:?}", other),
+        }
+        let temperature = TemperatureValue { value: 21.0, unit: Some("degree"), precision: Precision::Exact, latent: false };
+        match ctx.resolve(&Dimension::Temperature(temperature)) {
+            Some(Output::Temperature(output)) => {
+                assert_eq!(Some("fahrenheit"), output.unit);
+                assert!(output.unit_inferred);
+            }
+            other => panic!("unexpected output {:?}", other),
+        }
+        let stated = TemperatureValue { value: 21.0, unit: Some("celsius"), precision: Precision::Exact, latent: false };
+        match ctx.resolve(&Dimension::Temperature(stated)) {
+            Some(Output::Temperature(output)) => {
+                assert_eq!(Some("celsius"), output.unit);
+                assert!(!output.unit_inferred);
+            }
+            other => panic!("unexpected output {:?}", other),
+        }
+    }
+}
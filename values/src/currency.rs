@@ -0,0 +1,199 @@
+//! Currencies of the AmountOfMoney dimension.
+//!
+//! Grammars read either a unit that names a single ISO 4217 currency ("USD", "euros", "€"), a
+//! symbol shared by several currencies ("$", "kr", "¥", "£", "฿") or a subunit ("cents"). Shared
+//! symbols are resolved against the locale of the `ResolverContext`.
+
+use helpers;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Currency {
+    AED,
+    AUD,
+    BRL,
+    CAD,
+    CHF,
+    CNY,
+    DKK,
+    EGP,
+    /// Spanish peseta, replaced by the euro
+    ESP,
+    EUR,
+    GBP,
+    HKD,
+    INR,
+    JPY,
+    KRW,
+    NOK,
+    NZD,
+    RUB,
+    SEK,
+    SGD,
+    THB,
+    USD,
+    /// Bitcoin, outside of ISO 4217 but commonly coded XBT
+    XBT,
+}
+
+impl Currency {
+    pub fn code(&self) -> &'static str {
+        match self {
+            &Currency::AED => "AED",
+            &Currency::AUD => "AUD",
+            &Currency::BRL => "BRL",
+            &Currency::CAD => "CAD",
+            &Currency::CHF => "CHF",
+            &Currency::CNY => "CNY",
+            &Currency::DKK => "DKK",
+            &Currency::EGP => "EGP",
+            &Currency::ESP => "ESP",
+            &Currency::EUR => "EUR",
+            &Currency::GBP => "GBP",
+            &Currency::HKD => "HKD",
+            &Currency::INR => "INR",
+            &Currency::JPY => "JPY",
+            &Currency::KRW => "KRW",
+            &Currency::NOK => "NOK",
+            &Currency::NZD => "NZD",
+            &Currency::RUB => "RUB",
+            &Currency::SEK => "SEK",
+            &Currency::SGD => "SGD",
+            &Currency::THB => "THB",
+            &Currency::USD => "USD",
+            &Currency::XBT => "XBT",
+        }
+    }
+
+    /// Number of decimal digits of the minor unit, 2 for cents, 0 for currencies without subunit
+    pub fn minor_unit_exponent(&self) -> u32 {
+        match self {
+            &Currency::ESP | &Currency::JPY | &Currency::KRW => 0,
+            &Currency::XBT => 8,
+            _ => 2,
+        }
+    }
+
+    /// Currency of the region of a locale such as "en_US", "fr-CA" or "sv_SE"
+    pub fn for_locale(locale: &str) -> Option<Currency> {
+        match helpers::locale_region(locale)?.as_ref() {
+            "AE" => Some(Currency::AED),
+            "AU" => Some(Currency::AUD),
+            "BR" => Some(Currency::BRL),
+            "CA" => Some(Currency::CAD),
+            "CH" | "LI" => Some(Currency::CHF),
+            "CN" => Some(Currency::CNY),
+            "DK" => Some(Currency::DKK),
+            "EG" => Some(Currency::EGP),
+            "AT" | "BE" | "DE" | "ES" | "FI" | "FR" | "GR" | "IE" | "IT" | "LU" | "NL" | "PT" => Some(Currency::EUR),
+            "GB" => Some(Currency::GBP),
+            "HK" => Some(Currency::HKD),
+            "IN" => Some(Currency::INR),
+            "JP" => Some(Currency::JPY),
+            "KR" => Some(Currency::KRW),
+            "NO" => Some(Currency::NOK),
+            "NZ" => Some(Currency::NZD),
+            "RU" => Some(Currency::RUB),
+            "SE" => Some(Currency::SEK),
+            "SG" => Some(Currency::SGD),
+            "TH" => Some(Currency::THB),
+            "US" => Some(Currency::USD),
+            _ => None,
+        }
+    }
+}
+
+/// Symbols and words standing for several currencies
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum CurrencySymbol {
+    /// "$", "dollars"
+    Dollar,
+    /// "£", "pounds"
+    Pound,
+    /// "kr", "crowns", "kroner"
+    Krone,
+    /// "¥", "yen", "yuan"
+    Yen,
+    /// "฿", the baht sign also used for bitcoins
+    Baht,
+}
+
+impl CurrencySymbol {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            &CurrencySymbol::Dollar => "$",
+            &CurrencySymbol::Pound => "£",
+            &CurrencySymbol::Krone => "kr",
+            &CurrencySymbol::Yen => "¥",
+            &CurrencySymbol::Baht => "฿",
+        }
+    }
+
+    /// The currencies the symbol can stand for
+    pub fn candidates(&self) -> &'static [Currency] {
+        match self {
+            &CurrencySymbol::Dollar => &[Currency::USD, Currency::CAD, Currency::AUD, Currency::HKD, Currency::NZD, Currency::SGD],
+            &CurrencySymbol::Pound => &[Currency::GBP, Currency::EGP],
+            &CurrencySymbol::Krone => &[Currency::SEK, Currency::NOK, Currency::DKK],
+            &CurrencySymbol::Yen => &[Currency::JPY, Currency::CNY],
+            &CurrencySymbol::Baht => &[Currency::THB, Currency::XBT],
+        }
+    }
+}
+
+/// Unit of an amount of money as read in the sentence
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum MoneyUnit {
+    Currency(Currency),
+    Symbol(CurrencySymbol),
+    /// Hundredth of the main unit: "cents", "centimes", "pennies"
+    Cent,
+}
+
+impl MoneyUnit {
+    /// ISO code of a currency, the symbol of a shared symbol, "cent" for subunits
+    pub fn name(&self) -> &'static str {
+        match self {
+            &MoneyUnit::Currency(ref currency) => currency.code(),
+            &MoneyUnit::Symbol(ref symbol) => symbol.symbol(),
+            &MoneyUnit::Cent => "cent",
+        }
+    }
+
+    /// The currency of the unit, using the locale currency to pick among the candidates of a
+    /// shared symbol
+    pub fn currency(&self, locale_currency: Option<Currency>) -> Option<Currency> {
+        match self {
+            &MoneyUnit::Currency(currency) => Some(currency),
+            &MoneyUnit::Symbol(ref symbol) => locale_currency.filter(|c| symbol.candidates().contains(c)),
+            &MoneyUnit::Cent => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_locale() {
+        assert_eq!(Some(Currency::USD), Currency::for_locale("en_US"));
+        assert_eq!(Some(Currency::CAD), Currency::for_locale("fr-CA"));
+        assert_eq!(Some(Currency::EUR), Currency::for_locale("de_DE"));
+        assert_eq!(Some(Currency::BRL), Currency::for_locale("pt_BR"));
+        assert_eq!(Some(Currency::GBP), Currency::for_locale("en_UK"));
+        assert_eq!(None, Currency::for_locale("fr"));
+        assert_eq!(None, Currency::for_locale("xx_ZZ"));
+    }
+
+    #[test]
+    fn test_symbol_resolution() {
+        let dollar = MoneyUnit::Symbol(CurrencySymbol::Dollar);
+        assert_eq!(Some(Currency::CAD), dollar.currency(Some(Currency::CAD)));
+        assert_eq!(None, dollar.currency(Some(Currency::EUR)));
+        assert_eq!(None, dollar.currency(None));
+        assert_eq!(Some(Currency::EUR), MoneyUnit::Currency(Currency::EUR).currency(Some(Currency::USD)));
+        let baht = MoneyUnit::Symbol(CurrencySymbol::Baht);
+        assert_eq!(Some(Currency::THB), baht.currency(Currency::for_locale("th_TH")));
+        assert_eq!(None, baht.currency(Currency::for_locale("en_US")));
+    }
+}
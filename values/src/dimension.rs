@@ -3,6 +3,8 @@ use std::{fmt, result};
 use rustling::*;
 use moment::{RcConstraint, Period, Grain, Local};
 
+pub use currency::{Currency, CurrencySymbol, MoneyUnit};
+
 /// Union of all possible values parsed by the ontology.
 rustling_value! {
     #[doc="Union of all possible values parsed by the ontology."]
@@ -12,10 +14,20 @@ rustling_value! {
         AmountOfMoney(AmountOfMoneyValue),
         Ordinal(OrdinalValue),
         Temperature(TemperatureValue),
+        Distance(DistanceValue),
+        Quantity(QuantityValue),
+        Rate(RateValue),
+        Ratio(RatioValue),
+        DigitSequence(DigitSequenceValue),
         MoneyUnit(MoneyUnitValue),
+        QuantityUnit(QuantityUnitValue),
         Time(TimeValue),
         Duration(DurationValue),
         Percentage(PercentageValue),
+        NumberInterval(NumberIntervalValue),
+        AmountOfMoneyInterval(AmountOfMoneyIntervalValue),
+        TemperatureInterval(TemperatureIntervalValue),
+        DurationInterval(DurationIntervalValue),
         Cycle(CycleValue),
         UnitOfDuration(UnitOfDurationValue),
         RelativeMinute(RelativeMinuteValue),
@@ -23,12 +35,22 @@ rustling_value! {
 
     fn latent(v: &Dimension) -> bool {
         match v {
-            &Dimension::Number(_) => false,
+            &Dimension::Number(ref number) => number.latent(),
             &Dimension::Percentage(_) => false,
+            &Dimension::NumberInterval(ref interval) => interval.latent,
+            &Dimension::AmountOfMoneyInterval(_) => false,
+            &Dimension::TemperatureInterval(_) => false,
+            &Dimension::DurationInterval(_) => false,
             &Dimension::AmountOfMoney(_) => false,
-            &Dimension::Ordinal(_) => false,
+            &Dimension::Ordinal(ref ordinal) => ordinal.latent,
             &Dimension::Temperature(ref temp) => temp.latent,
+            &Dimension::Distance(ref distance) => distance.latent,
+            &Dimension::Quantity(_) => false,
+            &Dimension::Rate(_) => false,
+            &Dimension::Ratio(ref ratio) => ratio.latent,
+            &Dimension::DigitSequence(ref sequence) => sequence.latent,
             &Dimension::MoneyUnit(_) => true,
+            &Dimension::QuantityUnit(_) => true,
             &Dimension::Time(ref tv) => tv.latent,
             &Dimension::Duration(_) => false,
             &Dimension::Cycle(_) => true,
@@ -41,10 +63,20 @@ rustling_value! {
         match v {
             &Dimension::Number(_) => None,
             &Dimension::Percentage(_) => None,
+            &Dimension::NumberInterval(_) => None,
+            &Dimension::AmountOfMoneyInterval(_) => None,
+            &Dimension::TemperatureInterval(_) => None,
+            &Dimension::DurationInterval(_) => None,
             &Dimension::AmountOfMoney(_) => None,
             &Dimension::Ordinal(_) => None,
             &Dimension::Temperature(_) => None,
+            &Dimension::Distance(_) => None,
+            &Dimension::Quantity(_) => None,
+            &Dimension::Rate(_) => None,
+            &Dimension::Ratio(_) => None,
+            &Dimension::DigitSequence(_) => None,
             &Dimension::MoneyUnit(_) => None,
+            &Dimension::QuantityUnit(_) => None,
             &Dimension::Time(ref tv) => Some(Payload(tv.constraint.grain())),
             &Dimension::Duration(_) => None,
             &Dimension::Cycle(_) => None,
@@ -60,10 +92,20 @@ impl Dimension {
         match self {
             &Dimension::Number(_) => false,
             &Dimension::Percentage(_) => false,
+            &Dimension::NumberInterval(_) => false,
+            &Dimension::AmountOfMoneyInterval(_) => false,
+            &Dimension::TemperatureInterval(_) => false,
+            &Dimension::DurationInterval(_) => false,
             &Dimension::AmountOfMoney(_) => false,
             &Dimension::Ordinal(_) => false,
             &Dimension::Temperature(_) => false,
+            &Dimension::Distance(_) => false,
+            &Dimension::Quantity(_) => false,
+            &Dimension::Rate(_) => false,
+            &Dimension::Ratio(_) => false,
+            &Dimension::DigitSequence(_) => false,
             &Dimension::MoneyUnit(_) => false,
+            &Dimension::QuantityUnit(_) => false,
             &Dimension::Time(ref tv) => tv.is_too_ambiguous(),
             &Dimension::Duration(_) => false,
             &Dimension::Cycle(_) => true,
@@ -86,10 +128,20 @@ impl fmt::Display for Dimension {
                 }
             }
             &Dimension::Percentage(ref v) => write!(fmt, "Percentage: {}", v.0),
+            &Dimension::NumberInterval(_) => write!(fmt, "NumberInterval"),
+            &Dimension::AmountOfMoneyInterval(_) => write!(fmt, "AmountOfMoneyInterval"),
+            &Dimension::TemperatureInterval(_) => write!(fmt, "TemperatureInterval"),
+            &Dimension::DurationInterval(_) => write!(fmt, "DurationInterval"),
             &Dimension::Ordinal(_) => write!(fmt, "Ordinal"),
             &Dimension::Temperature(_) => write!(fmt, "Temperature"),
+            &Dimension::Distance(_) => write!(fmt, "Distance"),
             &Dimension::AmountOfMoney(_) => write!(fmt, "AmountOfMoney"),
             &Dimension::MoneyUnit(_) => write!(fmt, "MoneyUnit"),
+            &Dimension::Quantity(_) => write!(fmt, "Quantity"),
+            &Dimension::Rate(_) => write!(fmt, "Rate"),
+            &Dimension::Ratio(_) => write!(fmt, "Ratio"),
+            &Dimension::DigitSequence(_) => write!(fmt, "DigitSequence"),
+            &Dimension::QuantityUnit(_) => write!(fmt, "QuantityUnit"),
             &Dimension::Time(_) => write!(fmt, "Time"),
             &Dimension::Duration(_) => write!(fmt, "Duration"),
             &Dimension::Cycle(_) => write!(fmt, "Cycle"),
@@ -105,6 +157,7 @@ pub struct OrdinalValue {
     pub value: i64,
     pub prefixed: bool,
     pub grain: Option<u8>,
+    pub latent: bool,
 }
 
 impl OrdinalValue {
@@ -113,6 +166,7 @@ impl OrdinalValue {
             value,
             prefixed: false,
             grain: None,
+            latent: false,
         }
     }
 
@@ -121,6 +175,7 @@ impl OrdinalValue {
             value: value,
             prefixed: false,
             grain: Some(grain),
+            latent: false,
         }
     }
 
@@ -129,8 +184,13 @@ impl OrdinalValue {
             value: self.value,
             prefixed: true,
             grain: None,
+            latent: self.latent,
         }
     }
+
+    pub fn latent(self, latent: bool) -> OrdinalValue {
+        OrdinalValue { latent, ..self }
+    }
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -148,15 +208,15 @@ impl Default for Precision {
 /// Payload for the amount of money of Dimension
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
 pub struct AmountOfMoneyValue {
-    pub value: f32,
+    pub value: f64,
     pub precision: Precision,
-    pub unit: Option<&'static str>,
+    pub unit: Option<MoneyUnit>,
 }
 
 /// Payload for the unit of money of Dimension
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
 pub struct MoneyUnitValue {
-    pub unit: Option<&'static str>,
+    pub unit: Option<MoneyUnit>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -165,6 +225,21 @@ pub enum CombinationDirection {
     Right,
 }
 
+/// Semantic class of the counter word attached to a number in Japanese, Chinese and Korean
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum CounterClass {
+    /// Small or generic objects: 個, つ, 个, 개
+    Object,
+    /// Long and thin objects: 本
+    LongObject,
+    /// Flat objects: 枚
+    FlatObject,
+    /// People: 人, 名, 位, 명, 사람
+    Person,
+    /// Animals: 匹, 頭, 羽, 只, 마리
+    Animal,
+}
+
 /// Payload for the integral numbers of Dimension
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct IntegerValue {
@@ -179,8 +254,12 @@ pub struct IntegerValue {
     pub suffixed: bool,
     #[doc(hidden)]
     pub combine_from: Option<CombinationDirection>,
-    #[doc(hidden)]
+    /// Approximate for "about 20", "roughly a hundred"
     pub precision: Precision,
+    #[doc(hidden)]
+    pub latent: bool,
+    /// Class of the counter word read with the number, person for "3人"
+    pub counter: Option<CounterClass>,
 }
 
 impl IntegerValue {
@@ -206,6 +285,24 @@ impl IntegerValue {
         })
     }
 
+    pub fn latent(self, latent: bool) -> IntegerValue {
+        IntegerValue { latent, ..self }
+    }
+
+    pub fn with_precision(self, precision: Precision) -> RuleResult<IntegerValue> {
+        Ok(IntegerValue {
+            precision,
+            ..self
+        })
+    }
+
+    pub fn with_counter(self, counter: Option<CounterClass>) -> RuleResult<IntegerValue> {
+        Ok(IntegerValue {
+            counter,
+            ..self
+        })
+    }
+
     #[doc(hidden)]
     pub fn combine_from(self, direction: CombinationDirection) -> RuleResult<IntegerValue> {
         Ok(IntegerValue {
@@ -316,25 +413,50 @@ impl AttemptFrom<Dimension> for FloatValue {
 /// Payload for the floating numbers of Dimension
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct FloatValue {
-    pub value: f32,
+    pub value: f64,
     #[doc(hidden)]
     pub prefixed: bool,
     #[doc(hidden)]
     pub suffixed: bool,
     #[doc(hidden)]
     pub combine_from: Option<CombinationDirection>,
-    #[doc(hidden)]
+    /// Approximate for "about 20", "roughly a hundred"
     pub precision: Precision,
+    #[doc(hidden)]
+    pub latent: bool,
+    pub fraction: Option<Fraction>,
 }
 
 impl FloatValue {
-    pub fn new(value: f32) -> RuleResult<FloatValue> {
+    pub fn new(value: f64) -> RuleResult<FloatValue> {
         Ok(FloatValue {
             value: value,
             ..FloatValue::default()
         })
     }
 
+    pub fn fraction(numerator: i64, denominator: i64) -> RuleResult<FloatValue> {
+        if denominator <= 0 {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(FloatValue {
+            value: numerator as f64 / denominator as f64,
+            fraction: Some(Fraction { numerator, denominator }),
+            ..FloatValue::default()
+        })
+    }
+
+    pub fn latent(self, latent: bool) -> FloatValue {
+        FloatValue { latent, ..self }
+    }
+
+    pub fn with_precision(self, precision: Precision) -> RuleResult<FloatValue> {
+        Ok(FloatValue {
+            precision,
+            ..self
+        })
+    }
+
     #[doc(hidden)]
     pub fn combine_from(self, direction: CombinationDirection) -> RuleResult<FloatValue> {
         Ok(FloatValue {
@@ -362,6 +484,13 @@ impl FloatValue {
     }
 }
 
+/// Numerator and denominator of a number parsed as a fraction
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Fraction {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
 impl From<FloatValue> for NumberValue {
     fn from(v: FloatValue) -> NumberValue {
         NumberValue::Float(v)
@@ -425,10 +554,10 @@ impl NumberValue {
     }
 
     #[doc(hidden)]
-    pub fn value(&self) -> f32 {
+    pub fn value(&self) -> f64 {
         match self {
             &NumberValue::Float(ref v) => v.value,
-            &NumberValue::Integer(ref v) => v.value as f32,
+            &NumberValue::Integer(ref v) => v.value as f64,
         }
     }
 
@@ -439,18 +568,266 @@ impl NumberValue {
             &NumberValue::Integer(ref v) => v.grain,
         }
     }
+
+    pub fn latent(&self) -> bool {
+        match self {
+            &NumberValue::Float(ref v) => v.latent,
+            &NumberValue::Integer(ref v) => v.latent,
+        }
+    }
+
+    pub fn fraction(&self) -> Option<Fraction> {
+        match self {
+            &NumberValue::Float(ref v) => v.fraction,
+            &NumberValue::Integer(_) => None,
+        }
+    }
+
+    pub fn precision(&self) -> Precision {
+        match self {
+            &NumberValue::Float(ref v) => v.precision,
+            &NumberValue::Integer(ref v) => v.precision,
+        }
+    }
+
+    pub fn with_precision(self, precision: Precision) -> RuleResult<NumberValue> {
+        match self {
+            NumberValue::Float(v) => Ok(v.with_precision(precision)?.into()),
+            NumberValue::Integer(v) => Ok(v.with_precision(precision)?.into()),
+        }
+    }
 }
 
 /// Payload for the temperatures of Dimension
 #[derive(Debug, PartialEq, Clone)]
 pub struct TemperatureValue {
-    pub value: f32,
+    pub value: f64,
     /// Celsius, Fahrenheit, ...
     pub unit: Option<&'static str>,
+    pub precision: Precision,
     /// true if it can not be confirmed that the value is actually a temperature
     pub latent: bool,
 }
 
+/// Units of the Distance dimension
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum DistanceUnit {
+    Kilometer,
+    Meter,
+    Centimeter,
+    Millimeter,
+    Mile,
+    Yard,
+    Foot,
+    Inch,
+}
+
+impl DistanceUnit {
+    pub fn name(&self) -> &'static str {
+        match self {
+            &DistanceUnit::Kilometer => "kilometer",
+            &DistanceUnit::Meter => "meter",
+            &DistanceUnit::Centimeter => "centimeter",
+            &DistanceUnit::Millimeter => "millimeter",
+            &DistanceUnit::Mile => "mile",
+            &DistanceUnit::Yard => "yard",
+            &DistanceUnit::Foot => "foot",
+            &DistanceUnit::Inch => "inch",
+        }
+    }
+}
+
+/// Payload for the distances of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct DistanceValue {
+    pub value: f64,
+    pub unit: DistanceUnit,
+    pub precision: Precision,
+    /// true if the unit could as well be something else, e.g. minutes for "10 m"
+    pub latent: bool,
+}
+
+impl DistanceValue {
+    pub fn new(value: f64, unit: DistanceUnit) -> DistanceValue {
+        DistanceValue { value, unit, precision: Precision::Exact, latent: false }
+    }
+
+    pub fn precision(self, precision: Precision) -> DistanceValue {
+        DistanceValue { precision, .. self }
+    }
+
+    pub fn latent(self, latent: bool) -> DistanceValue {
+        DistanceValue { latent, .. self }
+    }
+}
+
+/// Units of the Quantity dimension
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum QuantityUnit {
+    Milligram,
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+    Milliliter,
+    Centiliter,
+    Liter,
+    Teaspoon,
+    Tablespoon,
+    Cup,
+}
+
+impl QuantityUnit {
+    pub fn name(&self) -> &'static str {
+        match self {
+            &QuantityUnit::Milligram => "milligram",
+            &QuantityUnit::Gram => "gram",
+            &QuantityUnit::Kilogram => "kilogram",
+            &QuantityUnit::Ounce => "ounce",
+            &QuantityUnit::Pound => "pound",
+            &QuantityUnit::Milliliter => "milliliter",
+            &QuantityUnit::Centiliter => "centiliter",
+            &QuantityUnit::Liter => "liter",
+            &QuantityUnit::Teaspoon => "teaspoon",
+            &QuantityUnit::Tablespoon => "tablespoon",
+            &QuantityUnit::Cup => "cup",
+        }
+    }
+}
+
+/// Payload for the quantities of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct QuantityValue {
+    pub value: f64,
+    pub unit: QuantityUnit,
+    /// Byte range of what is measured in the input, e.g. "milk" in "2 liters of milk"
+    pub product: Option<Range>,
+}
+
+impl QuantityValue {
+    pub fn new(value: f64, unit: QuantityUnit) -> QuantityValue {
+        QuantityValue { value, unit, product: None }
+    }
+
+    pub fn product(self, product: Range) -> QuantityValue {
+        QuantityValue { product: Some(product), .. self }
+    }
+}
+
+/// Numerator of a rate: what is counted per unit of time
+#[derive(Debug, PartialEq, Clone)]
+pub enum RateQuantity {
+    Number(NumberValue),
+    AmountOfMoney(AmountOfMoneyValue),
+    Distance(DistanceValue),
+}
+
+impl RateQuantity {
+    pub fn value(&self) -> f64 {
+        match self {
+            &RateQuantity::Number(ref number) => number.value(),
+            &RateQuantity::AmountOfMoney(ref amount) => amount.value,
+            &RateQuantity::Distance(ref distance) => distance.value,
+        }
+    }
+}
+
+impl From<RateQuantity> for Dimension {
+    fn from(quantity: RateQuantity) -> Dimension {
+        match quantity {
+            RateQuantity::Number(number) => Dimension::Number(number),
+            RateQuantity::AmountOfMoney(amount) => Dimension::AmountOfMoney(amount),
+            RateQuantity::Distance(distance) => Dimension::Distance(distance),
+        }
+    }
+}
+
+/// Payload for the rates of Dimension, e.g. "$20 per hour" or "3 times a day"
+#[derive(Debug, PartialEq, Clone)]
+pub struct RateValue {
+    pub quantity: RateQuantity,
+    /// The unit of time the quantity is counted over
+    pub per: Grain,
+}
+
+impl RateValue {
+    pub fn new<Q: Into<RateQuantity>>(quantity: Q, per: &UnitOfDurationValue) -> RateValue {
+        RateValue { quantity: quantity.into(), per: per.grain }
+    }
+}
+
+impl From<NumberValue> for RateQuantity {
+    fn from(number: NumberValue) -> RateQuantity {
+        RateQuantity::Number(number)
+    }
+}
+
+impl From<AmountOfMoneyValue> for RateQuantity {
+    fn from(amount: AmountOfMoneyValue) -> RateQuantity {
+        RateQuantity::AmountOfMoney(amount)
+    }
+}
+
+impl From<DistanceValue> for RateQuantity {
+    fn from(distance: DistanceValue) -> RateQuantity {
+        RateQuantity::Distance(distance)
+    }
+}
+
+/// Payload for the ratios and scores of Dimension, e.g. "3 out of 5" or "4 to 1"
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RatioValue {
+    pub numerator: i64,
+    pub denominator: i64,
+    /// true if the ratio could as well be something else, e.g. a time of day for "2:30"
+    pub latent: bool,
+}
+
+impl RatioValue {
+    pub fn new(numerator: i64, denominator: i64) -> RatioValue {
+        RatioValue { numerator, denominator, latent: false }
+    }
+
+    pub fn latent(self, latent: bool) -> RatioValue {
+        RatioValue { latent, .. self }
+    }
+}
+
+/// Payload for the digit sequences of Dimension, e.g. "zero six one two" or "0612"
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigitSequenceValue {
+    /// The digits in order, leading zeros included
+    pub digits: String,
+    /// true for short sequences without a leading zero, which read as well as a number
+    pub latent: bool,
+}
+
+impl DigitSequenceValue {
+    pub fn new(digits: &str) -> DigitSequenceValue {
+        let sequence = DigitSequenceValue { digits: digits.to_string(), latent: false };
+        let latent = sequence.digits.len() < 3 && !sequence.has_leading_zero();
+        DigitSequenceValue { latent, .. sequence }
+    }
+
+    pub fn latent(self, latent: bool) -> DigitSequenceValue {
+        DigitSequenceValue { latent, ..self }
+    }
+
+    pub fn has_leading_zero(&self) -> bool {
+        self.digits.len() > 1 && self.digits.starts_with('0')
+    }
+
+    pub fn append(&self, other: &DigitSequenceValue) -> DigitSequenceValue {
+        DigitSequenceValue::new(&format!("{}{}", self.digits, other.digits))
+    }
+}
+
+/// Payload for the unit of quantity of Dimension
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct QuantityUnitValue {
+    pub unit: QuantityUnit,
+}
+
 /// Payload for the cycle of Dimension
 #[derive(Debug, PartialEq, Clone)]
 pub struct CycleValue {
@@ -731,7 +1108,7 @@ pub struct YearMonthDayForm {
 }
 
 #[derive(Debug, PartialEq, Clone)]
-pub struct PercentageValue(pub f32);
+pub struct PercentageValue(pub f64);
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum FromAddition {
@@ -790,5 +1167,153 @@ impl DurationValue {
     }
 }
 
+/// One end of an interval of values
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct IntervalBound<T> {
+    pub value: T,
+    pub inclusive: bool,
+}
+
+impl<T> IntervalBound<T> {
+    pub fn inclusive(value: T) -> IntervalBound<T> {
+        IntervalBound { value, inclusive: true }
+    }
+
+    pub fn exclusive(value: T) -> IntervalBound<T> {
+        IntervalBound { value, inclusive: false }
+    }
+}
+
+/// Open-ended intervals: "under", "over", "at least" and "at most"
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum OpenInterval {
+    Under,
+    Over,
+    AtLeast,
+    AtMost,
+}
+
+impl OpenInterval {
+    /// The (from, to) bounds of the interval for the given value
+    pub fn bounds<T>(&self, value: T) -> (Option<IntervalBound<T>>, Option<IntervalBound<T>>) {
+        match self {
+            &OpenInterval::Under => (None, Some(IntervalBound::exclusive(value))),
+            &OpenInterval::Over => (Some(IntervalBound::exclusive(value)), None),
+            &OpenInterval::AtLeast => (Some(IntervalBound::inclusive(value)), None),
+            &OpenInterval::AtMost => (None, Some(IntervalBound::inclusive(value))),
+        }
+    }
+}
+
+/// Payload for the number intervals of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct NumberIntervalValue {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+    pub latent: bool,
+}
+
+impl NumberIntervalValue {
+    /// Latent, two numbers without unit such as "3-4" or "from 3 to 5" being as well times,
+    /// scores or fragments of phone numbers
+    pub fn between(from: f64, to: f64) -> RuleResult<NumberIntervalValue> {
+        if from >= to {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(NumberIntervalValue {
+            from: Some(IntervalBound::inclusive(from)),
+            to: Some(IntervalBound::inclusive(to)),
+            latent: true,
+        })
+    }
+
+    pub fn open(interval: OpenInterval, value: f64) -> NumberIntervalValue {
+        let (from, to) = interval.bounds(value);
+        NumberIntervalValue { from, to, latent: false }
+    }
+}
+
+/// Payload for the amount of money intervals of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct AmountOfMoneyIntervalValue {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+    pub unit: Option<MoneyUnit>,
+    pub precision: Precision,
+}
+
+impl AmountOfMoneyIntervalValue {
+    /// The lower amount may leave its unit out, as in "between 20 and 30 euros"
+    pub fn between(from: &AmountOfMoneyValue, to: &AmountOfMoneyValue) -> RuleResult<AmountOfMoneyIntervalValue> {
+        if from.value >= to.value || (from.unit.is_some() && from.unit != to.unit) {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(AmountOfMoneyIntervalValue {
+            from: Some(IntervalBound::inclusive(from.value)),
+            to: Some(IntervalBound::inclusive(to.value)),
+            unit: to.unit,
+            precision: to.precision,
+        })
+    }
+
+    pub fn open(interval: OpenInterval, value: &AmountOfMoneyValue) -> AmountOfMoneyIntervalValue {
+        let (from, to) = interval.bounds(value.value);
+        AmountOfMoneyIntervalValue { from, to, unit: value.unit, precision: value.precision }
+    }
+}
+
+/// Payload for the temperature intervals of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct TemperatureIntervalValue {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+    pub unit: Option<&'static str>,
+}
+
+impl TemperatureIntervalValue {
+    /// The lower temperature may leave its unit out, as in "between 20 and 25 degrees"
+    pub fn between(from: &TemperatureValue, to: &TemperatureValue) -> RuleResult<TemperatureIntervalValue> {
+        if from.value >= to.value || (from.unit.is_some() && from.unit != to.unit) {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(TemperatureIntervalValue {
+            from: Some(IntervalBound::inclusive(from.value)),
+            to: Some(IntervalBound::inclusive(to.value)),
+            unit: to.unit,
+        })
+    }
+
+    pub fn open(interval: OpenInterval, value: &TemperatureValue) -> TemperatureIntervalValue {
+        let (from, to) = interval.bounds(value.value);
+        TemperatureIntervalValue { from, to, unit: value.unit }
+    }
+}
+
+/// Payload for the duration intervals of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct DurationIntervalValue {
+    pub from: Option<IntervalBound<Period>>,
+    pub to: Option<IntervalBound<Period>>,
+    pub precision: Precision,
+}
+
+impl DurationIntervalValue {
+    pub fn between(from: &DurationValue, to: &DurationValue) -> RuleResult<DurationIntervalValue> {
+        if from.period.coarse_num_secs() >= to.period.coarse_num_secs() {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(DurationIntervalValue {
+            from: Some(IntervalBound::inclusive(from.period.clone())),
+            to: Some(IntervalBound::inclusive(to.period.clone())),
+            precision: ::helpers::precision_resolution(from.precision, to.precision),
+        })
+    }
+
+    pub fn open(interval: OpenInterval, value: &DurationValue) -> DurationIntervalValue {
+        let (from, to) = interval.bounds(value.period.clone());
+        DurationIntervalValue { from, to, precision: value.precision }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct RelativeMinuteValue(pub i32);
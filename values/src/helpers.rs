@@ -6,13 +6,14 @@ use regex::Regex;
 
 pub fn compose_numbers(a: &NumberValue, b: &NumberValue) -> RuleResult<NumberValue> {
     let grain = a.grain().unwrap_or(0) as u32;
-    if 10u64.pow(grain) as f32 > b.value() && a.value() >= 0.0 && b.value() >= 0.0 {
-        match (a, b) {
+    if 10u64.pow(grain) as f64 > b.value() && a.value() >= 0.0 && b.value() >= 0.0 {
+        let composed = match (a, b) {
             (&NumberValue::Integer(ref lhs), &NumberValue::Integer(ref rhs)) => {
-                Ok(NumberValue::Integer(IntegerValue::new(lhs.value + rhs.value)?.with_grain(rhs.grain)?))
+                NumberValue::Integer(IntegerValue::new(lhs.value + rhs.value)?.with_grain(rhs.grain)?)
             }
-            _ => Ok(NumberValue::Float(FloatValue::new(a.value() + b.value())?)),
-        }
+            _ => NumberValue::Float(FloatValue::new(a.value() + b.value())?),
+        };
+        composed.with_precision(precision_resolution(a.precision(), b.precision()))
     } else {
         Err(RuleError::Invalid.into())
     }
@@ -69,6 +70,283 @@ pub fn decimal_hour_in_minute(a: &str, b: &str) -> RuleResult<i64> {
     Ok((b_value * 6) / 10i64.pow(b.len() as u32 - 1) + a_value * 60)
 }
 
+/// "3:2" as a ratio or a score. It is latent when it also reads as a time of day, like "2:30".
+pub fn colon_ratio(a: &str, b: &str) -> RuleResult<RatioValue> {
+    let numerator: i64 = a.parse()?;
+    let denominator: i64 = b.parse()?;
+    let is_time_of_day = numerator <= 23 && b.len() == 2 && denominator <= 59;
+    Ok(RatioValue::new(numerator, denominator).latent(is_time_of_day))
+}
+
+const ROMAN_NUMERALS: [(&'static str, i64); 13] = [
+    ("m", 1000), ("cm", 900), ("d", 500), ("cd", 400),
+    ("c", 100), ("xc", 90), ("l", 50), ("xl", 40),
+    ("x", 10), ("ix", 9), ("v", 5), ("iv", 4), ("i", 1),
+];
+
+/// Value of a lowercase Roman numeral written in canonical form ("xiv", "mmxix"), from 1 to 3999.
+/// Non canonical spellings such as "iiii" or "ic" are rejected.
+pub fn roman_numeral(text: &str) -> RuleResult<i64> {
+    let mut rest = text;
+    let mut value = 0;
+    for &(symbol, symbol_value) in ROMAN_NUMERALS.iter() {
+        while rest.starts_with(symbol) {
+            rest = &rest[symbol.len()..];
+            value += symbol_value;
+        }
+    }
+    if !rest.is_empty() || value < 1 || value > 3999 || to_roman_numeral(value) != text {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(value)
+}
+
+fn to_roman_numeral(value: i64) -> String {
+    let mut rest = value;
+    let mut numeral = String::new();
+    for &(symbol, symbol_value) in ROMAN_NUMERALS.iter() {
+        while rest >= symbol_value {
+            numeral.push_str(symbol);
+            rest -= symbol_value;
+        }
+    }
+    numeral
+}
+
+/// Whether a Roman numeral is also a common word or unit symbol ("i", "cm", "mix") in a language
+/// which lists its ambiguous numerals of more than one letter.
+pub fn is_ambiguous_roman_numeral(text: &str, ambiguous: &[&str]) -> bool {
+    text.len() == 1 || ambiguous.contains(&text)
+}
+
+/// Words building Roman numerals in a language
+#[derive(Debug, PartialEq, Clone)]
+pub struct RomanNumeralWords {
+    /// Words after which a numeral is an ordinal, as in "chapter XIV"
+    pub ordinal_prefix: &'static str,
+    /// Marker of an ordinal after the numeral, as in "XXe" or "XIV."
+    pub ordinal_suffix: Option<&'static str>,
+    /// Numerals of more than one letter which are also common words or unit symbols, and are
+    /// only produced as latent integers
+    pub ambiguous: &'static [&'static str],
+}
+
+/// Roman numerals read as integers, or as ordinals after a prefix or with a suffix
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>, words: &RomanNumeralWords) -> RustlingResult<()> {
+    let ambiguous = words.ambiguous;
+    b.rule_1_terminal("roman numeral (integer)",
+                      b.reg(r#"([ivxlcdm]+)"#)?,
+                      move |text_match| {
+                          let value = roman_numeral(text_match.group(1))?;
+                          Ok(IntegerValue::new(value)?.latent(is_ambiguous_roman_numeral(text_match.group(1), ambiguous)))
+                      }
+    );
+    b.rule_2("<ordinal prefix> <roman numeral>",
+             b.reg(words.ordinal_prefix)?,
+             b.reg(r#"([ivxlcdm]+)"#)?,
+             |_, text_match| Ok(OrdinalValue::new(roman_numeral(text_match.group(1))?))
+    );
+    if let Some(suffix) = words.ordinal_suffix {
+        b.rule_1_terminal("roman numeral (ordinal)",
+                          b.reg(&format!(r#"([ivxlcdm]+)(?:{})"#, suffix))?,
+                          move |text_match| {
+                              let value = roman_numeral(text_match.group(1))?;
+                              Ok(OrdinalValue::new(value).latent(is_ambiguous_roman_numeral(text_match.group(1), ambiguous)))
+                          }
+        );
+    }
+    Ok(())
+}
+
+/// Digit grouping and decimal separator of numbers written with digits
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SeparatorPolicy {
+    /// "1,000,000.5"
+    CommaGroupsDotDecimal,
+    /// "1.000.000,5"
+    DotGroupsCommaDecimal,
+    /// "1'000'000.50", as in Switzerland
+    Swiss,
+    /// "10,00,000.5", grouping by lakh and crore
+    Indian,
+    /// "1 000 000,5"
+    SpaceGroupsCommaDecimal,
+    /// "1 000 000.5"
+    SpaceGroupsDotDecimal,
+}
+
+impl SeparatorPolicy {
+    /// Every policy, in the order their rules are registered
+    pub fn all() -> Vec<SeparatorPolicy> {
+        vec![
+            SeparatorPolicy::CommaGroupsDotDecimal,
+            SeparatorPolicy::DotGroupsCommaDecimal,
+            SeparatorPolicy::Swiss,
+            SeparatorPolicy::Indian,
+            SeparatorPolicy::SpaceGroupsCommaDecimal,
+            SeparatorPolicy::SpaceGroupsDotDecimal,
+        ]
+    }
+
+    fn group_separator(&self) -> &'static str {
+        match self {
+            &SeparatorPolicy::CommaGroupsDotDecimal | &SeparatorPolicy::Indian => ",",
+            &SeparatorPolicy::DotGroupsCommaDecimal => r"\.",
+            &SeparatorPolicy::Swiss => "['’]",
+            &SeparatorPolicy::SpaceGroupsCommaDecimal | &SeparatorPolicy::SpaceGroupsDotDecimal => "[ \u{a0}\u{202f}]",
+        }
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            &SeparatorPolicy::DotGroupsCommaDecimal | &SeparatorPolicy::SpaceGroupsCommaDecimal => ',',
+            _ => '.',
+        }
+    }
+
+    fn grouped_digits_regex(&self) -> String {
+        match self {
+            &SeparatorPolicy::Indian => r"\d{1,2}(?:,\d\d)*,\d{3}".to_string(),
+            _ => format!(r"\d{{1,3}}(?:{}\d{{3}}){{1,5}}", self.group_separator()),
+        }
+    }
+
+    /// Integers with grouped digits, e.g. "1,000,000"
+    pub fn integer_regex(&self) -> String {
+        format!("({})", self.grouped_digits_regex())
+    }
+
+    /// Decimals with grouped digits, e.g. "1,000,000.5"
+    pub fn grouped_decimal_regex(&self) -> String {
+        format!(r"({}{}\d+)", self.grouped_digits_regex(), ::regex::escape(&self.decimal_separator().to_string()))
+    }
+
+    /// Decimals without grouping, e.g. "3.5" or ".5"
+    pub fn decimal_regex(&self) -> String {
+        format!(r"(\d*{}\d+)", ::regex::escape(&self.decimal_separator().to_string()))
+    }
+
+    pub fn parse_integer(&self, text: &str) -> RuleResult<i64> {
+        Ok(text.chars().filter(|c| c.is_digit(10)).collect::<String>().parse()?)
+    }
+
+    pub fn parse_decimal(&self, text: &str) -> RuleResult<f64> {
+        let decimal_separator = self.decimal_separator();
+        Ok(text.chars()
+            .filter_map(|c| if c.is_digit(10) { Some(c) } else if c == decimal_separator { Some('.') } else { None })
+            .collect::<String>()
+            .parse()?)
+    }
+}
+
+/// Numbers written with digits and separators, shared by all grammars. The rules of every
+/// policy are registered in the same order whatever the given policies are, and those of the
+/// other policies never match: the rule set interns the same symbols, so that a parser trained
+/// with the default policies of a language can be built with others.
+pub fn rules_separated_numbers(b: &mut RuleSetBuilder<Dimension>, policies: &[SeparatorPolicy]) -> RustlingResult<()> {
+    let mut decimal_separators = vec![];
+    for policy in SeparatorPolicy::all() {
+        let enabled = policies.contains(&policy);
+        b.rule_1_terminal("integer with thousands separator",
+                          b.reg(&policy.integer_regex())?,
+                          move |text_match| {
+                              if !enabled {
+                                  return Err(RuleError::Invalid.into());
+                              }
+                              IntegerValue::new(policy.parse_integer(text_match.group(1))?)
+                          }
+        );
+        b.rule_1_terminal("decimal with thousands separator",
+                          b.reg(&policy.grouped_decimal_regex())?,
+                          move |text_match| {
+                              if !enabled {
+                                  return Err(RuleError::Invalid.into());
+                              }
+                              FloatValue::new(policy.parse_decimal(text_match.group(1))?)
+                          }
+        );
+        let decimal_separator = policy.decimal_separator();
+        if !decimal_separators.contains(&decimal_separator) {
+            decimal_separators.push(decimal_separator);
+            let enabled = policies.iter().any(|p| p.decimal_separator() == decimal_separator);
+            b.rule_1_terminal("decimal number",
+                              b.reg(&policy.decimal_regex())?,
+                              move |text_match| {
+                                  if !enabled {
+                                      return Err(RuleError::Invalid.into());
+                                  }
+                                  FloatValue::new(policy.parse_decimal(text_match.group(1))?)
+                              }
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Fractions written with digits, shared by all grammars
+pub fn rules_digit_fractions(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // "2/3" is as well a date, the fraction needs a unit or an amount after it
+    b.rule_1_terminal("fraction (a/b)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| Ok(FloatValue::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)?.latent(true))
+    );
+    Ok(())
+}
+
+/// Digit sequences written with digits, and their composition with the spoken digits of each
+/// grammar. A written sequence without a leading zero reads as well as a number, and is latent.
+pub fn rules_digit_sequences(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("digit sequence (written)",
+                      b.reg(r#"(\d{2,})"#)?,
+                      |text_match| {
+                          let sequence = DigitSequenceValue::new(text_match.group(1));
+                          let latent = !sequence.has_leading_zero();
+                          Ok(sequence.latent(latent))
+                      }
+    );
+    b.rule_1_terminal("digit sequence (grouped, leading zero)",
+                      b.reg(r#"(0\d(?:[ .-]?\d{2})+)"#)?,
+                      |text_match| {
+                          let digits = text_match.group(1).chars().filter(|c| c.is_digit(10)).collect::<String>();
+                          Ok(DigitSequenceValue::new(&digits))
+                      }
+    );
+    b.rule_2("<digit sequence> <spoken digit>",
+             digit_sequence_check!(),
+             digit_sequence_check!(|sequence: &DigitSequenceValue| sequence.digits.len() == 1),
+             |a, b| Ok(a.value().append(b.value()))
+    );
+    Ok(())
+}
+
+/// Converts between "celsius", "fahrenheit" and "kelvin"
+pub fn convert_temperature(value: f64, from: &str, to: &str) -> Option<f64> {
+    let celsius = match from {
+        "celsius" => value,
+        "fahrenheit" => (value - 32.0) * 5.0 / 9.0,
+        "kelvin" => value - 273.15,
+        _ => return None,
+    };
+    match to {
+        "celsius" => Some(celsius),
+        "fahrenheit" => Some(celsius * 9.0 / 5.0 + 32.0),
+        "kelvin" => Some(celsius + 273.15),
+        _ => None,
+    }
+}
+
+/// ISO 3166 region of a locale such as "en_US", "fr-CA" or "sv_SE", "en_UK" giving "GB"
+pub fn locale_region(locale: &str) -> Option<String> {
+    let mut parts = locale.split(|c| c == '_' || c == '-');
+    parts.next()?;
+    parts.next()
+        .filter(|region| region.len() == 2)
+        .map(|region| match region.to_uppercase().as_ref() {
+            "UK" => "GB".to_string(),
+            region => region.to_string(),
+        })
+}
+
 pub fn compose_money(a: &AmountOfMoneyValue,
                      b: &AmountOfMoneyValue)
                      -> RuleResult<AmountOfMoneyValue> {
@@ -91,6 +369,221 @@ pub fn compose_money_number(a: &AmountOfMoneyValue,
        })
 }
 
+/// "between 20 and 30 euros": the lower bound is a bare number
+pub fn money_interval_from_number(from: &NumberValue,
+                                  to: &AmountOfMoneyValue)
+                                  -> RuleResult<AmountOfMoneyIntervalValue> {
+    let from = AmountOfMoneyValue {
+        value: from.value(),
+        unit: None,
+        precision: to.precision,
+    };
+    AmountOfMoneyIntervalValue::between(&from, to)
+}
+
+/// "between 20 and 25 degrees": the lower bound is a bare number
+pub fn temperature_interval_from_number(from: &NumberValue,
+                                        to: &TemperatureValue)
+                                        -> RuleResult<TemperatureIntervalValue> {
+    let from = TemperatureValue {
+        value: from.value(),
+        unit: None,
+        precision: to.precision,
+        latent: true,
+    };
+    TemperatureIntervalValue::between(&from, to)
+}
+
+/// "between 2 and 3 hours": the lower bound takes the unit of the upper one
+pub fn duration_interval_from_integer(from: &IntegerValue,
+                                      to: &DurationValue)
+                                      -> RuleResult<DurationIntervalValue> {
+    let comps = to.period.comps();
+    if comps.len() != 1 {
+        return Err(RuleError::Invalid.into());
+    }
+    let from = DurationValue::new(PeriodComp::new(comps[0].grain, from.value).into()).precision(to.precision);
+    DurationIntervalValue::between(&from, to)
+}
+
+/// Where the word of an open interval stands around its bound
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum OpenIntervalMarker {
+    /// "at least 20", "至少20"
+    Before(&'static str),
+    /// "20以上"
+    After(&'static str),
+}
+
+/// Regexes of the words building number, amount of money, temperature and duration intervals
+/// in a language. A word takes a single role: a range connector which also marks an upper bound
+/// gives competing parses.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IntervalWords {
+    /// "between" and "and" of "between 20 and 30"
+    pub between: Option<(&'static str, &'static str)>,
+    /// "from" and "to" of "from 20 to 30"
+    pub from_to: Option<(&'static str, &'static str)>,
+    /// Written between the two bounds, as in "20-30"
+    pub range: &'static str,
+    /// "under", "over", "at least" and "at most"
+    pub open: Vec<(OpenInterval, OpenIntervalMarker)>,
+}
+
+fn rules_closed_intervals(b: &mut RuleSetBuilder<Dimension>, prefix: &'static str, infix: &'static str) -> RustlingResult<()> {
+    b.rule_4("<prefix> <number> <infix> <number>",
+             b.reg(prefix)?,
+             number_check!(),
+             b.reg(infix)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_4("<prefix> <amount-of-money> <infix> <amount-of-money>",
+             b.reg(prefix)?,
+             amount_of_money_check!(),
+             b.reg(infix)?,
+             amount_of_money_check!(),
+             |_, a, _, b| AmountOfMoneyIntervalValue::between(&a.value(), &b.value())
+    );
+    b.rule_4("<prefix> <number> <infix> <amount-of-money>",
+             b.reg(prefix)?,
+             number_check!(),
+             b.reg(infix)?,
+             amount_of_money_check!(),
+             |_, a, _, b| money_interval_from_number(&a.value(), &b.value())
+    );
+    b.rule_4("<prefix> <temp> <infix> <temp>",
+             b.reg(prefix)?,
+             temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+             b.reg(infix)?,
+             temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+             |_, a, _, b| TemperatureIntervalValue::between(&a.value(), &b.value())
+    );
+    b.rule_4("<prefix> <number> <infix> <temp>",
+             b.reg(prefix)?,
+             number_check!(),
+             b.reg(infix)?,
+             temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+             |_, a, _, b| temperature_interval_from_number(&a.value(), &b.value())
+    );
+    b.rule_4("<prefix> <duration> <infix> <duration>",
+             b.reg(prefix)?,
+             duration_check!(),
+             b.reg(infix)?,
+             duration_check!(),
+             |_, a, _, b| DurationIntervalValue::between(&a.value(), &b.value())
+    );
+    b.rule_4("<prefix> <integer> <infix> <duration>",
+             b.reg(prefix)?,
+             integer_check!(),
+             b.reg(infix)?,
+             duration_check!(),
+             |_, a, _, b| duration_interval_from_integer(&a.value(), &b.value())
+    );
+    Ok(())
+}
+
+/// Number, amount of money, temperature and duration intervals, shared by all grammars
+pub fn rules_intervals(b: &mut RuleSetBuilder<Dimension>, words: &IntervalWords) -> RustlingResult<()> {
+    if let Some((between, and)) = words.between {
+        rules_closed_intervals(b, between, and)?;
+    }
+    if let Some((from, to)) = words.from_to {
+        rules_closed_intervals(b, from, to)?;
+    }
+    b.rule_3("<number> - <number>",
+             number_check!(),
+             b.reg(words.range)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(words.range)?,
+             amount_of_money_check!(),
+             |a, _, b| AmountOfMoneyIntervalValue::between(&a.value(), &b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(words.range)?,
+             amount_of_money_check!(),
+             |a, _, b| money_interval_from_number(&a.value(), &b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+             b.reg(words.range)?,
+             temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+             |a, _, b| TemperatureIntervalValue::between(&a.value(), &b.value())
+    );
+    b.rule_3("<number> - <temp>",
+             number_check!(),
+             b.reg(words.range)?,
+             temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+             |a, _, b| temperature_interval_from_number(&a.value(), &b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(words.range)?,
+             duration_check!(),
+             |a, _, b| DurationIntervalValue::between(&a.value(), &b.value())
+    );
+    b.rule_3("<integer> - <duration>",
+             integer_check!(),
+             b.reg(words.range)?,
+             duration_check!(),
+             |a, _, b| duration_interval_from_integer(&a.value(), &b.value())
+    );
+    for &(interval, marker) in &words.open {
+        match marker {
+            OpenIntervalMarker::Before(regex) => {
+                b.rule_2("<open interval> <number>",
+                         b.reg(regex)?,
+                         number_check!(),
+                         move |_, a| Ok(NumberIntervalValue::open(interval, a.value().value()))
+                );
+                b.rule_2("<open interval> <amount-of-money>",
+                         b.reg(regex)?,
+                         amount_of_money_check!(),
+                         move |_, a| Ok(AmountOfMoneyIntervalValue::open(interval, &a.value()))
+                );
+                b.rule_2("<open interval> <temp>",
+                         b.reg(regex)?,
+                         temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+                         move |_, a| Ok(TemperatureIntervalValue::open(interval, &a.value()))
+                );
+                b.rule_2("<open interval> <duration>",
+                         b.reg(regex)?,
+                         duration_check!(),
+                         move |_, a| Ok(DurationIntervalValue::open(interval, &a.value()))
+                );
+            }
+            OpenIntervalMarker::After(regex) => {
+                b.rule_2("<number> <open interval>",
+                         number_check!(),
+                         b.reg(regex)?,
+                         move |a, _| Ok(NumberIntervalValue::open(interval, a.value().value()))
+                );
+                b.rule_2("<amount-of-money> <open interval>",
+                         amount_of_money_check!(),
+                         b.reg(regex)?,
+                         move |a, _| Ok(AmountOfMoneyIntervalValue::open(interval, &a.value()))
+                );
+                b.rule_2("<temp> <open interval>",
+                         temperature_check!(|temp: &TemperatureValue| temp.unit.is_some()),
+                         b.reg(regex)?,
+                         move |a, _| Ok(TemperatureIntervalValue::open(interval, &a.value()))
+                );
+                b.rule_2("<duration> <open interval>",
+                         duration_check!(),
+                         b.reg(regex)?,
+                         move |a, _| Ok(DurationIntervalValue::open(interval, &a.value()))
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
 impl Form {
     fn time_of_day_hour(full_hour: u32, is_12_clock: bool) -> Form {
         Form::TimeOfDay(TimeOfDayForm::hour(full_hour, is_12_clock))
@@ -149,6 +642,34 @@ pub fn precision_resolution(lhs: Precision, rhs: Precision) -> Precision {
         Precision::Exact
     }
 }
+
+/// "three quarters of an hour": the fraction of one grain, expressed in the coarsest finer
+/// grain that holds it exactly. A month counts as 30 days, which makes the duration approximate.
+pub fn fraction_of_grain(fraction: Fraction, grain: Grain) -> RuleResult<DurationValue> {
+    let mut grain = grain;
+    let mut units = fraction.numerator;
+    let mut precision = Precision::Exact;
+    while units % fraction.denominator != 0 {
+        let (finer, factor) = match grain {
+            Grain::Year => (Grain::Month, 12),
+            Grain::Quarter => (Grain::Month, 3),
+            Grain::Month => {
+                precision = Precision::Approximate;
+                (Grain::Day, 30)
+            }
+            Grain::Week => (Grain::Day, 7),
+            Grain::Day => (Grain::Hour, 24),
+            Grain::Hour => (Grain::Minute, 60),
+            Grain::Minute => (Grain::Second, 60),
+            Grain::Second => return Err(RuleError::Invalid.into()),
+        };
+        units = units.checked_mul(factor)
+            .ok_or_else(|| format_err!("{}/{} of a {:?} overflows in {:?}", fraction.numerator, fraction.denominator, grain, finer))?;
+        grain = finer;
+    }
+    Ok(DurationValue::new(PeriodComp::new(grain, units / fraction.denominator).into()).precision(precision))
+}
+
 fn from_addition_resolution(lhs: Option<FromAddition>, rhs: Option<FromAddition>) -> FromAddition {
     match (lhs, rhs) {
         (Some(lhs), None) => lhs,
@@ -425,6 +946,18 @@ pub fn year_month_day(y: i32, m: u32, d: u32) -> RuleResult<TimeValue> {
     Ok(TimeValue::constraint(YearMonthDay::new(y, m, d).invalid_if_err()?).form(Form::YearMonthDay(Some(YearMonthDayForm { year: y, month: m, day_of_month: d }))))
 }
 
+pub fn hijri_month(m: u32) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(HijriMonth::new(m).invalid_if_err()?))
+}
+
+pub fn hijri_month_day(m: u32, d: u32) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(HijriMonthDay::new(m, d).invalid_if_err()?))
+}
+
+pub fn season(season: Season) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(SeasonOfYear::new(season)))
+}
+
 pub fn hour(h: u32, is_12_clock: bool) -> RuleResult<TimeValue> {
     if is_12_clock {
         Ok(TimeValue::constraint(Hour::clock_12(h).invalid_if_err()?).form(Form::time_of_day_hour(h, is_12_clock)))
@@ -513,6 +1046,21 @@ pub fn cycle_n_not_immediate(grain: Grain, n: i64) -> RuleResult<TimeValue> {
     Ok(TimeValue::constraint(Cycle::rc(grain).take_not_immediate(n)).form(Form::Cycle(grain)))
 }
 
+pub fn business_day() -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(BusinessDay::new()))
+}
+
+/// The n-th business day counted from today, today excluded when n > 0.
+pub fn business_day_nth(n: i64) -> RuleResult<TimeValue> {
+    if n > 0 {
+        Ok(TimeValue::constraint(BusinessDay::new()
+                                     .the_nth(n - 1)
+                                     .after(&Cycle::rc(Grain::Day).take_the_nth(1))))
+    } else {
+        Ok(TimeValue::constraint(BusinessDay::new().take_the_nth(n)))
+    }
+}
+
 pub fn easter() -> RuleResult<TimeValue> {
     fn offset(i: &Interval<Local>, _: &Context<Local>) -> Option<Interval<Local>> {
         let (year, month, day) = computer_easter(i.start.year());
@@ -634,6 +1182,69 @@ impl<'a> ops::Add<DurationValue> for &'a DurationValue {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_roman_numeral() {
+        assert_eq!(4, roman_numeral("iv").unwrap());
+        assert_eq!(14, roman_numeral("xiv").unwrap());
+        assert_eq!(53, roman_numeral("liii").unwrap());
+        assert_eq!(2019, roman_numeral("mmxix").unwrap());
+        assert_eq!(3999, roman_numeral("mmmcmxcix").unwrap());
+        assert!(roman_numeral("iiii").is_err());
+        assert!(roman_numeral("ic").is_err());
+        assert!(roman_numeral("vv").is_err());
+        assert!(roman_numeral("ixi").is_err());
+        assert!(roman_numeral("").is_err());
+    }
+
+    #[test]
+    fn test_separator_policy() {
+        let check = |text: &str, regex: String| Regex::new(&format!("^{}$", regex)).unwrap().is_match(text);
+        let swiss = SeparatorPolicy::Swiss;
+        assert!(check("1'000'000", swiss.integer_regex()));
+        assert_eq!(1000.5, swiss.parse_decimal("1'000.50").unwrap());
+        let indian = SeparatorPolicy::Indian;
+        assert!(check("1,00,000", indian.integer_regex()));
+        assert!(check("10,00,00,000", indian.integer_regex()));
+        assert!(!check("100,000", indian.integer_regex()));
+        assert_eq!(100000, indian.parse_integer("1,00,000").unwrap());
+        let space = SeparatorPolicy::SpaceGroupsCommaDecimal;
+        assert!(check("1 000 000,5", space.grouped_decimal_regex()));
+        assert_eq!(1000000.5, space.parse_decimal("1 000 000,5").unwrap());
+        assert!(!check("1.5", SeparatorPolicy::DotGroupsCommaDecimal.decimal_regex()));
+    }
+
+    #[test]
+    fn test_convert_temperature() {
+        assert_eq!(Some(100.0), convert_temperature(212.0, "fahrenheit", "celsius"));
+        assert_eq!(Some(273.15), convert_temperature(0.0, "celsius", "kelvin"));
+        assert_eq!(Some(-40.0), convert_temperature(-40.0, "celsius", "fahrenheit"));
+        assert_eq!(None, convert_temperature(20.0, "degree", "celsius"));
+    }
+
+    #[test]
+    fn test_colon_ratio() {
+        assert!(!colon_ratio("3", "2").unwrap().latent);
+        assert!(!colon_ratio("4", "75").unwrap().latent);
+        assert!(colon_ratio("2", "30").unwrap().latent);
+        assert_eq!(RatioValue::new(2, 30).latent(true), colon_ratio("2", "30").unwrap());
+    }
+
+    #[test]
+    fn test_locale_region() {
+        assert_eq!(Some("US".to_string()), locale_region("en_US"));
+        assert_eq!(Some("CA".to_string()), locale_region("fr-ca"));
+        assert_eq!(Some("GB".to_string()), locale_region("en_UK"));
+        assert_eq!(None, locale_region("en"));
+    }
+
+    #[test]
+    fn test_fraction_of_grain() {
+        let three_quarters = Fraction { numerator: 3, denominator: 4 };
+        assert_eq!(DurationValue::new(PeriodComp::minutes(45).into()), fraction_of_grain(three_quarters, Grain::Hour).unwrap());
+        let huge = Fraction { numerator: i64::max_value() / 2, denominator: 7 };
+        assert!(fraction_of_grain(huge, Grain::Hour).is_err());
+    }
+
     #[test]
     fn test_decimal_hour() {
         assert_eq!(90, decimal_hour_in_minute("1", "5").unwrap());
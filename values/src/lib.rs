@@ -7,12 +7,13 @@ extern crate regex;
 
 
 pub mod check;
+pub mod currency;
 pub mod dimension;
+#[macro_use]
+pub mod macros_rules;
 pub mod helpers;
 #[macro_use]
 pub mod macros_training;
-#[macro_use]
-pub mod macros_rules;
 pub mod output;
 pub mod context;
 
@@ -61,6 +61,17 @@ macro_rules! money_unit {
     () => ( ::rustling::core::AnyNodePattern::<MoneyUnitValue>::new() );
 }
 
+#[macro_export]
+macro_rules! quantity_check {
+    () => ( ::rustling::core::AnyNodePattern::<QuantityValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<QuantityValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! quantity_unit {
+    () => ( ::rustling::core::AnyNodePattern::<QuantityUnitValue>::new() );
+}
+
 
 #[macro_export]
 macro_rules! cycle_check {
@@ -82,6 +93,24 @@ macro_rules! temperature_check {
     ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<TemperatureValue>::filter(vec![b!($predicate)]) );
 }
 
+#[macro_export]
+macro_rules! distance_check {
+    () => ( ::rustling::core::AnyNodePattern::<DistanceValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<DistanceValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! ratio_check {
+    () => ( ::rustling::core::AnyNodePattern::<RatioValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<RatioValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! digit_sequence_check {
+    () => ( ::rustling::core::AnyNodePattern::<DigitSequenceValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<DigitSequenceValue>::filter(vec![b!($predicate)]) );
+}
+
 
 #[macro_export]
 macro_rules! time_check {
@@ -18,34 +18,41 @@ macro_rules! check_duration {
     ([$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_duration(period!($( $item ),*), $precision) );
 }
 
+#[macro_export]
+macro_rules! check_duration_interval {
+    ([$($from:expr),*], [$($to:expr),*]) => ( ::rustling_ontology_values::check::check_duration_interval(
+        Some(::rustling_ontology_values::dimension::IntervalBound::inclusive(period!($( $from ),*))),
+        Some(::rustling_ontology_values::dimension::IntervalBound::inclusive(period!($( $to ),*)))) );
+}
+
 #[macro_export]
 macro_rules! check_moment {
-    ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
-    ($context:expr, [$($item:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), $grain, Precision::Exact, None) );
+    ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
+    ($context:expr, [$($item:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), $grain, Precision::Exact, None) );
 }
 
 #[macro_export]
 macro_rules! check_moment_with_precision {
-    ($context:expr, [$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), $precision, None) );
+    ($context:expr, [$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), $precision, None) );
 }
 
 #[macro_export]
 macro_rules! check_moment_with_direction {
-    ($context:expr, [$($item:expr),*], $direction:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, Some($direction)) );
-    ($context:expr, [$($item:expr),*], $direction:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), $grain, Precision::Exact, Some($direction)) );
+    ($context:expr, [$($item:expr),*], $direction:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, Some($direction)) );
+    ($context:expr, [$($item:expr),*], $direction:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), $grain, Precision::Exact, Some($direction)) );
 
 }
 
 #[macro_export]
 macro_rules! check_moment_span {
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*]) => ( ::rustling_ontology_values::check::check_moment_span($context, Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*]) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
 }
 
 #[macro_export]
 macro_rules! check_moment_span_with_precision {
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
 }
 
 #[macro_export]
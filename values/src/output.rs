@@ -1,5 +1,7 @@
+use rustling::Range;
 use moment::*;
 use dimension::*;
+use helpers;
 
 #[derive(Clone,PartialEq,Debug)]
 pub enum Output {
@@ -11,7 +13,16 @@ pub enum Output {
     TimeInterval(TimeIntervalOutput),
     AmountOfMoney(AmountOfMoneyOutput),
     Temperature(TemperatureOutput),
+    Distance(DistanceOutput),
+    Quantity(QuantityOutput),
+    Rate(RateOutput),
+    Ratio(RatioOutput),
+    DigitSequence(DigitSequenceOutput),
     Duration(DurationOutput),
+    NumberInterval(NumberIntervalOutput),
+    AmountOfMoneyInterval(AmountOfMoneyIntervalOutput),
+    TemperatureInterval(TemperatureIntervalOutput),
+    DurationInterval(DurationIntervalOutput),
 }
 
 impl Output {
@@ -24,8 +35,17 @@ impl Output {
             &Output::TimeInterval(_) => OutputKind::Time,
             &Output::AmountOfMoney(_) => OutputKind::AmountOfMoney,
             &Output::Temperature(_) => OutputKind::Temperature,
+            &Output::Distance(_) => OutputKind::Distance,
+            &Output::Quantity(_) => OutputKind::Quantity,
+            &Output::Rate(_) => OutputKind::Rate,
+            &Output::Ratio(_) => OutputKind::Ratio,
+            &Output::DigitSequence(_) => OutputKind::DigitSequence,
             &Output::Duration(_) => OutputKind::Duration,
             &Output::Percentage(_) => OutputKind::Percentage,
+            &Output::NumberInterval(_) => OutputKind::NumberInterval,
+            &Output::AmountOfMoneyInterval(_) => OutputKind::AmountOfMoneyInterval,
+            &Output::TemperatureInterval(_) => OutputKind::TemperatureInterval,
+            &Output::DurationInterval(_) => OutputKind::DurationInterval,
         }
     }
 }
@@ -38,7 +58,16 @@ enum_kind!(OutputKind,
         Time,
         AmountOfMoney,
         Temperature,
-        Percentage
+        Percentage,
+        Distance,
+        Quantity,
+        NumberInterval,
+        AmountOfMoneyInterval,
+        TemperatureInterval,
+        DurationInterval,
+        Rate,
+        Ratio,
+        DigitSequence
     ]
 );
 
@@ -50,20 +79,37 @@ impl OutputKind {
             &OutputKind::Time => DimensionKind::Time,
             &OutputKind::AmountOfMoney => DimensionKind::AmountOfMoney,
             &OutputKind::Temperature => DimensionKind::Temperature,
+            &OutputKind::Distance => DimensionKind::Distance,
+            &OutputKind::Quantity => DimensionKind::Quantity,
             &OutputKind::Duration => DimensionKind::Duration,
             &OutputKind::Percentage => DimensionKind::Percentage,
+            &OutputKind::NumberInterval => DimensionKind::NumberInterval,
+            &OutputKind::AmountOfMoneyInterval => DimensionKind::AmountOfMoneyInterval,
+            &OutputKind::TemperatureInterval => DimensionKind::TemperatureInterval,
+            &OutputKind::DurationInterval => DimensionKind::DurationInterval,
+            &OutputKind::Rate => DimensionKind::Rate,
+            &OutputKind::Ratio => DimensionKind::Ratio,
+            &OutputKind::DigitSequence => DimensionKind::DigitSequence,
         }
     }
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
-pub struct IntegerOutput(pub i64);
+pub struct IntegerOutput {
+    pub value: i64,
+    pub precision: Precision,
+    pub counter: Option<CounterClass>,
+}
 
 #[derive(Clone,Copy,PartialEq,Debug)]
-pub struct FloatOutput(pub f32);
+pub struct FloatOutput {
+    pub value: f64,
+    pub precision: Precision,
+    pub fraction: Option<Fraction>,
+}
 
 #[derive(Clone,Copy,PartialEq,Debug)]
-pub struct PercentageOutput(pub f32);
+pub struct PercentageOutput(pub f64);
 
 #[derive(Clone,Copy,PartialEq,Debug)]
 pub struct OrdinalOutput(pub i64);
@@ -85,15 +131,87 @@ pub enum TimeIntervalOutput {
 
 #[derive(Clone,Copy,PartialEq,Debug)]
 pub struct AmountOfMoneyOutput {
-    pub value: f32, 
+    pub value: f64, 
     pub precision: Precision, 
-    pub unit: Option<&'static str>,
+    pub unit: Option<MoneyUnit>,
+    /// Currency of the unit, shared symbols being resolved with the locale of the context
+    pub currency: Option<Currency>,
+    /// The unit was left out and filled with the default currency of the context
+    pub unit_inferred: bool,
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
 pub struct TemperatureOutput {
-    pub value: f32, 
+    pub value: f64, 
     pub unit: Option<&'static str>,
+    pub precision: Precision,
+    pub latent: bool,
+    /// The unit was left out, or read as plain "degrees", and filled with the default
+    /// temperature unit of the context
+    pub unit_inferred: bool,
+}
+
+impl TemperatureOutput {
+    /// The temperature in "celsius", "fahrenheit" or "kelvin", if its own unit is one of them
+    pub fn converted(&self, unit: &'static str) -> Option<TemperatureOutput> {
+        Some(TemperatureOutput {
+            value: helpers::convert_temperature(self.value, self.unit?, unit)?,
+            unit: Some(unit),
+            ..*self
+        })
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct DistanceOutput {
+    pub value: f64,
+    pub unit: DistanceUnit,
+    pub precision: Precision,
+    pub latent: bool,
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct QuantityOutput {
+    pub value: f64,
+    pub unit: QuantityUnit,
+    pub product: Option<Range>,
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub enum RateQuantityOutput {
+    Integer(IntegerOutput),
+    Float(FloatOutput),
+    AmountOfMoney(AmountOfMoneyOutput),
+    Distance(DistanceOutput),
+}
+
+impl From<RateQuantityOutput> for Output {
+    fn from(quantity: RateQuantityOutput) -> Output {
+        match quantity {
+            RateQuantityOutput::Integer(v) => Output::Integer(v),
+            RateQuantityOutput::Float(v) => Output::Float(v),
+            RateQuantityOutput::AmountOfMoney(v) => Output::AmountOfMoney(v),
+            RateQuantityOutput::Distance(v) => Output::Distance(v),
+        }
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct RateOutput {
+    pub quantity: RateQuantityOutput,
+    pub per: Grain,
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct RatioOutput {
+    pub numerator: i64,
+    pub denominator: i64,
+    pub latent: bool,
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct DigitSequenceOutput {
+    pub digits: String,
     pub latent: bool,
 }
 
@@ -103,6 +221,73 @@ pub struct DurationOutput {
     pub precision: Precision,
 }
 
+impl DurationOutput {
+    /// Total number of seconds, approximate when the duration itself is, or when it counts years,
+    /// quarters or months
+    pub fn num_secs(&self) -> (i64, Precision) {
+        let precision = if self.precision == Precision::Exact && self.period.has_exact_num_secs() {
+            Precision::Exact
+        } else {
+            Precision::Approximate
+        };
+        (self.period.coarse_num_secs(), precision)
+    }
+
+    /// ISO 8601 duration such as "PT1H30M", `None` when months and days of opposite signs can
+    /// not be written as one
+    pub fn to_iso8601(&self) -> Option<String> {
+        self.period.to_iso8601()
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct NumberIntervalOutput {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct AmountOfMoneyIntervalOutput {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+    pub unit: Option<MoneyUnit>,
+    pub currency: Option<Currency>,
+    pub unit_inferred: bool,
+    pub precision: Precision,
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct TemperatureIntervalOutput {
+    pub from: Option<IntervalBound<f64>>,
+    pub to: Option<IntervalBound<f64>>,
+    pub unit: Option<&'static str>,
+    pub unit_inferred: bool,
+}
+
+impl TemperatureIntervalOutput {
+    /// The interval in "celsius", "fahrenheit" or "kelvin", if its own unit is one of them
+    pub fn converted(&self, unit: &'static str) -> Option<TemperatureIntervalOutput> {
+        let from_unit = self.unit?;
+        let convert = |bound: IntervalBound<f64>| {
+            helpers::convert_temperature(bound.value, from_unit, unit)
+                .map(|value| IntervalBound { value, inclusive: bound.inclusive })
+        };
+        Some(TemperatureIntervalOutput {
+            from: match self.from { Some(bound) => Some(convert(bound)?), None => None },
+            to: match self.to { Some(bound) => Some(convert(bound)?), None => None },
+            unit: Some(unit),
+            ..*self
+        })
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct DurationIntervalOutput {
+    pub from: Option<IntervalBound<Period>>,
+    pub to: Option<IntervalBound<Period>>,
+    pub precision: Precision,
+}
+
 variant_converters!(Output, Integer, IntegerOutput);
 variant_converters!(Output, Float, FloatOutput);
 variant_converters!(Output, Percentage, PercentageOutput);
@@ -111,4 +296,13 @@ variant_converters!(Output, Time, TimeOutput);
 variant_converters!(Output, TimeInterval, TimeIntervalOutput);
 variant_converters!(Output, AmountOfMoney, AmountOfMoneyOutput);
 variant_converters!(Output, Temperature, TemperatureOutput);
-variant_converters!(Output, Duration, DurationOutput);
\ No newline at end of file
+variant_converters!(Output, Distance, DistanceOutput);
+variant_converters!(Output, Quantity, QuantityOutput);
+variant_converters!(Output, Rate, RateOutput);
+variant_converters!(Output, Ratio, RatioOutput);
+variant_converters!(Output, DigitSequence, DigitSequenceOutput);
+variant_converters!(Output, Duration, DurationOutput);
+variant_converters!(Output, NumberInterval, NumberIntervalOutput);
+variant_converters!(Output, AmountOfMoneyInterval, AmountOfMoneyIntervalOutput);
+variant_converters!(Output, TemperatureInterval, TemperatureIntervalOutput);
+variant_converters!(Output, DurationInterval, DurationIntervalOutput);